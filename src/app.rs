@@ -4,19 +4,34 @@ use maven_registry::{
         SearchResponseDoc,
         GetVersionsResponseDoc,
     };
-use ratatui::{DefaultTerminal, widgets::ListState};
-use tokio::sync::mpsc;
+use ratatui::DefaultTerminal;
+use tokio::{sync::mpsc, task::JoinHandle};
 use std::collections::HashMap;
 
 use crate::{
+    audit::AuditState,
+    build_file::{self, BuildFile, ProjectBuildFile},
+    effective_pom::EffectivePomState,
+    gradle::GradleFile,
     dependency::{self, MavenFile},
+    dependency_editor::DependencyEdit,
     events::{
-        self, AppAsyncOrchestrator, AppEvent, AppExecutor, AppIntentHandler, Effect, EventContext,
+        self, AppAsyncOrchestrator, AppEvent, AppExecutor, Effect,
     },
-    maven_registry, ui,
+    license::{LicenseLookup, LicensePolicy},
+    maven_registry,
+    pom::PomMetadata,
+    popup::Popup,
+    profile::ActivationContext,
+    resolver::ResolutionState,
     ui::UI,
     views::{
-        View, ViewId, dependency_search_view::DependencySearchView, dependency_view::DependencyView,
+        Pane, View, ViewId, conflicts_view::ConflictsView, dependency_search_view::DependencySearchView,
+        dependency_tree_view::DependencyTreeView, dependency_view::DependencyView,
+        effective_pom_view::EffectivePomView,
+        managed_dependencies_view::ManagedDependenciesView, plugins_view::PluginsView,
+        profiles_view::ProfilesView, project_info_view::ProjectInfoView,
+        properties_view::PropertiesView,
     },
 };
 
@@ -24,6 +39,7 @@ pub struct App {
     tx: mpsc::Sender<events::AppEvent>,
     rx: mpsc::Receiver<events::AppEvent>,
     state: AppState,
+    search_task: Option<JoinHandle<Result<()>>>,
 }
 
 pub struct AppState {
@@ -34,19 +50,148 @@ pub struct AppState {
 pub struct UIState {
     pub views: Vec<(ViewId, Box<dyn View>)>,
     pub currently_focused_view: ViewId,
+    pub visible_views: HashMap<Pane, ViewId>,
+    pub popup: Option<Popup>,
 }
 
+impl UIState {
+    /// Focuses a view and brings it to the front of its pane.
+    pub fn focus(&mut self, view_id: ViewId) {
+        self.visible_views.insert(view_id.pane(), view_id.clone());
+        self.currently_focused_view = view_id;
+    }
+}
+
+#[derive(Default)]
 pub struct Data {
     pub mode: InteractionMode,
     pub maven_file: MavenFile,
-    pub found_dependencies: Vec<SearchResponseDoc>,
+    pub found_dependencies: Paginated<SearchResponseDoc>,
+    pub search_query: String,
+    pub latest_search_id: u64,
     pub dependencies: Vec<JavaDependency>,
     pub exit: bool,
-    pub found_dependency_versions: HashMap<String, Vec<GetVersionsResponseDoc>>,
+    pub found_dependency_versions: HashMap<String, Paginated<GetVersionsResponseDoc>>,
+    pub artifact_metadata: HashMap<String, PomMetadata>,
+    pub dependency_tree: ResolutionState,
+    pub audit: AuditState,
+    /// License lookups keyed by `groupId:artifactId`.
+    pub licenses: HashMap<String, LicenseLookup>,
+    pub license_policy: LicensePolicy,
+    /// Artifacts managed by each imported BOM, keyed by `groupId:artifactId` of the BOM.
+    pub bom_contents: HashMap<String, Result<Vec<String>, String>>,
+    /// Latest released versions looked up in the registry, keyed by `groupId:artifactId`.
+    pub latest_versions: HashMap<String, Result<String, String>>,
+    /// Every published version of an artifact, keyed by `groupId:artifactId`.
+    pub available_versions: HashMap<String, Result<Vec<String>, String>>,
+    /// Environment profiles of the project are activated against.
+    pub activation_context: ActivationContext,
+    /// Properties inherited from the parent POM, `None` until they are loaded.
+    pub parent_properties: Option<Result<HashMap<String, String>, String>>,
+    pub effective_pom: EffectivePomState,
+    /// The Gradle build script, for projects built with Gradle. Maven-only
+    /// views are then hidden and `maven_file` stays empty.
+    pub gradle_file: Option<GradleFile>,
+    /// Declarations as they were before each edit made in the dependency
+    /// editor, most recent last.
+    pub dependency_edits: Vec<DependencyEdit>,
+}
+
+impl Data {
+    /// The file dependencies are read from and written to.
+    pub fn build_file(&self) -> &dyn BuildFile {
+        match &self.gradle_file {
+            Some(gradle_file) => gradle_file,
+            None => &self.maven_file,
+        }
+    }
+
+    pub fn build_file_mut(&mut self) -> &mut dyn BuildFile {
+        match &mut self.gradle_file {
+            Some(gradle_file) => gradle_file,
+            None => &mut self.maven_file,
+        }
+    }
+}
+
+/// Registry results that are fetched one page at a time.
+#[derive(Debug)]
+pub struct Paginated<T> {
+    pub items: Vec<T>,
+    pub num_found: u32,
+    pub fetched: u32,
+    pub loading: bool,
+    /// Why the last page could not be loaded.
+    pub error: Option<String>,
+    /// Counts the pages stored, so views can tell when the items changed.
+    pub revision: u64,
+}
+
+impl<T> Default for Paginated<T> {
+    fn default() -> Self {
+        return Self {
+            items: vec![],
+            num_found: 0,
+            fetched: 0,
+            loading: false,
+            error: None,
+            revision: 0,
+        };
+    }
+}
+
+impl<T> Paginated<T> {
+    pub fn has_more(&self) -> bool {
+        return self.fetched < self.num_found;
+    }
+
+    pub fn next_start(&self) -> u32 {
+        return self.fetched;
+    }
+
+    /// Returns whether a page starting at `start` continues the loaded results.
+    /// The first page always does and clears previous results.
+    pub fn begin_page(&mut self, start: u32) -> bool {
+        if start == 0 {
+            self.items.clear();
+            self.fetched = 0;
+        }
+
+        return start == self.fetched;
+    }
+
+    /// An empty page ends the results, even when the registry counts more.
+    pub fn finish_page(&mut self, start: u32, rows: usize, num_found: u32) {
+        self.fetched = start + rows as u32;
+        self.num_found = if rows == 0 { self.fetched } else { num_found };
+        self.loading = false;
+        self.error = None;
+        self.revision += 1;
+    }
+
+    /// Records a page that could not be loaded. It is asked for again
+    /// once the end of the list is reached the next time.
+    pub fn fail_page(&mut self, error: String) {
+        self.loading = false;
+        self.error = Some(error);
+    }
+
+    /// Stores a page received from the registry. The first page replaces
+    /// previous results, later pages are appended when they line up.
+    pub fn add_page(&mut self, start: u32, num_found: u32, items: Vec<T>) {
+        if !self.begin_page(start) {
+            return;
+        }
+
+        let rows = items.len();
+        self.items.extend(items);
+        self.finish_page(start, rows, num_found);
+    }
 }
 
-#[derive(Copy, Clone, Debug)]
+#[derive(Copy, Clone, Debug, Default, PartialEq)]
 pub enum InteractionMode {
+    #[default]
     Normal,
     Input,
 }
@@ -62,32 +207,77 @@ impl App {
                 ui_state: UIState {
                     views: vec![
                         (ViewId::DependencyView, Box::new(DependencyView::new())),
+                        (ViewId::DependencyTreeView, Box::new(DependencyTreeView::new())),
+                        (ViewId::ConflictsView, Box::new(ConflictsView::new())),
+                        (ViewId::ManagedDependenciesView, Box::new(ManagedDependenciesView::new())),
+                        (ViewId::PluginsView, Box::new(PluginsView::new())),
+                        (ViewId::ProfilesView, Box::new(ProfilesView::new())),
+                        (ViewId::PropertiesView, Box::new(PropertiesView::new())),
+                        (ViewId::ProjectInfoView, Box::new(ProjectInfoView::new())),
                         (
                             ViewId::DependencySearchView,
                             Box::new(DependencySearchView::new()),
                         ),
+                        (ViewId::EffectivePomView, Box::new(EffectivePomView::new())),
                     ],
                     currently_focused_view: ViewId::DependencyView,
+                    visible_views: HashMap::from([
+                        (Pane::Left, ViewId::DependencyView),
+                        (Pane::Right, ViewId::DependencySearchView),
+                    ]),
+                    popup: None,
                 },
                 data: Data {
                     mode: InteractionMode::Normal,
                     found_dependencies: Default::default(),
+                    search_query: Default::default(),
+                    latest_search_id: 0,
                     found_dependency_versions: Default::default(),
+                    artifact_metadata: Default::default(),
+                    dependency_tree: Default::default(),
+                    audit: Default::default(),
+                    licenses: Default::default(),
+                    license_policy: Default::default(),
+                    bom_contents: Default::default(),
+                    latest_versions: Default::default(),
+                    available_versions: Default::default(),
+                    activation_context: Default::default(),
+                    parent_properties: None,
+                    effective_pom: EffectivePomState::NotStarted,
+                    gradle_file: None,
+                    dependency_edits: vec![],
                     dependencies: Default::default(),
                     maven_file: Default::default(),
                     exit: false,
                 },
             },
+            search_task: None,
         };
 
         Ok(me)
     }
 
     fn init(&mut self) -> Result<()> {
-        let maven_file = MavenFile::search_project_maven_file()?;
-        let dependencies = maven_file
+        match build_file::search_project_build_file()? {
+            ProjectBuildFile::Maven(maven_file) => self.state.data.maven_file = maven_file,
+            ProjectBuildFile::Gradle(gradle_file) => {
+                self.state.data.gradle_file = Some(gradle_file);
+                self.state.ui_state.views.retain(|(view_id, _)| !view_id.is_maven_only());
+            }
+        }
+
+        let build_file = self.state.data.build_file();
+        let directory = build_file.directory().unwrap_or_default();
+        let dependencies = build_file
             .get_dependencies()
             .context("no dependencies found")?;
+        // A broken policy file should not keep the project from opening.
+        self.state.data.license_policy = LicensePolicy::load(&directory).unwrap_or_else(|error| {
+            let text = format!("{:#}\n\nNo licenses are allowed or denied until it is fixed.", error);
+            self.state.ui_state.popup = Some(Popup::Message { title: "License policy".to_string(), text });
+            LicensePolicy::default()
+        });
+        self.state.data.activation_context = ActivationContext::detect(directory);
         self.state.data.dependencies = dependencies;
         self.spawn_input_task(self.tx.clone());
         return Ok(());
@@ -96,15 +286,26 @@ impl App {
     pub async fn run(&mut self, terminal: &mut DefaultTerminal) -> Result<()> {
         self.init()?;
         let mut effects: Vec<Effect> = vec![];
+        AppExecutor::load_advisories(&mut self.state, &mut effects);
+        AppExecutor::collect_licenses(&mut self.state, &mut effects);
+        AppExecutor::load_boms(&mut self.state, &mut effects);
+        AppExecutor::load_parent_properties(&mut self.state, &mut effects);
 
         while !self.state.data.exit {
             terminal.draw(|frame| UI::render(frame, &mut self.state.ui_state, &self.state.data))?;
 
             for effect in effects.drain(..) {
-                tokio::spawn(AppAsyncOrchestrator::handle_async_event(
+                let is_search = matches!(effect, Effect::SearchMaven { .. });
+
+                let task = tokio::spawn(AppAsyncOrchestrator::handle_async_event(
                     effect,
                     self.tx.clone(),
                 ));
+
+                // A new search supersedes the one still waiting or in flight.
+                if is_search && let Some(previous) = self.search_task.replace(task) {
+                    previous.abort();
+                }
             }
 
             if let Some(event) = self.rx.recv().await {
@@ -118,10 +319,7 @@ impl App {
     fn spawn_input_task(&self, tx: mpsc::Sender<AppEvent>) {
         tokio::spawn(async move {
             loop {
-                let event = match tokio::task::spawn_blocking(crossterm::event::read)
-                    .await
-                    .and_then(|r| Ok(r))
-                {
+                let event = match tokio::task::spawn_blocking(crossterm::event::read).await {
                     Ok(Ok(ev)) => ev,
                     _ => {
                         eprintln!("Error reading event");
@@ -136,3 +334,90 @@ impl App {
         });
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn page(start: u32, rows: u32) -> Vec<u32> {
+        return (start..start + rows).collect();
+    }
+
+    #[test]
+    fn loads_pages_until_the_last() {
+        let mut found = Paginated::default();
+        found.add_page(0, 25, page(0, 20));
+        assert!(found.has_more());
+        assert_eq!(found.next_start(), 20);
+
+        // A page that does not continue the results is dropped.
+        found.add_page(40, 25, page(40, 5));
+        assert_eq!(found.items.len(), 20);
+
+        found.add_page(20, 25, page(20, 5));
+        assert_eq!(found.items, page(0, 25));
+        assert!(!found.has_more());
+
+        found.add_page(0, 3, page(100, 3));
+        assert_eq!(found.items, page(100, 3));
+        assert!(!found.has_more());
+    }
+
+    #[test]
+    fn empty_results_have_no_more_pages() {
+        let mut found: Paginated<u32> = Paginated::default();
+        assert!(!found.has_more());
+
+        found.add_page(0, 0, vec![]);
+        assert!(found.items.is_empty());
+        assert!(!found.has_more());
+
+        found.add_page(0, 30, page(0, 20));
+        found.add_page(20, 30, vec![]);
+        assert!(!found.has_more());
+    }
+
+    #[test]
+    fn follows_num_found_changing_between_pages() {
+        let mut found = Paginated::default();
+        found.add_page(0, 50, page(0, 20));
+
+        found.add_page(20, 30, page(20, 10));
+        assert_eq!(found.num_found, 30);
+        assert!(!found.has_more());
+
+        found.add_page(0, 50, page(0, 20));
+        found.add_page(20, 60, page(20, 20));
+        assert_eq!(found.next_start(), 40);
+        assert!(found.has_more());
+    }
+
+    #[test]
+    fn skipped_rows_count_as_fetched() {
+        let mut found: Paginated<u32> = Paginated::default();
+        assert!(found.begin_page(0));
+        found.items.extend(page(0, 15));
+        found.finish_page(0, 20, 40);
+
+        assert_eq!(found.items.len(), 15);
+        assert_eq!(found.next_start(), 20);
+        assert!(!found.begin_page(10));
+        assert!(found.begin_page(20));
+    }
+
+    #[test]
+    fn failed_pages_can_be_loaded_again() {
+        let mut found = Paginated::default();
+        found.add_page(0, 40, page(0, 20));
+        found.loading = true;
+
+        found.fail_page("timed out".to_string());
+        assert!(!found.loading);
+        assert_eq!(found.error.as_deref(), Some("timed out"));
+        assert_eq!(found.next_start(), 20);
+
+        found.add_page(20, 40, page(20, 20));
+        assert_eq!(found.error, None);
+        assert!(!found.has_more());
+    }
+}
@@ -0,0 +1,374 @@
+use anyhow::{Context, Result};
+use serde::Deserialize;
+use std::{
+    collections::HashMap,
+    fs,
+    path::{Path, PathBuf},
+};
+
+use crate::maven_version::{MavenVersion, Restriction, VersionRange};
+
+/// Environment variable pointing at an unpacked OSV dump, e.g. the
+/// `Maven/all.zip` export of osv.dev.
+pub const ADVISORY_DIRECTORY_VARIABLE: &str = "LAZYMAVEN_ADVISORY_DB";
+
+/// Directory advisories are read from, when it exists.
+pub fn advisory_directory() -> Option<PathBuf> {
+    let directory = match std::env::var_os(ADVISORY_DIRECTORY_VARIABLE) {
+        Some(directory) => PathBuf::from(directory),
+        None => PathBuf::from(std::env::var_os("HOME")?).join(".lazymaven").join("advisories"),
+    };
+
+    return Some(directory).filter(|directory| directory.is_dir());
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum Severity {
+    Unknown,
+    Low,
+    Moderate,
+    High,
+    Critical,
+}
+
+impl Severity {
+    fn parse(value: &str) -> Severity {
+        match value.to_uppercase().as_str() {
+            "LOW" => Severity::Low,
+            "MODERATE" | "MEDIUM" => Severity::Moderate,
+            "HIGH" => Severity::High,
+            "CRITICAL" => Severity::Critical,
+            _ => Severity::Unknown,
+        }
+    }
+
+    pub fn label(&self) -> &'static str {
+        match self {
+            Severity::Unknown => "UNKNOWN",
+            Severity::Low => "LOW",
+            Severity::Moderate => "MODERATE",
+            Severity::High => "HIGH",
+            Severity::Critical => "CRITICAL",
+        }
+    }
+}
+
+/// A known vulnerability of one Maven artifact.
+#[derive(Debug, Clone)]
+pub struct Advisory {
+    pub id: String,
+    pub aliases: Vec<String>,
+    pub severity: Severity,
+    pub fixed: Vec<String>,
+    range: VersionRange,
+    versions: Vec<String>,
+}
+
+impl Advisory {
+    /// CVE identifier when the advisory has one, its own id otherwise.
+    pub fn display_id(&self) -> &str {
+        return self
+            .aliases
+            .iter()
+            .find(|alias| alias.starts_with("CVE-"))
+            .unwrap_or(&self.id);
+    }
+
+    pub fn affects(&self, version: &MavenVersion) -> bool {
+        return self.range.contains(version)
+            || self.versions.iter().any(|affected| MavenVersion::parse(affected) == *version);
+    }
+
+    /// Lowest version fixing this advisory that is newer than the given one.
+    fn fixed_after(&self, version: &MavenVersion) -> Option<MavenVersion> {
+        return self
+            .fixed
+            .iter()
+            .map(|fixed| MavenVersion::parse(fixed))
+            .filter(|fixed| fixed > version)
+            .min();
+    }
+}
+
+/// Advisories affecting one version of a dependency.
+#[derive(Debug)]
+pub struct Finding<'a> {
+    pub advisories: Vec<&'a Advisory>,
+    pub severity: Severity,
+    /// Lowest version fixing all of the advisories, if there is one.
+    pub fixed_in: Option<String>,
+}
+
+/// Advisories of Maven artifacts, keyed by `groupId:artifactId`.
+#[derive(Debug, Default)]
+pub struct AdvisoryDatabase {
+    advisories: HashMap<String, Vec<Advisory>>,
+}
+
+impl AdvisoryDatabase {
+    /// Reads every `.json` file below the directory. Files that are not OSV
+    /// entries are skipped, so a dump can be used without cleaning it up.
+    pub fn load(directory: &Path) -> Result<AdvisoryDatabase> {
+        let mut database = AdvisoryDatabase::default();
+        let mut directories = vec![directory.to_path_buf()];
+
+        while let Some(directory) = directories.pop() {
+            let entries = fs::read_dir(&directory)
+                .with_context(|| format!("cannot read {}", directory.display()))?;
+
+            for entry in entries {
+                let path = entry?.path();
+
+                if path.is_dir() {
+                    directories.push(path);
+                } else if path.extension().is_some_and(|extension| extension == "json") {
+                    let Ok(entry) = fs::read_to_string(&path)
+                        .map_err(anyhow::Error::from)
+                        .and_then(|content| Ok(serde_json::from_str::<OsvEntry>(&content)?))
+                    else {
+                        continue;
+                    };
+
+                    database.add(entry);
+                }
+            }
+        }
+
+        return Ok(database);
+    }
+
+    fn add(&mut self, entry: OsvEntry) {
+        if entry.withdrawn.is_some() {
+            return;
+        }
+
+        let severity = entry
+            .database_specific
+            .and_then(|specific| specific.severity)
+            .map(|severity| Severity::parse(&severity))
+            .unwrap_or(Severity::Unknown);
+
+        for affected in entry.affected {
+            if affected.package.ecosystem != "Maven" {
+                continue;
+            }
+
+            let (range, fixed) = range_of(&affected.ranges);
+
+            self.advisories.entry(affected.package.name).or_default().push(Advisory {
+                id: entry.id.clone(),
+                aliases: entry.aliases.clone(),
+                severity,
+                fixed,
+                range,
+                versions: affected.versions,
+            });
+        }
+    }
+
+    /// Number of artifact advisories loaded.
+    pub fn len(&self) -> usize {
+        return self.advisories.values().map(Vec::len).sum();
+    }
+
+    pub fn audit(&self, group_id: &str, artifact_id: &str, version: &str) -> Option<Finding<'_>> {
+        let advisories = self.advisories.get(&format!("{}:{}", group_id, artifact_id))?;
+        let version = MavenVersion::parse(version);
+
+        let affecting: Vec<&Advisory> = advisories.iter().filter(|advisory| advisory.affects(&version)).collect();
+        if affecting.is_empty() {
+            return None;
+        }
+
+        let severity = affecting.iter().map(|advisory| advisory.severity).max().unwrap_or(Severity::Unknown);
+
+        // Upgrading fixes everything only when each advisory has a fix.
+        let fixes: Option<Vec<MavenVersion>> = affecting.iter().map(|advisory| advisory.fixed_after(&version)).collect();
+        let fixed_in = fixes
+            .and_then(|fixes| fixes.into_iter().max())
+            .map(|fixed| fixed.as_str().to_string());
+
+        return Some(Finding { advisories: affecting, severity, fixed_in });
+    }
+}
+
+/// Turns the `introduced`/`fixed`/`last_affected` events of OSV into a
+/// Maven version range, together with the versions that fix it.
+fn range_of(ranges: &[OsvRange]) -> (VersionRange, Vec<String>) {
+    let mut restrictions = vec![];
+    let mut fixed = vec![];
+
+    for range in ranges.iter().filter(|range| range.type_ == "ECOSYSTEM") {
+        let mut introduced: Option<Option<MavenVersion>> = None;
+
+        for event in &range.events {
+            if let Some(version) = &event.introduced {
+                let lower = Some(version).filter(|version| *version != "0").map(|version| MavenVersion::parse(version));
+                introduced = Some(lower);
+            }
+
+            let (upper, upper_inclusive) = match (&event.fixed, &event.last_affected) {
+                (Some(version), _) => {
+                    fixed.push(version.clone());
+                    (version, false)
+                }
+                (None, Some(version)) => (version, true),
+                (None, None) => continue,
+            };
+
+            restrictions.push(Restriction {
+                lower: introduced.take().flatten(),
+                lower_inclusive: true,
+                upper: Some(MavenVersion::parse(upper)),
+                upper_inclusive,
+            });
+        }
+
+        // Still affected in the latest release.
+        if let Some(lower) = introduced {
+            restrictions.push(Restriction { lower, lower_inclusive: true, upper: None, upper_inclusive: false });
+        }
+    }
+
+    return (VersionRange { restrictions }, fixed);
+}
+
+/// Progress of loading the advisory database.
+#[derive(Debug, Default)]
+pub enum AuditState {
+    /// No advisory directory was found.
+    #[default]
+    Disabled,
+    Loading,
+    Loaded(AdvisoryDatabase),
+    Failed(String),
+}
+
+#[derive(Deserialize)]
+struct OsvEntry {
+    id: String,
+    #[serde(default)]
+    aliases: Vec<String>,
+    #[serde(default)]
+    withdrawn: Option<String>,
+    #[serde(default)]
+    affected: Vec<OsvAffected>,
+    #[serde(default)]
+    database_specific: Option<OsvDatabaseSpecific>,
+}
+
+#[derive(Deserialize)]
+struct OsvAffected {
+    package: OsvPackage,
+    #[serde(default)]
+    ranges: Vec<OsvRange>,
+    #[serde(default)]
+    versions: Vec<String>,
+}
+
+#[derive(Deserialize)]
+struct OsvPackage {
+    ecosystem: String,
+    name: String,
+}
+
+#[derive(Deserialize)]
+struct OsvRange {
+    #[serde(rename = "type")]
+    type_: String,
+    #[serde(default)]
+    events: Vec<OsvEvent>,
+}
+
+#[derive(Deserialize)]
+struct OsvEvent {
+    introduced: Option<String>,
+    fixed: Option<String>,
+    last_affected: Option<String>,
+}
+
+#[derive(Deserialize)]
+struct OsvDatabaseSpecific {
+    severity: Option<String>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const JACKSON_ADVISORY: &str = r#"{
+        "id": "GHSA-57j2-w4cx-62h2",
+        "aliases": ["CVE-2020-36518"],
+        "summary": "Deeply nested json in jackson-databind",
+        "affected": [{
+            "package": { "ecosystem": "Maven", "name": "com.fasterxml.jackson.core:jackson-databind" },
+            "ranges": [{
+                "type": "ECOSYSTEM",
+                "events": [
+                    { "introduced": "0" },
+                    { "fixed": "2.12.6.1" },
+                    { "introduced": "2.13.0" },
+                    { "fixed": "2.13.2.1" }
+                ]
+            }]
+        }],
+        "database_specific": { "severity": "HIGH" }
+    }"#;
+
+    fn database(entries: &[&str]) -> AdvisoryDatabase {
+        let mut database = AdvisoryDatabase::default();
+
+        for entry in entries {
+            database.add(serde_json::from_str(entry).unwrap());
+        }
+
+        return database;
+    }
+
+    #[test]
+    fn matches_versions_inside_osv_ranges() {
+        let database = database(&[JACKSON_ADVISORY]);
+        let (group_id, artifact_id) = ("com.fasterxml.jackson.core", "jackson-databind");
+
+        let finding = database.audit(group_id, artifact_id, "2.12.3").unwrap();
+        assert_eq!(finding.severity, Severity::High);
+        assert_eq!(finding.fixed_in.as_deref(), Some("2.12.6.1"));
+        assert_eq!(finding.advisories[0].display_id(), "CVE-2020-36518");
+
+        assert_eq!(database.audit(group_id, artifact_id, "2.13.1").unwrap().fixed_in.as_deref(), Some("2.13.2.1"));
+        assert!(database.audit(group_id, artifact_id, "2.12.6.1").is_none());
+        assert!(database.audit(group_id, artifact_id, "2.13.2.1").is_none());
+        assert!(database.audit(group_id, "jackson-core", "2.12.3").is_none());
+    }
+
+    #[test]
+    fn open_ranges_have_no_fix() {
+        let database = database(&[r#"{
+            "id": "GHSA-xxxx",
+            "affected": [{
+                "package": { "ecosystem": "Maven", "name": "org.example:lib" },
+                "ranges": [{ "type": "ECOSYSTEM", "events": [{ "introduced": "1.5" }] }],
+                "versions": ["1.0"]
+            }]
+        }"#]);
+
+        let finding = database.audit("org.example", "lib", "2.0").unwrap();
+        assert_eq!(finding.severity, Severity::Unknown);
+        assert_eq!(finding.fixed_in, None);
+
+        assert!(database.audit("org.example", "lib", "1.0").is_some());
+        assert!(database.audit("org.example", "lib", "1.2").is_none());
+    }
+
+    #[test]
+    fn skips_withdrawn_and_other_ecosystems() {
+        let database = database(&[
+            r#"{ "id": "A", "withdrawn": "2024-01-01T00:00:00Z", "affected": [{
+                "package": { "ecosystem": "Maven", "name": "org.example:lib" }, "versions": ["1.0"] }] }"#,
+            r#"{ "id": "B", "affected": [{
+                "package": { "ecosystem": "npm", "name": "org.example:lib" }, "versions": ["1.0"] }] }"#,
+        ]);
+
+        assert_eq!(database.len(), 0);
+    }
+}
@@ -0,0 +1,163 @@
+use std::collections::HashMap;
+
+use crate::{
+    app::Data,
+    dependency::JavaDependency,
+    maven_version::{self, UpgradeLevel},
+    pom::Pom,
+};
+
+/// Scopes dependencies can be moved to.
+pub const SCOPES: &[&str] = &["compile", "provided", "runtime", "test"];
+
+/// An operation applied to several dependencies at once.
+#[derive(Debug, Clone, PartialEq)]
+pub enum BatchOperation {
+    Delete,
+    Upgrade(UpgradeLevel),
+    SetScope(String),
+}
+
+impl BatchOperation {
+    pub fn title(&self) -> String {
+        match self {
+            BatchOperation::Delete => "Delete dependencies".to_string(),
+            BatchOperation::Upgrade(level) => format!("Upgrade to {} versions", level.label()),
+            BatchOperation::SetScope(scope) => format!("Change scope to {}", scope),
+        }
+    }
+}
+
+/// What an operation does to one dependency.
+#[derive(Debug, Clone, PartialEq)]
+pub enum BatchChange {
+    Remove,
+    SetVersion(String),
+    SetScope { from: String, to: String },
+    /// Nothing changes, for the given reason.
+    Skip(String),
+    /// The available versions are still being looked up.
+    Pending,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct PlannedChange {
+    pub dependency: JavaDependency,
+    pub change: BatchChange,
+}
+
+impl PlannedChange {
+    pub fn description(&self) -> String {
+        match &self.change {
+            BatchChange::Remove => "removed".to_string(),
+            BatchChange::SetVersion(version) => format!("{} → {}", self.dependency.version, version),
+            BatchChange::SetScope { from, to } => format!("{} → {}", from, to),
+            BatchChange::Skip(reason) => format!("unchanged, {}", reason),
+            BatchChange::Pending => "looking up versions…".to_string(),
+        }
+    }
+}
+
+/// What `operation` would change for each of `targets`. `pom` gives the
+/// scopes as declared in the build file, `versions` the versions available
+/// per `groupId:artifactId`.
+pub fn plan(
+    operation: &BatchOperation,
+    targets: &[JavaDependency],
+    pom: &Pom,
+    versions: &HashMap<String, Result<Vec<String>, String>>,
+) -> Vec<PlannedChange> {
+    return targets
+        .iter()
+        .map(|dependency| {
+            let change = match operation {
+                BatchOperation::Delete => BatchChange::Remove,
+                BatchOperation::Upgrade(_) if dependency.version.is_empty() => BatchChange::Skip("version is managed".to_string()),
+                BatchOperation::Upgrade(_) if dependency.version.contains("${") => {
+                    BatchChange::Skip("version comes from a property".to_string())
+                }
+                BatchOperation::Upgrade(level) => {
+                    match versions.get(&format!("{}:{}", dependency.group_id, dependency.artifact_id)) {
+                        Some(Ok(available)) => match maven_version::latest_upgrade(&dependency.version, available, *level) {
+                            Some(version) => BatchChange::SetVersion(version),
+                            None => BatchChange::Skip("up to date".to_string()),
+                        },
+                        Some(Err(error)) => BatchChange::Skip(format!("lookup failed: {}", error)),
+                        None => BatchChange::Pending,
+                    }
+                }
+                BatchOperation::SetScope(scope) => {
+                    let declared = pom.dependencies.iter().find(|declared| {
+                        declared.group_id == dependency.group_id && declared.artifact_id == dependency.artifact_id
+                    });
+
+                    match declared.map(|declared| if declared.scope.is_empty() { "compile" } else { declared.scope.as_str() }) {
+                        None => BatchChange::Skip("not written to the build file yet".to_string()),
+                        Some(current) if current == scope => BatchChange::Skip(format!("already {}", scope)),
+                        Some(current) => BatchChange::SetScope { from: current.to_string(), to: scope.clone() },
+                    }
+                }
+            };
+
+            PlannedChange { dependency: dependency.clone(), change }
+        })
+        .collect();
+}
+
+/// Plans an operation on the listed dependencies of the project.
+pub fn plan_for(data: &Data, operation: &BatchOperation, artifacts: &[(String, String)]) -> Vec<PlannedChange> {
+    let targets: Vec<JavaDependency> = data
+        .dependencies
+        .iter()
+        .filter(|dependency| {
+            artifacts
+                .iter()
+                .any(|(group_id, artifact_id)| dependency.group_id == *group_id && dependency.artifact_id == *artifact_id)
+        })
+        .cloned()
+        .collect();
+
+    return plan(operation, &targets, &data.build_file().to_pom(), &data.available_versions);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::pom::PomDependency;
+
+    fn dependency(artifact_id: &str, version: &str) -> JavaDependency {
+        return JavaDependency { group_id: "org.example".to_string(), artifact_id: artifact_id.to_string(), version: version.to_string() };
+    }
+
+    #[test]
+    fn plans_upgrades_and_scope_changes() {
+        let targets = vec![dependency("a", "1.0.0"), dependency("b", "${b.version}"), dependency("c", "2.0"), dependency("d", "")];
+        let versions = HashMap::from([
+            ("org.example:a".to_string(), Ok(vec!["1.0.0".to_string(), "1.0.3".to_string(), "1.1.0".to_string()])),
+            ("org.example:d".to_string(), Err("offline".to_string())),
+        ]);
+
+        let changes: Vec<BatchChange> = plan(&BatchOperation::Upgrade(UpgradeLevel::Patch), &targets, &Pom::default(), &versions)
+            .into_iter()
+            .map(|planned| planned.change)
+            .collect();
+
+        assert_eq!(changes[0], BatchChange::SetVersion("1.0.3".to_string()));
+        assert!(matches!(changes[1], BatchChange::Skip(_)));
+        assert_eq!(changes[2], BatchChange::Pending);
+        assert!(matches!(changes[3], BatchChange::Skip(_)));
+
+        let pom = Pom {
+            dependencies: vec![
+                PomDependency { group_id: "org.example".to_string(), artifact_id: "a".to_string(), ..Default::default() },
+                PomDependency { group_id: "org.example".to_string(), artifact_id: "c".to_string(), scope: "test".to_string(), ..Default::default() },
+            ],
+            ..Default::default()
+        };
+
+        let changes = plan(&BatchOperation::SetScope("test".to_string()), &targets, &pom, &versions);
+        assert_eq!(changes[0].change, BatchChange::SetScope { from: "compile".to_string(), to: "test".to_string() });
+        assert!(matches!(changes[1].change, BatchChange::Skip(_)));
+        assert_eq!(changes[2].change, BatchChange::Skip("already test".to_string()));
+    }
+}
@@ -0,0 +1,74 @@
+use anyhow::Result;
+use std::{fmt::Debug, path::PathBuf};
+
+use crate::{
+    dependency::{JavaDependency, MavenFile},
+    gradle::GradleFile,
+    pom::Pom,
+};
+
+/// A build file whose declared dependencies lazymaven reads and edits. The
+/// dependency view, registry search and updates work through it, whatever
+/// the build tool.
+pub trait BuildFile: Debug {
+    /// Name of the file, shown to the user.
+    fn name(&self) -> String;
+
+    /// Directory containing the file.
+    fn directory(&self) -> Option<PathBuf>;
+
+    fn get_dependencies(&self) -> Option<Vec<JavaDependency>>;
+
+    /// Makes the declared dependencies match `updated` and writes the file.
+    /// A POM also takes over their order; Gradle scripts keep theirs.
+    fn update_dependencies(&mut self, updated: &[JavaDependency]) -> Result<()>;
+
+    /// Moves a declared dependency to a Maven scope, in memory. Returns
+    /// whether the dependency was found.
+    fn set_dependency_scope(&mut self, dependency: &JavaDependency, scope: &str) -> bool;
+
+    /// The project as a POM, for resolving, auditing and license lookups.
+    fn to_pom(&self) -> Pom;
+}
+
+impl BuildFile for MavenFile {
+    fn name(&self) -> String {
+        return "pom.xml".to_string();
+    }
+
+    fn directory(&self) -> Option<PathBuf> {
+        return MavenFile::directory(self);
+    }
+
+    fn get_dependencies(&self) -> Option<Vec<JavaDependency>> {
+        return MavenFile::get_dependencies(self);
+    }
+
+    fn update_dependencies(&mut self, updated: &[JavaDependency]) -> Result<()> {
+        return MavenFile::update_dependencies(self, updated);
+    }
+
+    fn set_dependency_scope(&mut self, dependency: &JavaDependency, scope: &str) -> bool {
+        return MavenFile::set_dependency_scope(self, dependency, scope);
+    }
+
+    fn to_pom(&self) -> Pom {
+        return MavenFile::to_pom(self);
+    }
+}
+
+/// The build file found for the project.
+pub enum ProjectBuildFile {
+    Maven(MavenFile),
+    Gradle(GradleFile),
+}
+
+/// Looks for a Gradle build script in the working directory, falling back
+/// to the project's POM.
+pub fn search_project_build_file() -> Result<ProjectBuildFile> {
+    if let Some(path) = GradleFile::search() {
+        return Ok(ProjectBuildFile::Gradle(GradleFile::from_file(path)?));
+    }
+
+    return Ok(ProjectBuildFile::Maven(MavenFile::search_project_maven_file()?));
+}
@@ -1,9 +1,18 @@
 use anyhow::{Context, Result};
 use std::{
+    collections::HashMap,
     fs::{self, File},
     io::{self},
+    ops::Range,
+    path::{Path, PathBuf},
+};
+use xmltree::{Element, ElementPredicate, XMLNode};
+
+use crate::{
+    pom::{self, Pom, PomDependency},
+    profile::Profile,
+    project_info::{ProjectField, ProjectList},
 };
-use xmltree::{Element, ElementPredicate};
 
 #[derive(Debug, Clone)]
 pub struct MavenFile {
@@ -32,6 +41,20 @@ impl MavenFile {
         });
     }
 
+    pub fn to_pom(&self) -> Pom {
+        return Pom::from_element(&self.root);
+    }
+
+    /// The POM as it is edited, before it is written back.
+    pub fn root(&self) -> &Element {
+        return &self.root;
+    }
+
+    /// Directory containing the file, used to locate parent POMs on disk.
+    pub fn directory(&self) -> Option<PathBuf> {
+        return Path::new(&self.file_path).parent().map(Path::to_path_buf);
+    }
+
     pub fn get_dependencies(&self) -> Option<Vec<JavaDependency>> {
         let dependencies_root: &Element = self.root.get_child("dependencies")?;
 
@@ -39,20 +62,19 @@ impl MavenFile {
             .children
             .iter()
             .filter_map(|child| child.as_element())
-            .map(|dependency| JavaDependency::from_element(dependency))
+            .map(JavaDependency::from_element)
             .collect();
 
         return Some(dependencies);
     }
 
+    /// Makes `<dependencies>` match `updated_dependencies`, in their order,
+    /// and writes the file.
     pub fn update_dependencies(
         &mut self,
-        updated_dependencies: &Vec<JavaDependency>,
+        updated_dependencies: &[JavaDependency],
     ) -> Result<()> {
-        let dependencies_root = self
-            .root
-            .get_mut_child("dependencies")
-            .context("no dependencies root")?;
+        let dependencies_root = child_or_insert(&mut self.root, "dependencies");
 
         dependencies_root.children.retain(|child| {
             child
@@ -62,25 +84,535 @@ impl MavenFile {
 
                     updated_dependencies
                         .iter()
-                        .any(|elem| elem.group_id == dependency.group_id)
+                        .any(|elem| elem.is_same_artifact(&dependency))
                 })
                 .unwrap_or(true)
         });
 
-        self.update_xml_file();
+        for dependency in updated_dependencies {
+            let existing = dependencies_root
+                .children
+                .iter_mut()
+                .filter_map(|child| child.as_mut_element())
+                .find(|element| JavaDependency::from_element(element).is_same_artifact(dependency));
+
+            match existing {
+                Some(element) => dependency.update_element(element),
+                None => dependencies_root.children.push(XMLNode::Element(dependency.to_element())),
+            }
+        }
+
+        arrange_declarations(dependencies_root, updated_dependencies);
+
+        self.update_xml_file()?;
 
         Ok(())
     }
 
-    pub fn update_xml_file(&self) {
+    /// Sets the `<scope>` of a declared dependency, leaving it out for the
+    /// default `compile` scope.
+    pub fn set_dependency_scope(&mut self, dependency: &JavaDependency, scope: &str) -> bool {
+        let Some(element) = self
+            .root
+            .get_mut_child("dependencies")
+            .and_then(|dependencies| {
+                dependencies
+                    .children
+                    .iter_mut()
+                    .filter_map(|child| child.as_mut_element())
+                    .find(|element| JavaDependency::from_element(element).is_same_artifact(dependency))
+            })
+        else {
+            return false;
+        };
+
+        match (scope, element.get_mut_child("scope")) {
+            ("compile", _) => {
+                element.take_child("scope");
+            }
+            (scope, Some(existing)) => existing.children = vec![XMLNode::Text(scope.to_string())],
+            (scope, None) => element.children.push(XMLNode::Element(text_element("scope", scope))),
+        }
+
+        return true;
+    }
+
+    /// Rewrites version, scope, optional flag, classifier, type and exclusions
+    /// of a declared dependency where they differ from `declared`, keeping
+    /// the rest of the element. Returns whether the dependency was found.
+    pub fn set_declared_dependency(&mut self, declared: &PomDependency) -> bool {
+        let dependency = JavaDependency {
+            group_id: declared.group_id.clone(),
+            artifact_id: declared.artifact_id.clone(),
+            version: String::new(),
+        };
+
+        let Some(element) = self
+            .root
+            .get_mut_child("dependencies")
+            .and_then(|dependencies| {
+                dependencies
+                    .children
+                    .iter_mut()
+                    .filter_map(|child| child.as_mut_element())
+                    .find(|element| JavaDependency::from_element(element).is_same_artifact(&dependency))
+            })
+        else {
+            return false;
+        };
+
+        let current = PomDependency::from_element(element);
+        let scope = |scope: &str| if scope == "compile" { String::new() } else { scope.to_string() };
+        let flag = |set: bool| if set { "true" } else { "" };
+
+        for (name, from, to) in [
+            ("version", current.version.clone(), declared.version.clone()),
+            ("scope", scope(&current.scope), scope(&declared.scope)),
+            ("optional", flag(current.optional).to_string(), flag(declared.optional).to_string()),
+            ("classifier", current.classifier.clone(), declared.classifier.clone()),
+            ("type", current.type_.clone(), declared.type_.clone()),
+        ] {
+            if from != to {
+                set_child_text(element, name, &to);
+            }
+        }
+
+        if current.exclusions != declared.exclusions {
+            element.take_child("exclusions");
+
+            if !declared.exclusions.is_empty() {
+                let mut exclusions = Element::new("exclusions");
+                for excluded in &declared.exclusions {
+                    let mut exclusion = Element::new("exclusion");
+                    exclusion.children.push(XMLNode::Element(text_element("groupId", &excluded.group_id)));
+                    exclusion.children.push(XMLNode::Element(text_element("artifactId", &excluded.artifact_id)));
+                    exclusions.children.push(XMLNode::Element(exclusion));
+                }
+                element.children.push(XMLNode::Element(exclusions));
+            }
+        }
+
+        return true;
+    }
+
+    /// Entries of `<dependencyManagement>`, including imported BOMs.
+    pub fn get_managed_dependencies(&self) -> Vec<PomDependency> {
+        return self
+            .root
+            .get_child("dependencyManagement")
+            .map(pom::dependencies_of)
+            .unwrap_or_default();
+    }
+
+    /// Sets the version of an artifact in `<dependencyManagement>`, adding
+    /// the entry (and the section) when it does not exist yet.
+    pub fn pin_managed_version(&mut self, group_id: &str, artifact_id: &str, version: &str) {
+        self.upsert_managed(group_id, artifact_id, version, false);
+    }
+
+    /// Imports a BOM into `<dependencyManagement>`, or updates its version.
+    pub fn add_managed_import(&mut self, group_id: &str, artifact_id: &str, version: &str) {
+        self.upsert_managed(group_id, artifact_id, version, true);
+    }
+
+    fn upsert_managed(&mut self, group_id: &str, artifact_id: &str, version: &str, import: bool) {
+        let management = child_or_insert(&mut self.root, "dependencyManagement");
+        let dependencies = child_or_insert(management, "dependencies");
+
+        let pinned = JavaDependency {
+            group_id: group_id.to_string(),
+            artifact_id: artifact_id.to_string(),
+            version: version.to_string(),
+        };
+
+        let existing = dependencies
+            .children
+            .iter_mut()
+            .filter_map(|child| child.as_mut_element())
+            .find(|element| JavaDependency::from_element(element).is_same_artifact(&pinned));
+
+        if let Some(element) = existing {
+            pinned.update_element(element);
+            return;
+        }
+
+        let mut element = pinned.to_element();
+        if import {
+            element.children.push(XMLNode::Element(text_element("type", "pom")));
+            element.children.push(XMLNode::Element(text_element("scope", "import")));
+        }
+
+        dependencies.children.push(XMLNode::Element(element));
+    }
+
+    /// Sets the version of the entry at `index` of `<dependencyManagement>`,
+    /// counted the same way as in `get_managed_dependencies`.
+    pub fn set_managed_version(&mut self, index: usize, version: &str) {
+        let Some(element) = self
+            .root
+            .get_mut_child("dependencyManagement")
+            .and_then(|management| management.get_mut_child("dependencies"))
+            .and_then(|dependencies| nth_element_mut(dependencies, index))
+        else {
+            return;
+        };
+
+        let updated = JavaDependency { version: version.to_string(), ..JavaDependency::from_element(element) };
+        updated.update_element(element);
+    }
+
+    /// Removes the entry at `index` of `<dependencyManagement>`, counted the
+    /// same way as in `get_managed_dependencies`.
+    pub fn remove_managed_dependency(&mut self, index: usize) {
+        if let Some(dependencies) = self
+            .root
+            .get_mut_child("dependencyManagement")
+            .and_then(|management| management.get_mut_child("dependencies"))
+        {
+            remove_nth_element(dependencies, index);
+        }
+    }
+
+    fn plugins_element(&self, section: PluginSection) -> Option<&Element> {
+        let build = self.root.get_child("build")?;
+
+        return match section {
+            PluginSection::Build => build.get_child("plugins"),
+            PluginSection::Management => build.get_child("pluginManagement")?.get_child("plugins"),
+        };
+    }
+
+    fn plugins_element_mut(&mut self, section: PluginSection) -> &mut Element {
+        let build = child_or_insert(&mut self.root, "build");
+
+        return match section {
+            PluginSection::Build => child_or_insert(build, "plugins"),
+            PluginSection::Management => child_or_insert(child_or_insert(build, "pluginManagement"), "plugins"),
+        };
+    }
+
+    /// Plugins declared in a section of `<build>`. Plugins without a
+    /// `<groupId>` get the default one, as Maven does.
+    pub fn get_plugins(&self, section: PluginSection) -> Vec<JavaDependency> {
+        let Some(plugins) = self.plugins_element(section) else {
+            return vec![];
+        };
+
+        return plugins
+            .children
+            .iter()
+            .filter_map(|child| child.as_element())
+            .map(|element| {
+                let mut plugin = JavaDependency::from_element(element);
+                if plugin.group_id.is_empty() {
+                    plugin.group_id = DEFAULT_PLUGIN_GROUP_ID.to_string();
+                }
+                plugin
+            })
+            .collect();
+    }
+
+    /// Adds a plugin to a section, or updates its version when it is already declared.
+    pub fn add_plugin(&mut self, section: PluginSection, plugin: &JavaDependency) {
+        let index = self.get_plugins(section).iter().position(|existing| existing.is_same_artifact(plugin));
+        let plugins = self.plugins_element_mut(section);
+
+        match index.and_then(|index| nth_element_mut(plugins, index)) {
+            Some(element) => plugin.update_element(element),
+            None => plugins.children.push(XMLNode::Element(plugin.to_named_element("plugin"))),
+        }
+    }
+
+    pub fn set_plugin_version(&mut self, section: PluginSection, index: usize, version: &str) {
+        let Some(plugin) = self.get_plugins(section).into_iter().nth(index) else {
+            return;
+        };
+
+        let updated = JavaDependency { version: version.to_string(), ..plugin };
+        if let Some(element) = nth_element_mut(self.plugins_element_mut(section), index) {
+            updated.update_element(element);
+        }
+    }
+
+    pub fn remove_plugin(&mut self, section: PluginSection, index: usize) {
+        if self.plugins_element(section).is_some() {
+            remove_nth_element(self.plugins_element_mut(section), index);
+        }
+    }
+
+    /// Fields describing the project, with one set of fields per declared
+    /// license and developer.
+    pub fn project_fields(&self) -> Vec<(ProjectField, String)> {
+        let mut fields = vec![
+            ProjectField::GroupId,
+            ProjectField::ArtifactId,
+            ProjectField::Version,
+            ProjectField::Name,
+            ProjectField::Description,
+            ProjectField::Url,
+        ];
+
+        for list in [ProjectList::Licenses, ProjectList::Developers] {
+            let (list_tag, entry_tag) = list.tags();
+            let entries = self.root.get_child(list_tag).map(|entries| named_children(entries, entry_tag).count());
+
+            for index in 0..entries.unwrap_or(0) {
+                fields.extend(list.fields(index));
+            }
+        }
+
+        fields.extend([
+            ProjectField::ScmUrl,
+            ProjectField::ScmConnection,
+            ProjectField::ScmDeveloperConnection,
+            ProjectField::ScmTag,
+        ]);
+
+        return fields
+            .into_iter()
+            .map(|field| (field, self.get_project_field(field)))
+            .collect();
+    }
+
+    pub fn get_project_field(&self, field: ProjectField) -> String {
+        let mut element = &self.root;
+
+        for (name, index) in field.path() {
+            let Some(child) = named_children(element, name).nth(index) else {
+                return String::new();
+            };
+            element = child;
+        }
+
+        return element.get_text().map(|text| text.trim().to_string()).unwrap_or_default();
+    }
+
+    /// Writes a field, creating the elements leading to it when missing.
+    pub fn set_project_field(&mut self, field: ProjectField, value: &str) {
+        let path = field.path();
+        let Some(((name, _), parents)) = path.split_last() else {
+            return;
+        };
+        let mut element = &mut self.root;
+
+        for (parent, index) in parents {
+            if value.is_empty() && named_children(element, parent).count() <= *index {
+                return;
+            }
+            while named_children(element, parent).count() <= *index {
+                element.children.push(XMLNode::Element(Element::new(parent)));
+            }
+
+            element = element
+                .children
+                .iter_mut()
+                .filter_map(|child| child.as_mut_element())
+                .filter(|child| child.name == *parent)
+                .nth(*index)
+                .unwrap();
+        }
+
+        // An empty element would hide the value inherited from the parent.
+        if value.is_empty() {
+            element.take_child(*name);
+            return;
+        }
+
+        match element.get_mut_child(*name) {
+            Some(child) => child.children = vec![XMLNode::Text(value.to_string())],
+            None => element.children.push(XMLNode::Element(text_element(name, value))),
+        }
+    }
+
+    pub fn add_project_entry(&mut self, list: ProjectList) {
+        let (list_tag, entry_tag) = list.tags();
+
+        child_or_insert(&mut self.root, list_tag).children.push(XMLNode::Element(Element::new(entry_tag)));
+    }
+
+    pub fn remove_project_entry(&mut self, list: ProjectList, index: usize) {
+        if let Some(entries) = self.root.get_mut_child(list.tags().0) {
+            remove_nth_element(entries, index);
+        }
+    }
+
+    pub fn get_properties(&self) -> Vec<(String, String)> {
+        return self.to_pom().properties;
+    }
+
+    /// Sets the value of a property, adding it to `<properties>` when missing.
+    pub fn set_property(&mut self, name: &str, value: &str) {
+        let properties = child_or_insert(&mut self.root, "properties");
+        let property = child_or_insert(properties, name);
+
+        property.children = vec![XMLNode::Text(value.to_string())];
+    }
+
+    pub fn remove_property(&mut self, name: &str) {
+        if let Some(properties) = self.root.get_mut_child("properties") {
+            properties
+                .children
+                .retain(|child| child.as_element().is_none_or(|property| property.name != name));
+        }
+    }
+
+    /// Places each property is referenced from with `${name}`, keyed by the
+    /// property name. Locations start at the enclosing dependency or plugin
+    /// when there is one, e.g. `maven-compiler-plugin/configuration/release`.
+    pub fn property_references(&self) -> HashMap<String, Vec<String>> {
+        let mut references: HashMap<String, Vec<String>> = HashMap::new();
+
+        for element in self.root.children.iter().filter_map(|child| child.as_element()) {
+            collect_references(element, element.name.clone(), &mut references);
+        }
+
+        return references;
+    }
+
+    /// Moves the hardcoded version of a direct dependency into a new
+    /// `<artifactId>.version` property and references it instead. Returns
+    /// the name of the property, or `None` when the version is not a literal
+    /// or the name is taken by a property with another value.
+    pub fn extract_version_property(&mut self, dependency: &JavaDependency) -> Option<String> {
+        if dependency.version.is_empty() || dependency.version.contains("${") {
+            return None;
+        }
+
+        let properties = self.get_properties();
+        let name = [
+            format!("{}.version", dependency.artifact_id),
+            format!("{}.{}.version", dependency.group_id, dependency.artifact_id),
+        ]
+        .into_iter()
+        .find(|name| {
+            properties
+                .iter()
+                .all(|(existing, value)| existing != name || *value == dependency.version)
+        })?;
+
+        let element = self
+            .root
+            .get_mut_child("dependencies")?
+            .children
+            .iter_mut()
+            .filter_map(|child| child.as_mut_element())
+            .find(|element| JavaDependency::from_element(element).is_same_artifact(dependency))?;
+
+        let reference = JavaDependency { version: format!("${{{}}}", name), ..dependency.clone() };
+        reference.update_element(element);
+        self.set_property(&name, &dependency.version);
+
+        return Some(name);
+    }
+
+    pub fn get_profiles(&self) -> Vec<Profile> {
+        let Some(profiles) = self.root.get_child("profiles") else {
+            return vec![];
+        };
+
+        return profiles
+            .children
+            .iter()
+            .filter_map(|child| child.as_element())
+            .map(Profile::from_element)
+            .collect();
+    }
+
+    /// `<dependencies>` of the profile at `index` of `<profiles>`, created when missing.
+    fn profile_dependencies_mut(&mut self, profile: usize) -> Option<&mut Element> {
+        let profile = nth_element_mut(self.root.get_mut_child("profiles")?, profile)?;
+
+        return Some(child_or_insert(profile, "dependencies"));
+    }
+
+    /// Adds a dependency to a profile, or updates its version when the
+    /// profile already declares it.
+    pub fn add_profile_dependency(&mut self, profile: usize, dependency: &JavaDependency) {
+        let Some(dependencies) = self.profile_dependencies_mut(profile) else {
+            return;
+        };
+
+        let existing = dependencies
+            .children
+            .iter_mut()
+            .filter_map(|child| child.as_mut_element())
+            .find(|element| JavaDependency::from_element(element).is_same_artifact(dependency));
+
+        match existing {
+            Some(element) => dependency.update_element(element),
+            None => dependencies.children.push(XMLNode::Element(dependency.to_element())),
+        }
+    }
+
+    pub fn set_profile_dependency_version(&mut self, profile: usize, index: usize, version: &str) {
+        let Some(element) = self
+            .profile_dependencies_mut(profile)
+            .and_then(|dependencies| nth_element_mut(dependencies, index))
+        else {
+            return;
+        };
+
+        let updated = JavaDependency { version: version.to_string(), ..JavaDependency::from_element(element) };
+        updated.update_element(element);
+    }
+
+    pub fn remove_profile_dependency(&mut self, profile: usize, index: usize) {
+        if let Some(dependencies) = self.profile_dependencies_mut(profile) {
+            remove_nth_element(dependencies, index);
+        }
+    }
+
+    /// Adds an `<exclusion>` to a direct dependency. Returns `false` when the
+    /// dependency is not declared in `<dependencies>`.
+    pub fn add_exclusion(&mut self, dependency: &JavaDependency, group_id: &str, artifact_id: &str) -> bool {
+        let Some(element) = self
+            .root
+            .get_mut_child("dependencies")
+            .and_then(|dependencies| {
+                dependencies
+                    .children
+                    .iter_mut()
+                    .filter_map(|child| child.as_mut_element())
+                    .find(|element| JavaDependency::from_element(element).is_same_artifact(dependency))
+            })
+        else {
+            return false;
+        };
+
+        let exclusions = child_or_insert(element, "exclusions");
+
+        let already_excluded = exclusions
+            .children
+            .iter()
+            .filter_map(|child| child.as_element())
+            .any(|exclusion| {
+                let excluded = JavaDependency::from_element(exclusion);
+                excluded.group_id == group_id && excluded.artifact_id == artifact_id
+            });
+
+        if !already_excluded {
+            let mut exclusion = Element::new("exclusion");
+            exclusion.children.push(XMLNode::Element(text_element("groupId", group_id)));
+            exclusion.children.push(XMLNode::Element(text_element("artifactId", artifact_id)));
+            exclusions.children.push(XMLNode::Element(exclusion));
+        }
+
+        return true;
+    }
+
+    pub fn update_xml_file(&self) -> Result<()> {
         let current_xml_file_path = self.file_path.clone();
         let backup_xml_file_path = format!("{}.old", self.file_path);
 
         let _ = fs::rename(&current_xml_file_path, backup_xml_file_path);
 
-        let _ = &self
-            .root
-            .write(File::create_new(current_xml_file_path).unwrap());
+        let file = File::create_new(&current_xml_file_path)
+            .with_context(|| format!("cannot write {}", current_xml_file_path))?;
+        self.root
+            .write(file)
+            .with_context(|| format!("cannot write {}", current_xml_file_path))?;
+
+        return Ok(());
     }
 
     pub fn search_project_maven_file() -> Result<MavenFile> {
@@ -90,6 +622,180 @@ impl MavenFile {
     }
 }
 
+/// Group id Maven assumes for plugins declared without one.
+pub const DEFAULT_PLUGIN_GROUP_ID: &str = "org.apache.maven.plugins";
+
+/// Orders the dependency declarations can be sorted in.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum DeclarationOrder {
+    Alphabetical,
+    /// By scope, then alphabetically within a scope.
+    Scope,
+}
+
+/// Scopes in the order declarations are sorted by.
+pub const SCOPE_ORDER: &[&str] = &["compile", "provided", "runtime", "test", "system", "import"];
+
+/// Position of a scope in `SCOPE_ORDER`, with no scope meaning `compile`.
+pub fn scope_rank(scope: &str) -> usize {
+    let scope = if scope.is_empty() { "compile" } else { scope };
+    return SCOPE_ORDER.iter().position(|known| *known == scope).unwrap_or(SCOPE_ORDER.len());
+}
+
+/// Sorts dependencies, stably. `pom` gives the declared scopes; dependencies
+/// not written yet count as `compile`.
+pub fn sort_declarations(dependencies: &mut [JavaDependency], order: DeclarationOrder, pom: &Pom) {
+    let scope_of = |dependency: &JavaDependency| {
+        let declared = pom.dependencies.iter().find(|declared| {
+            declared.group_id == dependency.group_id && declared.artifact_id == dependency.artifact_id
+        });
+        return scope_rank(declared.map_or("", |declared| declared.scope.as_str()));
+    };
+
+    dependencies.sort_by_cached_key(|dependency| {
+        let scope = match order {
+            DeclarationOrder::Alphabetical => 0,
+            DeclarationOrder::Scope => scope_of(dependency),
+        };
+        (scope, dependency.group_id.to_lowercase(), dependency.artifact_id.to_lowercase())
+    });
+}
+
+/// The two places of `<build>` plugins are declared in.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum PluginSection {
+    /// `<build><plugins>`
+    Build,
+    /// `<build><pluginManagement><plugins>`
+    Management,
+}
+
+fn named_children<'a>(parent: &'a Element, name: &'a str) -> impl Iterator<Item = &'a Element> {
+    return parent
+        .children
+        .iter()
+        .filter_map(|child| child.as_element())
+        .filter(move |child| child.name == name);
+}
+
+fn nth_element_mut(parent: &mut Element, index: usize) -> Option<&mut Element> {
+    return parent.children.iter_mut().filter_map(|child| child.as_mut_element()).nth(index);
+}
+
+/// Orders the declarations in `<dependencies>` like `order`. Comments and
+/// text right above a declaration move along with it, those after the last
+/// declaration stay at the end. Declarations missing from `order` go last.
+fn arrange_declarations(dependencies: &mut Element, order: &[JavaDependency]) {
+    let mut blocks: Vec<(usize, Vec<XMLNode>)> = vec![];
+    let mut pending: Vec<XMLNode> = vec![];
+
+    for child in std::mem::take(&mut dependencies.children) {
+        let position = child.as_element().map(|element| {
+            let dependency = JavaDependency::from_element(element);
+            order.iter().position(|ordered| ordered.is_same_artifact(&dependency)).unwrap_or(usize::MAX)
+        });
+
+        pending.push(child);
+        if let Some(position) = position {
+            blocks.push((position, std::mem::take(&mut pending)));
+        }
+    }
+
+    blocks.sort_by_key(|(position, _)| *position);
+
+    dependencies.children = blocks.into_iter().flat_map(|(_, nodes)| nodes).chain(pending).collect();
+}
+
+/// Removes the child element at `index`, ignoring text and comment nodes.
+fn remove_nth_element(parent: &mut Element, index: usize) {
+    let position = parent
+        .children
+        .iter()
+        .enumerate()
+        .filter(|(_, child)| child.as_element().is_some())
+        .nth(index)
+        .map(|(position, _)| position);
+
+    if let Some(position) = position {
+        parent.children.remove(position);
+    }
+}
+
+/// Records the `${name}` references in the text of `element` and its children.
+fn collect_references(element: &Element, location: String, references: &mut HashMap<String, Vec<String>>) {
+    for child in &element.children {
+        match child {
+            XMLNode::Text(text) => {
+                for name in referenced_properties(text) {
+                    let locations = references.entry(name.to_string()).or_default();
+                    if !locations.contains(&location) {
+                        locations.push(location.clone());
+                    }
+                }
+            }
+            XMLNode::Element(child) => {
+                let location = match child.name.as_str() {
+                    "dependency" | "plugin" | "extension" | "parent" => {
+                        let artifact_id = pom::child_text(child, "artifactId");
+                        if artifact_id.is_empty() { child.name.clone() } else { artifact_id }
+                    }
+                    name if element.name == "properties" => format!("property {}", name),
+                    name => format!("{}/{}", location, name),
+                };
+
+                collect_references(child, location, references);
+            }
+            _ => (),
+        }
+    }
+}
+
+/// Names of the properties referenced in a text, in order.
+fn referenced_properties(text: &str) -> Vec<&str> {
+    let mut names = vec![];
+    let mut rest = text;
+
+    while let Some(start) = rest.find("${") {
+        let Some(end) = rest[start..].find('}') else {
+            break;
+        };
+
+        names.push(&rest[start + 2..start + end]);
+        rest = &rest[start + end + 1..];
+    }
+
+    return names;
+}
+
+fn text_element(name: &str, value: &str) -> Element {
+    let mut element = Element::new(name);
+    element.children.push(XMLNode::Text(value.to_string()));
+
+    return element;
+}
+
+/// Sets the text of a child element, removing the child when `value` is empty.
+fn set_child_text(parent: &mut Element, name: &str, value: &str) {
+    if value.is_empty() {
+        parent.take_child(name);
+        return;
+    }
+
+    match parent.get_mut_child(name) {
+        Some(child) => child.children = vec![XMLNode::Text(value.to_string())],
+        None => parent.children.push(XMLNode::Element(text_element(name, value))),
+    }
+}
+
+/// Returns the named child element, appending an empty one when missing.
+fn child_or_insert<'a>(parent: &'a mut Element, name: &str) -> &'a mut Element {
+    if parent.get_child(name).is_none() {
+        parent.children.push(XMLNode::Element(Element::new(name)));
+    }
+
+    return parent.get_mut_child(name).unwrap();
+}
+
 impl<'a> JavaDependency {
     fn from_element(dependency: &'a Element) -> JavaDependency {
         let mut group_id = None;
@@ -97,7 +803,7 @@ impl<'a> JavaDependency {
         let mut version = None;
 
         for field in dependency.children.iter().filter_map(|f| f.as_element()) {
-            let value = field.children[0].as_text().unwrap_or("").to_string();
+            let value = field.get_text().unwrap_or_default().trim().to_string();
             match field.name.as_str() {
                 "groupId" => group_id = Some(value),
                 "artifactId" => artifact_id = Some(value),
@@ -114,6 +820,81 @@ impl<'a> JavaDependency {
     }
 }
 
+/// Parses `group:artifact[:version[:classifier]]` as written in Gradle
+/// scripts and typed into prompts, with the range of the version within the
+/// notation. Whitespace around the parts is ignored.
+pub fn parse_coordinates(notation: &str) -> Option<(JavaDependency, Option<Range<usize>>)> {
+    let mut parts = vec![];
+    let mut start = 0;
+
+    for part in notation.split(':') {
+        let trimmed = part.trim();
+        let trimmed_start = start + part.len() - part.trim_start().len();
+        parts.push((trimmed, trimmed_start..trimmed_start + trimmed.len()));
+        start += part.len() + 1;
+    }
+
+    let [(group_id, _), (artifact_id, _), rest @ ..] = &parts[..] else {
+        return None;
+    };
+    if group_id.is_empty() || artifact_id.is_empty() {
+        return None;
+    }
+
+    let (version, range) = match rest.first() {
+        Some((version, range)) => (version.to_string(), Some(range.clone())),
+        None => (String::new(), None),
+    };
+
+    let dependency = JavaDependency { group_id: group_id.to_string(), artifact_id: artifact_id.to_string(), version };
+
+    return Some((dependency, range));
+}
+
+impl JavaDependency {
+    /// Two declarations refer to the same artifact when group and artifact ids match.
+    pub fn is_same_artifact(&self, other: &JavaDependency) -> bool {
+        return self.group_id == other.group_id && self.artifact_id == other.artifact_id;
+    }
+
+    fn to_element(&self) -> Element {
+        return self.to_named_element("dependency");
+    }
+
+    fn to_named_element(&self, tag: &str) -> Element {
+        let mut element = Element::new(tag);
+
+        for (name, value) in [
+            ("groupId", &self.group_id),
+            ("artifactId", &self.artifact_id),
+            ("version", &self.version),
+        ] {
+            if value.is_empty() {
+                continue;
+            }
+
+            element.children.push(XMLNode::Element(text_element(name, value)));
+        }
+
+        return element;
+    }
+
+    /// Writes the version into an existing declaration, leaving versions managed
+    /// elsewhere (no `<version>` and no version set) untouched.
+    fn update_element(&self, element: &mut Element) {
+        if self.version.is_empty() {
+            return;
+        }
+
+        match element.get_mut_child("version") {
+            Some(version) => {
+                version.children = vec![XMLNode::Text(self.version.clone())];
+            }
+            None => element.children.push(XMLNode::Element(text_element("version", &self.version))),
+        }
+    }
+}
+
 impl ElementPredicate for JavaDependency {
     fn match_element(&self, e: &Element) -> bool {
         let dependency = JavaDependency::from_element(e);
@@ -135,3 +916,208 @@ impl Default for MavenFile {
         };
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn dependency(artifact_id: &str) -> JavaDependency {
+        return JavaDependency { group_id: "org.example".to_string(), artifact_id: artifact_id.to_string(), version: "1.0".to_string() };
+    }
+
+    #[test]
+    fn parses_coordinates_with_the_version_range() {
+        let (parsed, range) = parse_coordinates("org.example:lib:1.0:tests").unwrap();
+        assert_eq!(parsed, dependency("lib"));
+        assert_eq!(range, Some(16..19));
+
+        let (parsed, range) = parse_coordinates(" org.example : lib : 1.0 ").unwrap();
+        assert_eq!(parsed, dependency("lib"));
+        assert_eq!(&" org.example : lib : 1.0 "[range.unwrap()], "1.0");
+
+        let (parsed, range) = parse_coordinates("org.example:lib").unwrap();
+        assert_eq!((parsed.version.as_str(), range), ("", None));
+
+        assert_eq!(parse_coordinates("org.example"), None);
+        assert_eq!(parse_coordinates(":lib:1.0"), None);
+    }
+
+    #[test]
+    fn sets_the_version_of_the_selected_managed_entry() {
+        let root = Element::parse(
+            "<project><dependencyManagement><dependencies>\
+                <dependency><groupId>org.example</groupId><artifactId>lib</artifactId><version>1.0</version></dependency>\
+                <dependency><groupId>org.example</groupId><artifactId>lib</artifactId><version>1.0</version><classifier>tests</classifier></dependency>\
+            </dependencies></dependencyManagement></project>"
+                .as_bytes(),
+        )
+        .unwrap();
+        let mut maven_file = MavenFile { root, file_path: String::new() };
+
+        maven_file.set_managed_version(1, "2.0");
+
+        let managed = maven_file.get_managed_dependencies();
+        assert_eq!((managed[0].version.as_str(), managed[1].version.as_str()), ("1.0", "2.0"));
+        assert_eq!(managed[1].classifier, "tests");
+    }
+
+    #[test]
+    fn removes_cleared_project_fields() {
+        let mut maven_file = maven_file(
+            "<project><parent><groupId>org.example</groupId><artifactId>parent</artifactId><version>1.0</version></parent>\
+                <groupId>org.example</groupId><artifactId>app</artifactId><version>1.0</version></project>",
+        );
+
+        maven_file.set_project_field(ProjectField::GroupId, "");
+        maven_file.set_project_field(ProjectField::Version, "2.0");
+        maven_file.set_project_field(ProjectField::LicenseName(0), "");
+        maven_file.set_project_field(ProjectField::ScmUrl, "https://example.org/app");
+
+        assert!(maven_file.root.get_child("groupId").is_none());
+        assert!(maven_file.root.get_child("licenses").is_none());
+        assert_eq!(maven_file.root.get_child("parent").unwrap().get_child("groupId").unwrap().get_text().unwrap(), "org.example");
+
+        let pom = maven_file.to_pom();
+        assert_eq!((pom.group_id(), pom.version.as_str()), ("org.example", "2.0"));
+        assert_eq!(pom::child_text(maven_file.root.get_child("scm").unwrap(), "url"), "https://example.org/app");
+    }
+
+    fn maven_file(xml: &str) -> MavenFile {
+        return MavenFile { root: Element::parse(xml.as_bytes()).unwrap(), file_path: String::new() };
+    }
+
+    #[test]
+    fn finds_referenced_property_names() {
+        assert_eq!(referenced_properties("${a}-${b.c}"), vec!["a", "b.c"]);
+        assert_eq!(referenced_properties("1.0"), Vec::<&str>::new());
+        assert_eq!(referenced_properties("${a} ${unterminated"), vec!["a"]);
+    }
+
+    #[test]
+    fn locates_property_references() {
+        let maven_file = maven_file(
+            "<project>\
+                <properties><java.version>21</java.version><release>${java.version}</release></properties>\
+                <dependencies><dependency><groupId>org.example</groupId><artifactId>lib</artifactId><version>${lib.version}</version></dependency></dependencies>\
+                <build><plugins><plugin><artifactId>maven-compiler-plugin</artifactId>\
+                    <configuration><release>${java.version}</release></configuration>\
+                </plugin></plugins></build>\
+            </project>",
+        );
+
+        let references = maven_file.property_references();
+
+        assert_eq!(references["lib.version"], vec!["lib/version"]);
+        assert_eq!(
+            references["java.version"],
+            vec!["property release", "maven-compiler-plugin/configuration/release"]
+        );
+    }
+
+    #[test]
+    fn extracts_versions_into_free_property_names() {
+        let mut maven_file = maven_file(
+            "<project>\
+                <properties><lib.version>2.0</lib.version><other.version>1.0</other.version></properties>\
+                <dependencies>\
+                    <dependency><groupId>org.example</groupId><artifactId>lib</artifactId><version>1.0</version></dependency>\
+                    <dependency><groupId>org.example</groupId><artifactId>other</artifactId><version>1.0</version></dependency>\
+                    <dependency><groupId>org.example</groupId><artifactId>managed</artifactId><version>${managed.version}</version></dependency>\
+                </dependencies>\
+            </project>",
+        );
+
+        let name = maven_file.extract_version_property(&dependency("lib"));
+        assert_eq!(name.as_deref(), Some("org.example.lib.version"));
+
+        let name = maven_file.extract_version_property(&dependency("other"));
+        assert_eq!(name.as_deref(), Some("other.version"));
+
+        let managed = JavaDependency { version: "${managed.version}".to_string(), ..dependency("managed") };
+        assert_eq!(maven_file.extract_version_property(&managed), None);
+
+        let properties = maven_file.get_properties();
+        assert!(properties.contains(&("lib.version".to_string(), "2.0".to_string())));
+        assert!(properties.contains(&("org.example.lib.version".to_string(), "1.0".to_string())));
+        assert!(properties.contains(&("other.version".to_string(), "1.0".to_string())));
+
+        let versions: Vec<String> = maven_file.get_dependencies().unwrap().into_iter().map(|dependency| dependency.version).collect();
+        assert_eq!(versions, vec!["${org.example.lib.version}", "${other.version}", "${managed.version}"]);
+    }
+
+    #[test]
+    fn moves_comments_along_with_their_declaration() {
+        let mut dependencies = Element::parse(
+            "<dependencies>\
+                <!-- first --><dependency><groupId>org.example</groupId><artifactId>a</artifactId></dependency>\
+                <!-- second --><dependency><groupId>org.example</groupId><artifactId>b</artifactId></dependency>\
+                <dependency><groupId>org.example</groupId><artifactId>c</artifactId></dependency>\
+                <!-- end -->\
+            </dependencies>"
+                .as_bytes(),
+        )
+        .unwrap();
+
+        arrange_declarations(&mut dependencies, &[dependency("c"), dependency("b"), dependency("a")]);
+
+        let nodes: Vec<String> = dependencies
+            .children
+            .iter()
+            .filter_map(|child| match child {
+                XMLNode::Comment(comment) => Some(comment.trim().to_string()),
+                XMLNode::Element(element) => Some(JavaDependency::from_element(element).artifact_id),
+                _ => None,
+            })
+            .collect();
+
+        assert_eq!(nodes, vec!["c", "second", "b", "first", "a", "end"]);
+    }
+
+    #[test]
+    fn rewrites_only_changed_parts_of_a_declaration() {
+        let root = Element::parse(
+            "<project><dependencies>\
+                <dependency><groupId>org.example</groupId><artifactId>a</artifactId><version>1.0</version>\
+                <!-- keep --><scope>test</scope><exclusions><exclusion><groupId>x</groupId><artifactId>y</artifactId></exclusion></exclusions></dependency>\
+            </dependencies></project>"
+                .as_bytes(),
+        )
+        .unwrap();
+        let mut maven_file = MavenFile { root, file_path: String::new() };
+
+        let declared = PomDependency {
+            group_id: "org.example".to_string(),
+            artifact_id: "a".to_string(),
+            version: "1.1".to_string(),
+            scope: "compile".to_string(),
+            optional: true,
+            ..Default::default()
+        };
+        assert!(maven_file.set_declared_dependency(&declared));
+
+        let element = maven_file.root.get_child("dependencies").unwrap().get_child("dependency").unwrap();
+        let written = PomDependency::from_element(element);
+        assert_eq!(written, PomDependency { scope: String::new(), ..declared });
+        assert!(element.children.iter().any(|child| matches!(child, XMLNode::Comment(comment) if comment.trim() == "keep")));
+    }
+
+    #[test]
+    fn sorts_by_scope_then_name() {
+        let pom = Pom::from_element(
+            &Element::parse(
+                "<project><dependencies>\
+                    <dependency><groupId>org.example</groupId><artifactId>b</artifactId><scope>test</scope></dependency>\
+                    <dependency><groupId>org.example</groupId><artifactId>c</artifactId><scope>provided</scope></dependency>\
+                </dependencies></project>"
+                    .as_bytes(),
+            )
+            .unwrap(),
+        );
+
+        let mut dependencies = vec![dependency("b"), dependency("d"), dependency("c"), dependency("a")];
+        sort_declarations(&mut dependencies, DeclarationOrder::Scope, &pom);
+
+        let names: Vec<&str> = dependencies.iter().map(|dependency| dependency.artifact_id.as_str()).collect();
+        assert_eq!(names, vec!["a", "d", "c", "b"]);
+    }
+}
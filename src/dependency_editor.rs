@@ -0,0 +1,344 @@
+use crossterm::event::{Event, KeyCode};
+use std::collections::HashMap;
+
+use crate::{
+    batch::SCOPES,
+    events::Intent,
+    maven_version::MavenVersion,
+    pom::{Exclusion, PomDependency},
+    widgets::text_input::TextInput,
+};
+
+/// A part of a declaration the editor can change.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum EditorField {
+    Version,
+    Scope,
+    Optional,
+    Classifier,
+    Type,
+    Exclusions,
+}
+
+impl EditorField {
+    /// Everything a `<dependency>` of a POM can have changed.
+    pub const ALL: &[EditorField] = &[
+        EditorField::Version,
+        EditorField::Scope,
+        EditorField::Optional,
+        EditorField::Classifier,
+        EditorField::Type,
+        EditorField::Exclusions,
+    ];
+
+    pub fn label(&self) -> &'static str {
+        match self {
+            EditorField::Version => "Version",
+            EditorField::Scope => "Scope",
+            EditorField::Optional => "Optional",
+            EditorField::Classifier => "Classifier",
+            EditorField::Type => "Type",
+            EditorField::Exclusions => "Exclusions",
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+enum EditorAction {
+    NextField,
+    PreviousField,
+    NewerVersion,
+    OlderVersion,
+    NextScope,
+    PreviousScope,
+    ToggleOptional,
+    Apply,
+}
+
+/// A declaration as it was before an edit, for undoing it.
+#[derive(Debug, Clone)]
+pub struct DependencyEdit {
+    pub declared: PomDependency,
+    /// Configurations of the matching Gradle declarations, in script order.
+    /// Several of them, like `api` and `implementation`, share a Maven scope.
+    pub configurations: Vec<String>,
+}
+
+/// Form for changing a declared dependency, shown in a popup.
+#[derive(Debug, Clone)]
+pub struct DependencyEditor {
+    /// The declaration as it was when the editor was opened.
+    pub original: PomDependency,
+    /// Fields that can be changed, which depends on the build file.
+    pub fields: Vec<EditorField>,
+    pub focused: usize,
+    pub version: TextInput,
+    pub scope: String,
+    pub optional: bool,
+    pub classifier: TextInput,
+    pub type_: TextInput,
+    /// Comma separated `groupId:artifactId` pairs.
+    pub exclusions: TextInput,
+    /// Why some fields cannot be changed.
+    pub note: Option<&'static str>,
+}
+
+impl DependencyEditor {
+    pub fn new(original: PomDependency, fields: Vec<EditorField>) -> Self {
+        let input = |value: &str| {
+            let mut input = TextInput::new();
+            input.set_value(value);
+            return input;
+        };
+
+        let exclusions: Vec<String> = original
+            .exclusions
+            .iter()
+            .map(|exclusion| format!("{}:{}", exclusion.group_id, exclusion.artifact_id))
+            .collect();
+
+        return Self {
+            version: input(&original.version),
+            scope: if original.scope.is_empty() { "compile".to_string() } else { original.scope.clone() },
+            optional: original.optional,
+            classifier: input(&original.classifier),
+            type_: input(&original.type_),
+            exclusions: input(&exclusions.join(", ")),
+            original,
+            fields,
+            focused: 0,
+            note: None,
+        };
+    }
+
+    pub fn focused_field(&self) -> EditorField {
+        return self.fields.get(self.focused).copied().unwrap_or(EditorField::Version);
+    }
+
+    /// The declaration with the values entered so far.
+    pub fn edited(&self) -> PomDependency {
+        let scope = match (self.original.scope.is_empty(), self.scope.as_str()) {
+            (true, "compile") => String::new(),
+            (_, scope) => scope.to_string(),
+        };
+
+        return PomDependency {
+            version: self.version.value().trim().to_string(),
+            scope,
+            optional: self.optional,
+            classifier: self.classifier.value().trim().to_string(),
+            type_: self.type_.value().trim().to_string(),
+            exclusions: parse_exclusions(&self.exclusions.value()),
+            ..self.original.clone()
+        };
+    }
+
+    /// What keeps the edit from being applied. `versions` are the published
+    /// versions of the artifact, `None` while they are looked up.
+    pub fn problems(&self, versions: Option<&Result<Vec<String>, String>>) -> Vec<String> {
+        let mut problems = vec![];
+        let version = self.version.value().trim().to_string();
+
+        if version.is_empty() && !self.original.version.is_empty() {
+            problems.push("Version must not be empty".to_string());
+        }
+
+        // Properties and ranges cannot be checked against the registry.
+        let checkable = !version.is_empty() && !version.contains("${") && !version.starts_with(['[', '(']);
+        if checkable && version != self.original.version {
+            match versions {
+                None => problems.push("Looking up published versions…".to_string()),
+                Some(Ok(published)) if !published.contains(&version) => {
+                    problems.push(format!("{} is not published to Maven Central", version));
+                }
+                Some(_) => {}
+            }
+        }
+
+        for (label, value) in [("Classifier", self.classifier.value()), ("Type", self.type_.value())] {
+            if value.trim().contains(char::is_whitespace) {
+                problems.push(format!("{} must not contain spaces", label));
+            }
+        }
+
+        for entry in self.exclusions.value().split(',').map(str::trim).filter(|entry| !entry.is_empty()) {
+            let valid = entry
+                .split_once(':')
+                .is_some_and(|(group_id, artifact_id)| is_coordinate(group_id) && is_coordinate(artifact_id));
+
+            if !valid {
+                problems.push(format!("Exclusion '{}' is not groupId:artifactId", entry));
+            }
+        }
+
+        return problems;
+    }
+
+    fn get_mapping(&self) -> HashMap<KeyCode, EditorAction> {
+        let mut mapping = HashMap::from([
+            (KeyCode::Tab, EditorAction::NextField),
+            (KeyCode::BackTab, EditorAction::PreviousField),
+            (KeyCode::Enter, EditorAction::Apply),
+        ]);
+
+        match self.focused_field() {
+            EditorField::Version => {
+                mapping.insert(KeyCode::Up, EditorAction::NewerVersion);
+                mapping.insert(KeyCode::Down, EditorAction::OlderVersion);
+            }
+            EditorField::Scope => {
+                mapping.insert(KeyCode::Right, EditorAction::NextScope);
+                mapping.insert(KeyCode::Left, EditorAction::PreviousScope);
+                mapping.insert(KeyCode::Char(' '), EditorAction::NextScope);
+            }
+            EditorField::Optional => {
+                mapping.insert(KeyCode::Char(' '), EditorAction::ToggleOptional);
+            }
+            EditorField::Classifier | EditorField::Type | EditorField::Exclusions => {}
+        }
+
+        return mapping;
+    }
+
+    fn focused_input(&mut self) -> Option<&mut TextInput> {
+        match self.focused_field() {
+            EditorField::Version => return Some(&mut self.version),
+            EditorField::Classifier => return Some(&mut self.classifier),
+            EditorField::Type => return Some(&mut self.type_),
+            EditorField::Exclusions => return Some(&mut self.exclusions),
+            EditorField::Scope | EditorField::Optional => return None,
+        }
+    }
+
+    /// Replaces the version with the next newer or older published one.
+    fn pick_version(&mut self, published: &[String], newer: bool) {
+        let mut sorted: Vec<&String> = published.iter().collect();
+        sorted.sort_by_key(|version| std::cmp::Reverse(MavenVersion::parse(version)));
+
+        let current = self.version.value();
+        let picked = match sorted.iter().position(|version| **version == current) {
+            Some(index) if newer => index.checked_sub(1),
+            Some(index) => Some(index + 1),
+            None => Some(0),
+        };
+
+        if let Some(version) = picked.and_then(|index| sorted.get(index)) {
+            self.version.set_value(version);
+        }
+    }
+
+    fn cycle_scope(&mut self, forward: bool) {
+        // Scopes like `system` are kept selectable when declared.
+        let mut scopes: Vec<&str> = SCOPES.to_vec();
+        if !self.original.scope.is_empty() && !scopes.contains(&self.original.scope.as_str()) {
+            scopes.push(&self.original.scope);
+        }
+
+        let index = scopes.iter().position(|scope| *scope == self.scope).unwrap_or(0);
+        let next = match forward {
+            true => (index + 1) % scopes.len(),
+            false => (index + scopes.len() - 1) % scopes.len(),
+        };
+
+        self.scope = scopes[next].to_string();
+    }
+
+    pub fn handle_event(&mut self, event: &Event, versions: Option<&Result<Vec<String>, String>>) -> Option<Intent> {
+        let Event::Key(key_event) = event else {
+            if let Some(input) = self.focused_input() {
+                input.handle_event(event);
+            }
+            return None;
+        };
+
+        let Some(action) = self.get_mapping().get(&key_event.code).copied() else {
+            if let Some(input) = self.focused_input() {
+                input.handle_event(event);
+            }
+            return None;
+        };
+
+        match action {
+            EditorAction::NextField => self.focused = (self.focused + 1) % self.fields.len().max(1),
+            EditorAction::PreviousField => self.focused = (self.focused + self.fields.len().max(1) - 1) % self.fields.len().max(1),
+            EditorAction::NewerVersion | EditorAction::OlderVersion => {
+                if let Some(Ok(published)) = versions {
+                    self.pick_version(published, matches!(action, EditorAction::NewerVersion));
+                }
+            }
+            EditorAction::NextScope => self.cycle_scope(true),
+            EditorAction::PreviousScope => self.cycle_scope(false),
+            EditorAction::ToggleOptional => self.optional = !self.optional,
+            EditorAction::Apply => return Some(Intent::ApplyDependencyEdit),
+        }
+
+        return None;
+    }
+}
+
+fn is_coordinate(value: &str) -> bool {
+    return !value.is_empty() && !value.contains(char::is_whitespace);
+}
+
+/// Reads comma separated `groupId:artifactId` pairs, skipping malformed ones.
+fn parse_exclusions(text: &str) -> Vec<Exclusion> {
+    return text
+        .split(',')
+        .filter_map(|entry| entry.trim().split_once(':'))
+        .map(|(group_id, artifact_id)| Exclusion { group_id: group_id.trim().to_string(), artifact_id: artifact_id.trim().to_string() })
+        .collect();
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn editor() -> DependencyEditor {
+        let original = PomDependency {
+            group_id: "org.example".to_string(),
+            artifact_id: "lib".to_string(),
+            version: "1.0".to_string(),
+            exclusions: vec![Exclusion { group_id: "commons-logging".to_string(), artifact_id: "*".to_string() }],
+            ..Default::default()
+        };
+
+        return DependencyEditor::new(original, EditorField::ALL.to_vec());
+    }
+
+    #[test]
+    fn validates_against_published_versions() {
+        let mut editor = editor();
+        let published = Ok(vec!["1.0".to_string(), "1.1".to_string(), "2.0".to_string()]);
+
+        assert!(editor.problems(None).is_empty());
+
+        editor.version.set_value("1.5");
+        assert_eq!(editor.problems(Some(&published)), vec!["1.5 is not published to Maven Central".to_string()]);
+        assert_eq!(editor.problems(None), vec!["Looking up published versions…".to_string()]);
+        assert!(editor.problems(Some(&Err("offline".to_string()))).is_empty());
+
+        editor.pick_version(published.as_ref().unwrap(), true);
+        assert_eq!(editor.version.value(), "2.0");
+        editor.pick_version(published.as_ref().unwrap(), false);
+        assert_eq!(editor.version.value(), "1.1");
+        assert!(editor.problems(Some(&published)).is_empty());
+
+        editor.exclusions.set_value("commons-logging:*, broken");
+        assert_eq!(editor.problems(Some(&published)).len(), 1);
+    }
+
+    #[test]
+    fn keeps_unchanged_fields_as_declared() {
+        let mut editor = editor();
+        assert_eq!(editor.edited(), editor.original);
+
+        editor.cycle_scope(true);
+        editor.optional = true;
+        editor.exclusions.set_value("");
+
+        let edited = editor.edited();
+        assert_eq!(edited.scope, "provided");
+        assert!(edited.optional);
+        assert!(edited.exclusions.is_empty());
+    }
+}
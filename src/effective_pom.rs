@@ -0,0 +1,557 @@
+use anyhow::{Result, bail};
+use std::{collections::HashMap, io, path::PathBuf};
+use xmltree::{Element, XMLNode};
+
+use crate::{
+    dependency::DEFAULT_PLUGIN_GROUP_ID,
+    pom::{Pom, PomDependency},
+    profile::{self, ActivationContext, Profile},
+    resolver::{self, MAX_PARENT_DEPTH, ModelBuilder, PomSource},
+};
+
+/// Source of the elements declared in the project's own POM.
+pub const PROJECT_SOURCE: &str = "pom.xml";
+
+/// Elements a project does not inherit from its parent.
+const NOT_INHERITED: &[&str] = &["artifactId", "name", "packaging", "parent", "prerequisites", "profiles", "modules"];
+
+/// Lists a child declares as a whole, replacing the inherited entries.
+const REPLACED_LISTS: &[&str] = &[
+    "licenses",
+    "developers",
+    "contributors",
+    "mailingLists",
+    "exclusions",
+    "modules",
+    "goals",
+    "resources",
+    "testResources",
+    "filters",
+];
+
+/// Order of the `<project>` children in Maven's model, used for the output.
+const PROJECT_ORDER: &[&str] = &[
+    "modelVersion",
+    "parent",
+    "groupId",
+    "artifactId",
+    "version",
+    "packaging",
+    "name",
+    "description",
+    "url",
+    "inceptionYear",
+    "organization",
+    "licenses",
+    "developers",
+    "contributors",
+    "mailingLists",
+    "prerequisites",
+    "modules",
+    "scm",
+    "issueManagement",
+    "ciManagement",
+    "distributionManagement",
+    "properties",
+    "dependencyManagement",
+    "dependencies",
+    "repositories",
+    "pluginRepositories",
+    "build",
+    "reporting",
+];
+
+/// An element of the effective POM, annotated with the POM it comes from.
+#[derive(Debug, Clone, PartialEq)]
+pub struct EffectiveElement {
+    pub name: String,
+    pub text: String,
+    pub source: String,
+    pub children: Vec<EffectiveElement>,
+}
+
+impl EffectiveElement {
+    pub fn from_element(element: &Element, source: &str) -> EffectiveElement {
+        let text: String = element
+            .children
+            .iter()
+            .filter_map(|child| match child {
+                XMLNode::Text(text) | XMLNode::CData(text) => Some(text.as_str()),
+                _ => None,
+            })
+            .collect();
+
+        return EffectiveElement {
+            name: element.name.clone(),
+            text: text.trim().to_string(),
+            source: source.to_string(),
+            children: element
+                .children
+                .iter()
+                .filter_map(|child| child.as_element())
+                .map(|child| EffectiveElement::from_element(child, source))
+                .collect(),
+        };
+    }
+
+    fn leaf(name: &str, text: &str, source: &str) -> EffectiveElement {
+        return EffectiveElement {
+            name: name.to_string(),
+            text: text.to_string(),
+            source: source.to_string(),
+            children: vec![],
+        };
+    }
+
+    pub fn child(&self, name: &str) -> Option<&EffectiveElement> {
+        return self.children.iter().find(|child| child.name == name);
+    }
+
+    fn child_mut(&mut self, name: &str) -> Option<&mut EffectiveElement> {
+        return self.children.iter_mut().find(|child| child.name == name);
+    }
+
+    fn child_text(&self, name: &str) -> &str {
+        return self.child(name).map(|child| child.text.as_str()).unwrap_or_default();
+    }
+
+    fn has_repeated_children(&self) -> bool {
+        return self
+            .children
+            .iter()
+            .enumerate()
+            .any(|(i, child)| self.children[..i].iter().any(|previous| previous.name == child.name));
+    }
+
+    fn interpolate(&mut self, properties: &HashMap<String, String>) {
+        self.text = resolver::interpolate(&self.text, properties);
+
+        for child in self.children.iter_mut() {
+            child.interpolate(properties);
+        }
+    }
+}
+
+/// Key matching an entry of a list against the inherited entries, for lists
+/// whose entries can be overridden one by one.
+fn entry_key(list: &str, entry: &EffectiveElement) -> Option<String> {
+    match list {
+        "dependencies" => {
+            let type_ = Some(entry.child_text("type")).filter(|type_| !type_.is_empty()).unwrap_or("jar");
+            return Some(format!(
+                "{}:{}:{}:{}",
+                entry.child_text("groupId"),
+                entry.child_text("artifactId"),
+                type_,
+                entry.child_text("classifier"),
+            ));
+        }
+        "plugins" => {
+            let group_id = Some(entry.child_text("groupId")).filter(|id| !id.is_empty()).unwrap_or(DEFAULT_PLUGIN_GROUP_ID);
+            return Some(format!("{}:{}", group_id, entry.child_text("artifactId")));
+        }
+        "executions" => return Some(Some(entry.child_text("id")).filter(|id| !id.is_empty()).unwrap_or("default").to_string()),
+        "repositories" | "pluginRepositories" => return Some(entry.child_text("id").to_string()),
+        _ => return None,
+    }
+}
+
+/// Merges `child` over `parent`: values of the child win, containers are
+/// merged element by element and keyed lists entry by entry.
+fn merge(parent: &EffectiveElement, child: &EffectiveElement) -> EffectiveElement {
+    let mut merged = EffectiveElement { children: vec![], ..child.clone() };
+
+    // A declared value, even an empty one, hides the inherited value.
+    if child.children.is_empty() && (!child.text.is_empty() || parent.children.is_empty()) {
+        return child.clone();
+    }
+
+    let keyed = child
+        .children
+        .iter()
+        .chain(parent.children.iter())
+        .all(|entry| entry_key(&child.name, entry).is_some());
+
+    if REPLACED_LISTS.contains(&child.name.as_str()) || (!keyed && (parent.has_repeated_children() || child.has_repeated_children())) {
+        merged.children = if child.children.is_empty() { parent.children.clone() } else { child.children.clone() };
+        return merged;
+    }
+
+    let matches = |inherited: &EffectiveElement, declared: &EffectiveElement| -> bool {
+        match keyed {
+            true => entry_key(&child.name, inherited) == entry_key(&child.name, declared),
+            false => inherited.name == declared.name,
+        }
+    };
+
+    merged.children = parent
+        .children
+        .iter()
+        .map(|inherited| match child.children.iter().find(|declared| matches(inherited, declared)) {
+            Some(declared) => merge(inherited, declared),
+            None => inherited.clone(),
+        })
+        .collect();
+
+    for declared in &child.children {
+        if !parent.children.iter().any(|inherited| matches(inherited, declared)) {
+            merged.children.push(declared.clone());
+        }
+    }
+
+    return merged;
+}
+
+/// Applies the inheritance of a project from its (effective) parent.
+fn inherit(parent: &EffectiveElement, child: &EffectiveElement) -> EffectiveElement {
+    let mut inherited = parent.clone();
+    inherited.children.retain(|element| !NOT_INHERITED.contains(&element.name.as_str()));
+
+    let mut merged = merge(&inherited, child);
+    merged.children.sort_by_key(|element| PROJECT_ORDER.iter().position(|name| *name == element.name).unwrap_or(PROJECT_ORDER.len()));
+
+    return merged;
+}
+
+/// Converts a POM, with its active profiles merged into it.
+fn with_profiles(element: &Element, source: &str, context: &ActivationContext) -> EffectiveElement {
+    let mut model = EffectiveElement::from_element(element, source);
+    model.children.retain(|child| child.name != "profiles");
+
+    let Some(profiles) = element.get_child("profiles") else {
+        return model;
+    };
+
+    let profile_elements: Vec<&Element> = profiles.children.iter().filter_map(|child| child.as_element()).collect();
+    let declared: Vec<Profile> = profile_elements.iter().map(|profile| Profile::from_element(profile)).collect();
+    let statuses = profile::evaluate(&declared, context);
+
+    for ((profile_element, declared), status) in profile_elements.iter().zip(&declared).zip(statuses) {
+        if !status.is_active() {
+            continue;
+        }
+
+        let mut injected = EffectiveElement::from_element(profile_element, &format!("{} (profile {})", source, declared.id));
+        injected.name = model.name.clone();
+        injected.children.retain(|child| child.name != "id" && child.name != "activation");
+
+        let source = model.source.clone();
+        model = merge(&model, &injected);
+        model.source = source;
+    }
+
+    return model;
+}
+
+/// Properties available to interpolation, the ones of the model winning
+/// over the environment.
+fn interpolation_properties(model: &EffectiveElement, context: &ActivationContext) -> HashMap<String, String> {
+    let mut properties = context.properties.clone();
+
+    if let Some(declared) = model.child("properties") {
+        for property in &declared.children {
+            properties.insert(property.name.clone(), property.text.clone());
+        }
+    }
+
+    for field in ["groupId", "artifactId", "version", "name", "description", "url", "packaging"] {
+        properties.insert(format!("project.{}", field), model.child_text(field).to_string());
+        properties.insert(format!("pom.{}", field), model.child_text(field).to_string());
+    }
+
+    if let Some(parent) = model.child("parent") {
+        for field in ["groupId", "artifactId", "version"] {
+            properties.insert(format!("project.parent.{}", field), parent.child_text(field).to_string());
+        }
+    }
+
+    let base_dir = context.base_dir.to_string_lossy().to_string();
+    properties.insert("project.basedir".to_string(), base_dir.clone());
+    properties.insert("basedir".to_string(), base_dir);
+
+    return properties;
+}
+
+/// Builds the effective model from a POM and its parents, ordered from the
+/// project up, without BOM imports.
+pub fn assemble(chain: &[(String, Element)], context: &ActivationContext) -> EffectiveElement {
+    let mut models = chain.iter().rev().map(|(source, element)| with_profiles(element, source, context));
+
+    let Some(mut effective) = models.next() else {
+        return EffectiveElement::leaf("project", "", PROJECT_SOURCE);
+    };
+
+    for model in models {
+        effective = inherit(&effective, &model);
+    }
+
+    // The project's `<parent>` is not inherited, so it is taken over here.
+    if let Some((_, project)) = chain.first()
+        && effective.child("parent").is_none()
+        && let Some(parent) = project.get_child("parent")
+    {
+        effective.children.insert(0, EffectiveElement::from_element(parent, PROJECT_SOURCE));
+    }
+
+    let properties = interpolation_properties(&effective, context);
+    effective.interpolate(&properties);
+
+    return effective;
+}
+
+/// Fills in versions, scopes and exclusions of dependencies from their
+/// `<dependencyManagement>` entries.
+pub fn apply_management(effective: &mut EffectiveElement) {
+    let Some(managed) = effective.child("dependencyManagement").and_then(|management| management.child("dependencies")).cloned() else {
+        return;
+    };
+
+    let Some(dependencies) = effective.child_mut("dependencies") else {
+        return;
+    };
+
+    for dependency in dependencies.children.iter_mut() {
+        let key = entry_key("dependencies", dependency);
+        let Some(entry) = managed.children.iter().find(|entry| entry_key("dependencies", entry) == key) else {
+            continue;
+        };
+
+        for field in ["version", "scope", "exclusions"] {
+            if dependency.child(field).is_none()
+                && let Some(value) = entry.child(field)
+            {
+                dependency.children.push(value.clone());
+            }
+        }
+    }
+}
+
+/// Replaces `<scope>import</scope>` entries of `<dependencyManagement>` by
+/// the entries of the BOMs. Imports that cannot be loaded are kept as they are.
+async fn import_boms(effective: &mut EffectiveElement, builder: &mut ModelBuilder) {
+    let Some(managed) = effective.child_mut("dependencyManagement").and_then(|management| management.child_mut("dependencies")) else {
+        return;
+    };
+
+    let mut entries: Vec<EffectiveElement> = vec![];
+    let mut imports: Vec<EffectiveElement> = vec![];
+
+    for entry in managed.children.drain(..) {
+        match entry.child_text("scope") == "import" && entry.child_text("type") == "pom" {
+            true => imports.push(entry),
+            false => entries.push(entry),
+        }
+    }
+
+    for import in imports {
+        let (group_id, artifact_id, version) = (import.child_text("groupId"), import.child_text("artifactId"), import.child_text("version"));
+
+        let Ok(bom) = builder.load_effective(group_id, artifact_id, version).await else {
+            entries.push(import);
+            continue;
+        };
+
+        let source = format!("{}:{}:{} (import)", group_id, artifact_id, version);
+        for dependency in &bom.dependency_management {
+            let entry = dependency_element(dependency, &source);
+            let key = entry_key("dependencies", &entry);
+
+            if !entries.iter().any(|existing| entry_key("dependencies", existing) == key) {
+                entries.push(entry);
+            }
+        }
+    }
+
+    managed.children = entries;
+}
+
+fn dependency_element(dependency: &PomDependency, source: &str) -> EffectiveElement {
+    let mut element = EffectiveElement::leaf("dependency", "", source);
+
+    for (name, value) in [
+        ("groupId", &dependency.group_id),
+        ("artifactId", &dependency.artifact_id),
+        ("version", &dependency.version),
+        ("type", &dependency.type_),
+        ("classifier", &dependency.classifier),
+        ("scope", &dependency.scope),
+    ] {
+        if !value.is_empty() {
+            element.children.push(EffectiveElement::leaf(name, value, source));
+        }
+    }
+
+    return element;
+}
+
+/// Reads the parents of the project, from disk through `<relativePath>` or
+/// from the repositories. The project comes first.
+async fn load_chain(root: Element, base_dir: Option<PathBuf>) -> Result<Vec<(String, Element)>> {
+    let source = PomSource::new();
+    let mut chain = vec![(PROJECT_SOURCE.to_string(), root)];
+    let mut base_dir = base_dir;
+
+    while let Some((_, element)) = chain.last()
+        && let Some(parent) = Pom::from_element(element).parent
+    {
+        if chain.len() > MAX_PARENT_DEPTH {
+            bail!("parent chain of the project is too deep");
+        }
+
+        let (content, parent_dir) = match PomSource::read_relative_parent(&parent, base_dir.as_deref()) {
+            Some(found) => found,
+            None => (source.load_content(&parent.group_id, &parent.artifact_id, &parent.version).await?, None),
+        };
+
+        let element = Element::parse(content.as_bytes()).map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))?;
+        chain.push((format!("{}:{}:{}", parent.group_id, parent.artifact_id, parent.version), element));
+        base_dir = parent_dir;
+    }
+
+    return Ok(chain);
+}
+
+/// Computes the effective POM of the project, as Maven sees it.
+pub async fn build(root: Element, base_dir: Option<PathBuf>, context: ActivationContext) -> Result<EffectiveElement> {
+    let chain = load_chain(root, base_dir).await?;
+    let mut effective = assemble(&chain, &context);
+
+    import_boms(&mut effective, &mut ModelBuilder::new()).await;
+    apply_management(&mut effective);
+
+    return Ok(effective);
+}
+
+/// Progress of building the effective POM.
+#[derive(Debug, Default)]
+pub enum EffectivePomState {
+    #[default]
+    NotStarted,
+    Building,
+    Built(EffectiveElement),
+    Failed(String),
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const PARENT: &str = r#"<project>
+        <groupId>org.example</groupId>
+        <artifactId>parent</artifactId>
+        <version>1.0</version>
+        <name>Parent</name>
+        <url>https://example.org</url>
+        <properties>
+            <java.version>11</java.version>
+            <lib.version>2.0</lib.version>
+        </properties>
+        <dependencyManagement>
+            <dependencies>
+                <dependency>
+                    <groupId>org.example</groupId>
+                    <artifactId>lib</artifactId>
+                    <version>${lib.version}</version>
+                    <scope>test</scope>
+                </dependency>
+            </dependencies>
+        </dependencyManagement>
+        <build>
+            <plugins>
+                <plugin>
+                    <artifactId>maven-compiler-plugin</artifactId>
+                    <version>3.11.0</version>
+                    <configuration><release>${java.version}</release></configuration>
+                </plugin>
+            </plugins>
+        </build>
+    </project>"#;
+
+    const PROJECT: &str = r#"<project>
+        <parent>
+            <groupId>org.example</groupId>
+            <artifactId>parent</artifactId>
+            <version>1.0</version>
+        </parent>
+        <artifactId>app</artifactId>
+        <url />
+        <properties>
+            <java.version>17</java.version>
+        </properties>
+        <dependencies>
+            <dependency>
+                <groupId>org.example</groupId>
+                <artifactId>lib</artifactId>
+            </dependency>
+        </dependencies>
+        <build>
+            <plugins>
+                <plugin>
+                    <groupId>org.apache.maven.plugins</groupId>
+                    <artifactId>maven-compiler-plugin</artifactId>
+                    <configuration><parameters>true</parameters></configuration>
+                </plugin>
+            </plugins>
+        </build>
+        <profiles>
+            <profile>
+                <id>ci</id>
+                <activation><property><name>env.CI</name></property></activation>
+                <properties><lib.version>2.1</lib.version></properties>
+            </profile>
+        </profiles>
+    </project>"#;
+
+    fn effective(environment: &[(&str, &str)]) -> EffectiveElement {
+        let context = ActivationContext {
+            properties: environment.iter().map(|(name, value)| (name.to_string(), value.to_string())).collect(),
+            ..Default::default()
+        };
+        let chain = vec![
+            (PROJECT_SOURCE.to_string(), Element::parse(PROJECT.as_bytes()).unwrap()),
+            ("org.example:parent:1.0".to_string(), Element::parse(PARENT.as_bytes()).unwrap()),
+        ];
+
+        let mut effective = assemble(&chain, &context);
+        apply_management(&mut effective);
+
+        return effective;
+    }
+
+    #[test]
+    fn inherits_and_interpolates_parent_values() {
+        let effective = effective(&[]);
+
+        assert_eq!(effective.child_text("groupId"), "org.example");
+        assert_eq!(effective.child("groupId").unwrap().source, "org.example:parent:1.0");
+        assert_eq!(effective.child_text("artifactId"), "app");
+        assert_eq!(effective.child("name"), None);
+        assert_eq!(effective.child_text("url"), "");
+        assert_eq!(effective.child("profiles"), None);
+
+        let plugins = effective.child("build").unwrap().child("plugins").unwrap();
+        assert_eq!(plugins.children.len(), 1);
+        let configuration = plugins.children[0].child("configuration").unwrap();
+        assert_eq!(configuration.child_text("release"), "17");
+        assert_eq!(configuration.child("release").unwrap().source, "org.example:parent:1.0");
+        assert_eq!(configuration.child_text("parameters"), "true");
+        assert_eq!(plugins.children[0].child_text("version"), "3.11.0");
+    }
+
+    #[test]
+    fn applies_management_and_active_profiles() {
+        let dependency = |effective: &EffectiveElement| effective.child("dependencies").unwrap().children[0].clone();
+
+        let plain = dependency(&effective(&[]));
+        assert_eq!(plain.child_text("version"), "2.0");
+        assert_eq!(plain.child_text("scope"), "test");
+        assert_eq!(plain.child("version").unwrap().source, "org.example:parent:1.0");
+
+        let with_profile = effective(&[("env.CI", "true")]);
+        assert_eq!(dependency(&with_profile).child_text("version"), "2.1");
+        assert_eq!(
+            with_profile.child("properties").unwrap().child("lib.version").unwrap().source,
+            "pom.xml (profile ci)",
+        );
+    }
+}
@@ -1,23 +1,35 @@
 use anyhow::Result;
-use crossterm::event::{Event, KeyCode, KeyEvent};
-use std::collections::HashMap;
+use crossterm::event::{Event, KeyCode, KeyEvent, KeyModifiers};
+use std::{collections::HashMap, path::PathBuf, time::Duration};
 use tokio::sync::mpsc;
+use xmltree::Element;
 
 use crate::{
-    app::{AppState, UIState, InteractionMode},
+    app::{AppState, InteractionMode},
+    audit::{self, AdvisoryDatabase, AuditState},
+    batch::{self, BatchChange, BatchOperation},
+    dependency::{self, DeclarationOrder, JavaDependency, PluginSection},
+    dependency_editor::{DependencyEdit, DependencyEditor, EditorField},
+    effective_pom::{self, EffectiveElement, EffectivePomState},
+    license::{self, LicenseLookup, ReportRow},
     maven_registry::{
         MavenRegistry, 
-        MavenResponse, 
-        SearchResponseDoc, 
-        SearchResponse,
-        GetVersionsResponse
+        SearchResponse, 
+        GetVersionsResponse,
+        GetVersionsResponseDoc,
     },
+    pom::{Pom, PomDependency, PomMetadata},
+    popup::Popup,
+    profile::ActivationContext,
+    project_info::{self, Bump, ProjectField, ProjectList},
+    resolver::{self, DependencyTree, ModelBuilder, ResolutionState, Resolver},
+    search_query::SearchQuery,
     views::ViewId,
-    ui::Navigation,
 };
 
 pub struct EventContext<'a> {
     pub mode: InteractionMode,
+    #[allow(dead_code)]
     pub currently_focused_view: &'a ViewId
 }
 
@@ -44,40 +56,249 @@ pub enum Intent {
     LeaveInputMode,
     SubmitDependencyChanges,
     DeleteSelectedDependency { index: usize },
+    MoveDependency { from: usize, to: usize },
+    EditDependency { index: usize },
+    ApplyDependencyEdit,
+    UndoDependencyEdit,
+    SortDependencies(DeclarationOrder),
     FindNewDependencies(String),
+    SearchQueryChanged(String),
     GetAvailableDependencyVersions { index: usize },
+    LoadMoreDependencies,
+    LoadMoreDependencyVersions { index: usize },
+    GetArtifactDetails { index: usize, version_index: usize },
+    AddDependency { index: usize, version_index: usize },
+    ResolveDependencyTree,
+    PinManagedVersion { group_id: String, artifact_id: String, version: String },
+    AddExclusion {
+        dependency_group_id: String,
+        dependency_artifact_id: String,
+        group_id: String,
+        artifact_id: String,
+    },
+    ExplainDependency { group_id: String, artifact_id: String },
+    ExportLicenseReport,
+    AddManagedDependency { group_id: String, artifact_id: String, version: String, import: bool },
+    SetManagedVersion { index: usize, version: String },
+    RemoveManagedDependency { index: usize },
+    AddPlugin { section: PluginSection, group_id: String, artifact_id: String, version: String },
+    SetPluginVersion { section: PluginSection, index: usize, version: String },
+    RemovePlugin { section: PluginSection, index: usize },
+    CheckPluginUpdates,
+    CheckDependencyUpdates,
+    AddProfileDependency { profile: usize, group_id: String, artifact_id: String, version: String },
+    SetProfileDependencyVersion { profile: usize, index: usize, version: String },
+    RemoveProfileDependency { profile: usize, index: usize },
+    SetProperty { name: String, value: String },
+    RemoveProperty { name: String },
+    ExtractVersionProperty { index: usize },
+    SetProjectField { field: ProjectField, value: String },
+    BumpProjectVersion(Bump),
+    AddProjectEntry(ProjectList),
+    RemoveProjectEntry { list: ProjectList, index: usize },
+    BuildEffectivePom,
+    PlanBatch { operation: BatchOperation, artifacts: Vec<(String, String)> },
+    ApplyBatch,
     FocusNextView,
     FocusPreviousView,
-    HandleViewMapping(ViewId, Event)
+    ToggleHelp,
+}
+
+impl Intent {
+    pub fn description(&self) -> &'static str {
+        match self {
+            Intent::Exit => "Quit lazymaven",
+            Intent::EnterInputMode => "Start editing",
+            Intent::LeaveInputMode => "Stop editing",
+            Intent::SubmitDependencyChanges => "Write changes to pom.xml",
+            Intent::DeleteSelectedDependency { .. } => "Delete selected dependency",
+            Intent::MoveDependency { .. } => "Move dependency declaration",
+            Intent::EditDependency { .. } => "Edit dependency",
+            Intent::ApplyDependencyEdit => "Apply dependency edit",
+            Intent::UndoDependencyEdit => "Undo last dependency edit",
+            Intent::SortDependencies(_) => "Sort dependency declarations",
+            Intent::FindNewDependencies(_) => "Search Maven Central",
+            Intent::SearchQueryChanged(_) => "Search Maven Central while typing",
+            Intent::GetAvailableDependencyVersions { .. } => "Load available versions",
+            Intent::LoadMoreDependencies => "Load more search results",
+            Intent::LoadMoreDependencyVersions { .. } => "Load more versions",
+            Intent::GetArtifactDetails { .. } => "Load details of selected version",
+            Intent::AddDependency { .. } => "Add selected version to dependencies",
+            Intent::ResolveDependencyTree => "Resolve transitive dependencies",
+            Intent::PinManagedVersion { .. } => "Pin version in dependencyManagement",
+            Intent::AddExclusion { .. } => "Exclude transitive dependency",
+            Intent::ExplainDependency { .. } => "Show why an artifact is on the classpath",
+            Intent::ExportLicenseReport => "Export license report",
+            Intent::AddManagedDependency { .. } => "Add entry to dependencyManagement",
+            Intent::SetManagedVersion { .. } => "Change version of managed dependency",
+            Intent::RemoveManagedDependency { .. } => "Remove entry from dependencyManagement",
+            Intent::AddPlugin { .. } => "Add build plugin",
+            Intent::SetPluginVersion { .. } => "Change version of build plugin",
+            Intent::RemovePlugin { .. } => "Remove build plugin",
+            Intent::CheckPluginUpdates => "Check build plugins for newer versions",
+            Intent::CheckDependencyUpdates => "Check dependencies for newer versions",
+            Intent::AddProfileDependency { .. } => "Add dependency to profile",
+            Intent::SetProfileDependencyVersion { .. } => "Change version of profile dependency",
+            Intent::RemoveProfileDependency { .. } => "Remove dependency from profile",
+            Intent::SetProperty { .. } => "Set property value",
+            Intent::RemoveProperty { .. } => "Remove property",
+            Intent::ExtractVersionProperty { .. } => "Extract version into a property",
+            Intent::SetProjectField { .. } => "Change project information",
+            Intent::BumpProjectVersion(bump) => bump.description(),
+            Intent::AddProjectEntry(ProjectList::Licenses) => "Add license",
+            Intent::AddProjectEntry(ProjectList::Developers) => "Add developer",
+            Intent::RemoveProjectEntry { .. } => "Remove license or developer",
+            Intent::BuildEffectivePom => "Compute effective POM",
+            Intent::PlanBatch { .. } => "Summarize changes of a batch operation",
+            Intent::ApplyBatch => "Apply batch operation",
+            Intent::FocusNextView => "Focus next view",
+            Intent::FocusPreviousView => "Focus previous view",
+            Intent::ToggleHelp => "Toggle this help",
+        }
+    }
+
+    /// Intents that edit or evaluate parts of a POM, or reorder its
+    /// declarations, rejected for Gradle projects.
+    pub fn is_maven_only(&self) -> bool {
+        return matches!(
+            self,
+            Intent::MoveDependency { .. }
+                | Intent::SortDependencies(_)
+                | Intent::PinManagedVersion { .. }
+                | Intent::AddExclusion { .. }
+                | Intent::AddManagedDependency { .. }
+                | Intent::SetManagedVersion { .. }
+                | Intent::RemoveManagedDependency { .. }
+                | Intent::AddPlugin { .. }
+                | Intent::SetPluginVersion { .. }
+                | Intent::RemovePlugin { .. }
+                | Intent::CheckPluginUpdates
+                | Intent::AddProfileDependency { .. }
+                | Intent::SetProfileDependencyVersion { .. }
+                | Intent::RemoveProfileDependency { .. }
+                | Intent::SetProperty { .. }
+                | Intent::RemoveProperty { .. }
+                | Intent::ExtractVersionProperty { .. }
+                | Intent::SetProjectField { .. }
+                | Intent::BumpProjectVersion(_)
+                | Intent::AddProjectEntry(_)
+                | Intent::RemoveProjectEntry { .. }
+                | Intent::BuildEffectivePom
+        );
+    }
 }
 
 #[derive(Debug)]
 pub enum AsyncEvent {
-    MavenDependenciesFound(Vec<SearchResponseDoc>),
-    MavenDependencyVersionsFound(MavenResponse<GetVersionsResponse>)
+    MavenDependenciesFound { search_id: u64, response: SearchResponse },
+    MavenDependencyVersionsFound { 
+        group_id: String, 
+        artifact_id: String, 
+        response: GetVersionsResponse 
+    },
+    SearchFailed { search_id: u64, error: String },
+    DependencyVersionsFailed { group_id: String, artifact_id: String, error: String },
+    PomMetadataLoaded { coordinates: String, metadata: PomMetadata },
+    DependencyTreeResolved(DependencyTree),
+    DependencyResolutionFailed(String),
+    LicensesLoaded { group_id: String, artifact_id: String, lookup: LicenseLookup },
+    LatestVersionFound { group_id: String, artifact_id: String, version: Result<String, String> },
+    VersionsListed { group_id: String, artifact_id: String, versions: Result<Vec<String>, String> },
+    /// Ids of the artifacts managed by an imported BOM.
+    BomLoaded { group_id: String, artifact_id: String, managed: Result<Vec<String>, String> },
+    ParentPropertiesLoaded(Result<HashMap<String, String>, String>),
+    EffectivePomBuilt(Box<EffectiveElement>),
+    EffectivePomFailed(String),
+    AdvisoriesLoaded(AdvisoryDatabase),
+    AdvisoryLoadingFailed(String),
 }
 
 #[derive(Debug)]
 pub enum Effect {
-    SearchMaven(String),
+    SearchMaven { query: String, search_id: u64, start: u32, debounce: Duration },
     GetAvailableDependencyVersions { 
         group_id: String, 
-        artifact_id: String 
+        artifact_id: String,
+        start: u32,
     },
+    FetchPomMetadata {
+        group_id: String,
+        artifact_id: String,
+        version: String,
+    },
+    ResolveDependencyTree { root: Box<Pom>, base_dir: Option<PathBuf> },
+    LoadAdvisories { directory: PathBuf },
+    CollectLicenses { root: Box<Pom>, base_dir: Option<PathBuf> },
+    LoadBoms { root: Box<Pom>, base_dir: Option<PathBuf> },
+    FetchLatestVersions { artifacts: Vec<(String, String)> },
+    ListVersions { artifacts: Vec<(String, String)> },
+    LoadParentProperties { root: Box<Pom>, base_dir: Option<PathBuf> },
+    BuildEffectivePom { root: Box<Element>, base_dir: Option<PathBuf>, context: Box<ActivationContext> },
+}
+
+/// How long typing has to pause before a live search is sent.
+const SEARCH_DEBOUNCE: Duration = Duration::from_millis(300);
+
+/// A single entry of a keybinding table, as shown in the help popup.
+#[derive(Debug, Clone)]
+pub struct KeyBinding {
+    pub key: KeyCode,
+    pub modifiers: KeyModifiers,
+    pub description: &'static str,
+}
+
+impl KeyBinding {
+    pub fn from_mapping<T>(mapping: &HashMap<KeyCode, T>, describe: impl Fn(&T) -> &'static str) -> Vec<KeyBinding> {
+        let mut bindings: Vec<KeyBinding> = mapping
+            .iter()
+            .map(|(key, value)| KeyBinding { key: *key, modifiers: KeyModifiers::NONE, description: describe(value) })
+            .collect();
+
+        bindings.sort_by_key(|binding| binding.label());
+
+        return bindings;
+    }
+
+    pub fn from_modified_mapping<T>(
+        mapping: &HashMap<(KeyModifiers, KeyCode), T>,
+        describe: impl Fn(&T) -> &'static str,
+    ) -> Vec<KeyBinding> {
+        let mut bindings: Vec<KeyBinding> = mapping
+            .iter()
+            .map(|((modifiers, key), value)| KeyBinding { key: *key, modifiers: *modifiers, description: describe(value) })
+            .collect();
+
+        bindings.sort_by_key(|binding| binding.label());
+
+        return bindings;
+    }
+
+    pub fn label(&self) -> String {
+        if self.modifiers.is_empty() {
+            return self.key.to_string();
+        }
+
+        return format!("{}+{}", self.modifiers, self.key);
+    }
 }
 
 trait IntentMapping {
-    fn get_mapping(ctx: EventContext) -> HashMap<KeyCode, Intent>;
+    fn get_mapping(ctx: &EventContext) -> HashMap<KeyCode, Intent>;
 }
 
 pub struct AppIntentHandler {}
 
 impl IntentMapping for AppIntentHandler {
-    fn get_mapping(ctx: EventContext) -> HashMap<KeyCode, Intent> {
+    fn get_mapping(ctx: &EventContext) -> HashMap<KeyCode, Intent> {
+        // While a view captures text input every key belongs to that view.
+        if ctx.mode == InteractionMode::Input {
+            return HashMap::new();
+        }
+
         let default_mapping = HashMap::from([
             (KeyCode::Char('q'), Intent::Exit),
-            (KeyCode::Char('i'), Intent::EnterInputMode),
             (KeyCode::Char('a'), Intent::SubmitDependencyChanges),
+            (KeyCode::Char('?'), Intent::ToggleHelp),
             (KeyCode::Tab, Intent::FocusNextView),
             (KeyCode::BackTab, Intent::FocusPreviousView),
         ]);
@@ -90,14 +311,20 @@ impl AppIntentHandler {
 
     pub fn event_to_intent(event: Event, ctx: EventContext) -> Option<Intent> {
         let intent = match event {
-            Event::Key(key_event) => AppIntentHandler::handle_key_event(key_event, ctx),
+            Event::Key(key_event) => AppIntentHandler::handle_key_event(key_event, &ctx),
             _ => return None,
         };
 
         return intent;
     }
 
-    fn handle_key_event(key_event: KeyEvent, ctx: EventContext) -> Option<Intent> {
+    pub fn keybindings(ctx: &EventContext) -> Vec<KeyBinding> {
+        let mapping = Self::get_mapping(ctx);
+
+        return KeyBinding::from_mapping(&mapping, Intent::description);
+    }
+
+    fn handle_key_event(key_event: KeyEvent, ctx: &EventContext) -> Option<Intent> {
         let mapping = Self::get_mapping(ctx);
 
         let intent = mapping.get(&key_event.code)?;
@@ -113,14 +340,21 @@ impl AppExecutor {
     pub fn handle_event(event: AppEvent, state: &mut AppState, effects: &mut Vec<Effect>) {
 
         if let AppEvent::Raw(raw_event) = event {
-            AppExecutor::handle_event_for_focused_view(&raw_event, state).
-                map(|intent| 
-                    AppExecutor::execute(AppEvent::User(intent), state, effects));
-
-            let ctx = EventContext::from(&state);
-            AppIntentHandler::event_to_intent(raw_event, ctx).
-                map(|intent| 
-                    AppExecutor::execute(AppEvent::User(intent), state, effects));
+            if state.ui_state.popup.is_some() {
+                if let Some(intent) = AppExecutor::handle_event_for_popup(&raw_event, state) {
+                    AppExecutor::execute(AppEvent::User(intent), state, effects);
+                }
+                return;
+            }
+
+            if let Some(intent) = AppExecutor::handle_event_for_focused_view(&raw_event, state) {
+                AppExecutor::execute(AppEvent::User(intent), state, effects);
+            }
+
+            let ctx = EventContext::from(state);
+            if let Some(intent) = AppIntentHandler::event_to_intent(raw_event, ctx) {
+                AppExecutor::execute(AppEvent::User(intent), state, effects);
+            }
         } else {
             AppExecutor::execute(event, state, effects);
         }
@@ -128,43 +362,241 @@ impl AppExecutor {
     }
 
     pub fn execute(event: AppEvent, state: &mut AppState, effects: &mut Vec<Effect>) {
+        if let AppEvent::User(intent) = &event
+            && intent.is_maven_only()
+            && state.data.gradle_file.is_some()
+        {
+            state.data.mode = InteractionMode::Normal;
+            state.ui_state.popup = Some(Popup::Message {
+                title: "Not available for Gradle".to_string(),
+                text: format!("\"{}\" needs a pom.xml and is not available for Gradle projects.", intent.description()),
+            });
+            return;
+        }
 
         match event {
             AppEvent::User(Intent::Exit) => Self::exit_app(state),
+            AppEvent::User(Intent::EnterInputMode) => {
+                state.data.mode = InteractionMode::Input;
+            }
+            AppEvent::User(Intent::LeaveInputMode) => {
+                state.data.mode = InteractionMode::Normal;
+            }
+            AppEvent::User(Intent::MoveDependency { from, to }) => {
+                Self::move_dependency(from, to, state);
+            }
+            AppEvent::User(Intent::EditDependency { index }) => {
+                Self::edit_dependency(index, state, effects);
+            }
+            AppEvent::User(Intent::ApplyDependencyEdit) => {
+                Self::apply_dependency_edit(state);
+            }
+            AppEvent::User(Intent::UndoDependencyEdit) => {
+                Self::undo_dependency_edit(state);
+            }
+            AppEvent::User(Intent::SortDependencies(order)) => {
+                let pom = state.data.build_file().to_pom();
+                dependency::sort_declarations(&mut state.data.dependencies, order, &pom);
+            }
             AppEvent::User(Intent::DeleteSelectedDependency { index }) => {
                 Self::delete_selected_dependency(index, state)
             }
             AppEvent::User(Intent::SubmitDependencyChanges) => {
                 Self::submit_dependency_changes(state);
+                Self::collect_licenses(state, effects);
+            }
+            AppEvent::User(Intent::ExportLicenseReport) => {
+                Self::export_license_report(state);
+            }
+            AppEvent::User(Intent::AddManagedDependency { group_id, artifact_id, version, import }) => {
+                state.data.mode = InteractionMode::Normal;
+                match import {
+                    true => state.data.maven_file.add_managed_import(&group_id, &artifact_id, &version),
+                    false => state.data.maven_file.pin_managed_version(&group_id, &artifact_id, &version),
+                }
+                Self::load_boms(state, effects);
+            }
+            AppEvent::User(Intent::SetManagedVersion { index, version }) => {
+                state.data.mode = InteractionMode::Normal;
+                state.data.maven_file.set_managed_version(index, &version);
+                Self::load_boms(state, effects);
+            }
+            AppEvent::User(Intent::RemoveManagedDependency { index }) => {
+                state.data.maven_file.remove_managed_dependency(index);
+                Self::load_boms(state, effects);
+            }
+            AppEvent::User(Intent::AddPlugin { section, group_id, artifact_id, version }) => {
+                state.data.mode = InteractionMode::Normal;
+                let plugin = JavaDependency { group_id, artifact_id, version };
+                state.data.maven_file.add_plugin(section, &plugin);
+            }
+            AppEvent::User(Intent::SetPluginVersion { section, index, version }) => {
+                state.data.mode = InteractionMode::Normal;
+                state.data.maven_file.set_plugin_version(section, index, &version);
+            }
+            AppEvent::User(Intent::RemovePlugin { section, index }) => {
+                state.data.maven_file.remove_plugin(section, index);
+            }
+            AppEvent::User(Intent::AddProfileDependency { profile, group_id, artifact_id, version }) => {
+                state.data.mode = InteractionMode::Normal;
+                let dependency = JavaDependency { group_id, artifact_id, version };
+                state.data.maven_file.add_profile_dependency(profile, &dependency);
+            }
+            AppEvent::User(Intent::SetProfileDependencyVersion { profile, index, version }) => {
+                state.data.mode = InteractionMode::Normal;
+                state.data.maven_file.set_profile_dependency_version(profile, index, &version);
+            }
+            AppEvent::User(Intent::RemoveProfileDependency { profile, index }) => {
+                state.data.maven_file.remove_profile_dependency(profile, index);
+            }
+            AppEvent::User(Intent::SetProperty { name, value }) => {
+                state.data.mode = InteractionMode::Normal;
+                state.data.maven_file.set_property(&name, &value);
+            }
+            AppEvent::User(Intent::RemoveProperty { name }) => {
+                state.data.maven_file.remove_property(&name);
+            }
+            AppEvent::User(Intent::SetProjectField { field, value }) => {
+                state.data.mode = InteractionMode::Normal;
+                state.data.maven_file.set_project_field(field, &value);
+            }
+            AppEvent::User(Intent::BumpProjectVersion(bump)) => {
+                Self::bump_project_version(bump, state);
+            }
+            AppEvent::User(Intent::AddProjectEntry(list)) => {
+                state.data.maven_file.add_project_entry(list);
+            }
+            AppEvent::User(Intent::RemoveProjectEntry { list, index }) => {
+                state.data.maven_file.remove_project_entry(list, index);
+            }
+            AppEvent::User(Intent::ExtractVersionProperty { index }) => {
+                Self::extract_version_property(index, state);
+            }
+            AppEvent::Async(AsyncEvent::ParentPropertiesLoaded(properties)) => {
+                state.data.parent_properties = Some(properties);
+            }
+            AppEvent::User(Intent::BuildEffectivePom) => {
+                Self::build_effective_pom(state, effects);
+            }
+            AppEvent::Async(AsyncEvent::EffectivePomBuilt(effective)) => {
+                state.data.effective_pom = EffectivePomState::Built(*effective);
+            }
+            AppEvent::Async(AsyncEvent::EffectivePomFailed(error)) => {
+                state.data.effective_pom = EffectivePomState::Failed(error);
+            }
+            AppEvent::User(Intent::CheckPluginUpdates) => {
+                Self::check_plugin_updates(state, effects);
+            }
+            AppEvent::User(Intent::CheckDependencyUpdates) => {
+                Self::check_dependency_updates(state, effects);
+            }
+            AppEvent::Async(AsyncEvent::LatestVersionFound { group_id, artifact_id, version }) => {
+                state.data.latest_versions.insert(format!("{}:{}", group_id, artifact_id), version);
+            }
+            AppEvent::User(Intent::PlanBatch { operation, artifacts }) => {
+                state.data.mode = InteractionMode::Normal;
+                Self::plan_batch(operation, artifacts, state, effects);
+            }
+            AppEvent::User(Intent::ApplyBatch) => {
+                Self::apply_batch(state);
+            }
+            AppEvent::Async(AsyncEvent::VersionsListed { group_id, artifact_id, versions }) => {
+                state.data.available_versions.insert(format!("{}:{}", group_id, artifact_id), versions);
+            }
+            AppEvent::Async(AsyncEvent::BomLoaded { group_id, artifact_id, managed }) => {
+                state.data.bom_contents.insert(format!("{}:{}", group_id, artifact_id), managed);
+            }
+            AppEvent::Async(AsyncEvent::LicensesLoaded { group_id, artifact_id, lookup }) => {
+                state.data.licenses.insert(format!("{}:{}", group_id, artifact_id), lookup);
             }
             AppEvent::User(Intent::FindNewDependencies(search_phrase)) => {
-                effects.push(Effect::SearchMaven(search_phrase));
+                Self::search_maven(search_phrase, Duration::ZERO, state, effects);
+            }
+            AppEvent::User(Intent::SearchQueryChanged(search_phrase)) => {
+                Self::search_maven(search_phrase, SEARCH_DEBOUNCE, state, effects);
             }
             AppEvent::User(Intent::GetAvailableDependencyVersions { index }) => {
-                let dependency = state.data.found_dependencies.get(index).unwrap();
-                let group_id = (&dependency.g).to_string();
-                let artifact_id = (&dependency.a).to_string();
-
-                let effect = Effect::GetAvailableDependencyVersions { group_id, artifact_id };
-                effects.push(effect);
+                Self::get_available_dependency_versions(index, false, state, effects);
             }
-            AppEvent::Async(AsyncEvent::MavenDependenciesFound(dependencies)) => {
-                state.data.found_dependencies = dependencies;
+            AppEvent::User(Intent::LoadMoreDependencyVersions { index }) => {
+                Self::get_available_dependency_versions(index, true, state, effects);
             }
-            AppEvent::Async(AsyncEvent::MavenDependencyVersionsFound(response)) => {
-                let versions = response.response.docs;
-                if let Some(first_version) = versions.get(0) {
-                    let group_id  = &first_version.g;
-                    let artifact_id = &first_version.a;
-
-                    let dependency_id = format!("{}:{}", group_id, artifact_id);
+            AppEvent::User(Intent::GetArtifactDetails { index, version_index }) => {
+                Self::get_artifact_details(index, version_index, state, effects);
+            }
+            AppEvent::User(Intent::AddDependency { index, version_index }) => {
+                Self::add_dependency(index, version_index, state);
+            }
+            AppEvent::User(Intent::ResolveDependencyTree) => {
+                Self::resolve_dependency_tree(state, effects);
+            }
+            AppEvent::User(Intent::PinManagedVersion { group_id, artifact_id, version }) => {
+                Self::pin_managed_version(&group_id, &artifact_id, &version, state, effects);
+            }
+            AppEvent::User(Intent::AddExclusion { dependency_group_id, dependency_artifact_id, group_id, artifact_id }) => {
+                Self::add_exclusion(dependency_group_id, dependency_artifact_id, &group_id, &artifact_id, state, effects);
+            }
+            AppEvent::User(Intent::ExplainDependency { group_id, artifact_id }) => {
+                Self::explain_dependency(group_id, artifact_id, state, effects);
+            }
+            AppEvent::Async(AsyncEvent::DependencyTreeResolved(tree)) => {
+                state.data.dependency_tree = ResolutionState::Resolved(tree);
+            }
+            AppEvent::Async(AsyncEvent::DependencyResolutionFailed(error)) => {
+                state.data.dependency_tree = ResolutionState::Failed(error);
+            }
+            AppEvent::Async(AsyncEvent::AdvisoriesLoaded(database)) => {
+                state.data.audit = AuditState::Loaded(database);
+            }
+            AppEvent::Async(AsyncEvent::AdvisoryLoadingFailed(error)) => {
+                state.data.audit = AuditState::Failed(error);
+            }
+            AppEvent::User(Intent::LoadMoreDependencies) => {
+                Self::load_more_dependencies(state, effects);
+            }
+            // Responses of superseded searches may still arrive out of order and are dropped.
+            AppEvent::Async(AsyncEvent::MavenDependenciesFound { search_id, response })
+                if search_id == state.data.latest_search_id =>
+            {
+                if SearchQuery::parse(&state.data.search_query).is_class_search() {
+                    Self::add_class_search_page(response, state);
+                } else {
+                    state
+                        .data
+                        .found_dependencies
+                        .add_page(response.start, response.num_found, response.docs);
+                }
+            }
+            AppEvent::Async(AsyncEvent::MavenDependencyVersionsFound { group_id, artifact_id, response }) => {
+                let dependency_id = format!("{}:{}", group_id, artifact_id);
 
-                    state.data.found_dependency_versions.insert(dependency_id, versions);
+                state
+                    .data
+                    .found_dependency_versions
+                    .entry(dependency_id)
+                    .or_default()
+                    .add_page(response.start, response.num_found, response.docs);
+            }
+            AppEvent::Async(AsyncEvent::SearchFailed { search_id, error }) if search_id == state.data.latest_search_id => {
+                state.data.found_dependencies.fail_page(error);
+            }
+            AppEvent::Async(AsyncEvent::DependencyVersionsFailed { group_id, artifact_id, error }) => {
+                if let Some(versions) = state.data.found_dependency_versions.get_mut(&format!("{}:{}", group_id, artifact_id)) {
+                    versions.fail_page(error);
                 }
             }
+            AppEvent::Async(AsyncEvent::PomMetadataLoaded { coordinates, metadata }) => {
+                state.data.artifact_metadata.insert(coordinates, metadata);
+            }
             AppEvent::User(Intent::FocusNextView) => {
                 Self::focus_next_view(state);
             }
+            AppEvent::User(Intent::FocusPreviousView) => {
+                Self::focus_previous_view(state);
+            }
+            AppEvent::User(Intent::ToggleHelp) => {
+                Self::toggle_help(state);
+            }
             _ => (),
         };
     }
@@ -173,38 +605,684 @@ impl AppExecutor {
         let focused = &state.ui_state.currently_focused_view;
         let views = &mut state.ui_state.views;
 
-        if let Some((view_id, view)) = views.iter_mut().find(|(view_id, view)| *view_id == *focused) {
-            return view.handle_event(&event);
+        if let Some((_, view)) = views.iter_mut().find(|(view_id, _)| *view_id == *focused) {
+            return view.handle_event(event);
         }
 
         return None;
     }
 
+    fn handle_event_for_popup(event: &Event, state: &mut AppState) -> Option<Intent> {
+        // The editor takes every key but Esc, so text can be typed into it.
+        if let Some(Popup::EditDependency(editor)) = &mut state.ui_state.popup
+            && !matches!(event, Event::Key(key_event) if key_event.code == KeyCode::Esc)
+        {
+            let versions = state.data.available_versions.get(&editor.original.id());
+            return editor.handle_event(event, versions);
+        }
+
+        if let Event::Key(key_event) = event {
+            match (key_event.code, &mut state.ui_state.popup) {
+                (KeyCode::Esc | KeyCode::Char('q') | KeyCode::Char('?'), _) => {
+                    state.ui_state.popup = None;
+                }
+                (KeyCode::Char('j'), Some(Popup::DependencyPaths { scroll, .. } | Popup::BatchSummary { scroll, .. })) => {
+                    *scroll = scroll.saturating_add(1);
+                }
+                (KeyCode::Char('k'), Some(Popup::DependencyPaths { scroll, .. } | Popup::BatchSummary { scroll, .. })) => {
+                    *scroll = scroll.saturating_sub(1);
+                }
+                (KeyCode::Enter, Some(Popup::BatchSummary { .. })) => return Some(Intent::ApplyBatch),
+                _ => (),
+            }
+        }
+
+        return None;
+    }
+
+    /// Starts reading the advisory database when one is installed.
+    pub fn load_advisories(state: &mut AppState, effects: &mut Vec<Effect>) {
+        let Some(directory) = audit::advisory_directory() else {
+            return;
+        };
+
+        state.data.audit = AuditState::Loading;
+        effects.push(Effect::LoadAdvisories { directory });
+    }
+
+    /// Looks up the licenses of the project's dependencies in the background.
+    pub fn collect_licenses(state: &mut AppState, effects: &mut Vec<Effect>) {
+        effects.push(Effect::CollectLicenses {
+            root: Box::new(state.data.build_file().to_pom()),
+            base_dir: state.data.build_file().directory(),
+        });
+    }
+
+    /// Reads which artifacts the imported BOMs manage. Entries are replaced
+    /// as they arrive, so edited imports show their new contents.
+    pub fn load_boms(state: &mut AppState, effects: &mut Vec<Effect>) {
+        let root = state.data.build_file().to_pom();
+
+        if !root.dependency_management.iter().any(|dependency| dependency.is_bom_import()) {
+            return;
+        }
+
+        effects.push(Effect::LoadBoms {
+            root: Box::new(root),
+            base_dir: state.data.build_file().directory(),
+        });
+    }
+
+    /// Reads the properties of the parent chain, when the project has a parent.
+    pub fn load_parent_properties(state: &mut AppState, effects: &mut Vec<Effect>) {
+        let root = state.data.maven_file.to_pom();
+
+        if root.parent.is_none() {
+            return;
+        }
+
+        effects.push(Effect::LoadParentProperties {
+            root: Box::new(root),
+            base_dir: state.data.maven_file.directory(),
+        });
+    }
+
+    /// Builds the effective POM from the edited model, so unsaved changes show up.
+    fn build_effective_pom(state: &mut AppState, effects: &mut Vec<Effect>) {
+        if let EffectivePomState::Building = state.data.effective_pom {
+            return;
+        }
+
+        state.data.effective_pom = EffectivePomState::Building;
+        effects.push(Effect::BuildEffectivePom {
+            root: Box::new(state.data.maven_file.root().clone()),
+            base_dir: state.data.maven_file.directory(),
+            context: Box::new(state.data.activation_context.clone()),
+        });
+    }
+
+    fn bump_project_version(bump: Bump, state: &mut AppState) {
+        let version = state.data.maven_file.get_project_field(ProjectField::Version);
+
+        let text = match project_info::bump_version(&version, bump) {
+            Some(bumped) => {
+                state.data.maven_file.set_project_field(ProjectField::Version, &bumped);
+                return;
+            }
+            None if version.is_empty() => "The version is inherited from the parent, declare one to bump it".to_string(),
+            None => format!("{} cannot be bumped this way", version),
+        };
+
+        state.ui_state.popup = Some(Popup::Message { title: "Bump version".to_string(), text });
+    }
+
+    /// The declared dependency keeps its place in the list, only its version
+    /// becomes a reference to the new property.
+    fn extract_version_property(index: usize, state: &mut AppState) {
+        let Some(dependency) = state.data.dependencies.get(index) else {
+            return;
+        };
+
+        let text = match state.data.maven_file.extract_version_property(dependency) {
+            Some(name) => {
+                let version = format!("${{{}}}", name);
+                let text = format!("Version {} of {} moved into property {}", dependency.version, dependency.artifact_id, name);
+                state.data.dependencies[index].version = version;
+                text
+            }
+            None => format!(
+                "{} has no literal version, or the property names for it hold other values",
+                dependency.artifact_id,
+            ),
+        };
+
+        state.ui_state.popup = Some(Popup::Message { title: "Extract property".to_string(), text });
+    }
+
+    /// Shows what a batch operation will change. Upgrades first look up the
+    /// versions of the artifacts, which fill in the summary as they arrive.
+    fn plan_batch(operation: BatchOperation, artifacts: Vec<(String, String)>, state: &mut AppState, effects: &mut Vec<Effect>) {
+        if let BatchOperation::Upgrade(_) = operation {
+            let missing: Vec<(String, String)> = artifacts
+                .iter()
+                .filter(|(group_id, artifact_id)| {
+                    !matches!(state.data.available_versions.get(&format!("{}:{}", group_id, artifact_id)), Some(Ok(_)))
+                })
+                .cloned()
+                .collect();
+
+            for (group_id, artifact_id) in &missing {
+                state.data.available_versions.remove(&format!("{}:{}", group_id, artifact_id));
+            }
+
+            if !missing.is_empty() {
+                effects.push(Effect::ListVersions { artifacts: missing });
+            }
+        }
+
+        state.ui_state.popup = Some(Popup::BatchSummary { operation, artifacts, scroll: 0 });
+    }
+
+    /// Applies the batch operation of the summary, once no lookup is pending.
+    fn apply_batch(state: &mut AppState) {
+        let Some(Popup::BatchSummary { operation, artifacts, .. }) = &state.ui_state.popup else {
+            return;
+        };
+
+        let changes = batch::plan_for(&state.data, operation, artifacts);
+        if changes.iter().any(|planned| planned.change == BatchChange::Pending) {
+            return;
+        }
+
+        for planned in changes {
+            let dependency = &planned.dependency;
+
+            match planned.change {
+                BatchChange::Remove => state.data.dependencies.retain(|existing| !existing.is_same_artifact(dependency)),
+                BatchChange::SetVersion(version) => {
+                    if let Some(existing) = state.data.dependencies.iter_mut().find(|existing| existing.is_same_artifact(dependency)) {
+                        existing.version = version;
+                    }
+                }
+                BatchChange::SetScope { to, .. } => {
+                    state.data.build_file_mut().set_dependency_scope(dependency, &to);
+                }
+                BatchChange::Skip(_) | BatchChange::Pending => {}
+            }
+        }
+
+        state.ui_state.popup = None;
+    }
+
+    fn check_plugin_updates(state: &mut AppState, effects: &mut Vec<Effect>) {
+        let maven_file = &state.data.maven_file;
+
+        let mut artifacts: Vec<(String, String)> = vec![];
+        for section in [PluginSection::Build, PluginSection::Management] {
+            for plugin in maven_file.get_plugins(section) {
+                let artifact = (plugin.group_id, plugin.artifact_id);
+                if !artifacts.contains(&artifact) {
+                    artifacts.push(artifact);
+                }
+            }
+        }
+
+        for (group_id, artifact_id) in &artifacts {
+            state.data.latest_versions.remove(&format!("{}:{}", group_id, artifact_id));
+        }
+
+        effects.push(Effect::FetchLatestVersions { artifacts });
+    }
+
+    /// Looks up the latest versions of the dependencies not looked up yet.
+    fn check_dependency_updates(state: &mut AppState, effects: &mut Vec<Effect>) {
+        let mut artifacts: Vec<(String, String)> = vec![];
+        for dependency in &state.data.dependencies {
+            let artifact = (dependency.group_id.clone(), dependency.artifact_id.clone());
+            let known = state.data.latest_versions.contains_key(&format!("{}:{}", artifact.0, artifact.1));
+
+            if !known && !artifacts.contains(&artifact) {
+                artifacts.push(artifact);
+            }
+        }
+
+        if !artifacts.is_empty() {
+            effects.push(Effect::FetchLatestVersions { artifacts });
+        }
+    }
+
+    fn export_license_report(state: &mut AppState) {
+        let data = &state.data;
+
+        let rows: Vec<ReportRow> = data
+            .dependencies
+            .iter()
+            .map(|dependency| {
+                let (version, licenses) = match data.licenses.get(&format!("{}:{}", dependency.group_id, dependency.artifact_id)) {
+                    Some(LicenseLookup::Found { version, licenses }) => (version.as_str(), licenses.as_slice()),
+                    _ => (dependency.version.as_str(), [].as_slice()),
+                };
+
+                ReportRow {
+                    group_id: &dependency.group_id,
+                    artifact_id: &dependency.artifact_id,
+                    version,
+                    licenses,
+                    verdict: data.license_policy.evaluate(licenses),
+                }
+            })
+            .collect();
+
+        let directory = data.build_file().directory().unwrap_or_default();
+        let text = match license::export_report(&directory, &rows) {
+            Ok(path) => format!("License report written to {}", path.display()),
+            Err(e) => format!("Export failed: {:#}", e),
+        };
+
+        state.ui_state.popup = Some(Popup::Message { title: "License report".to_string(), text });
+    }
+
     fn exit_app(state: &mut AppState) {
         state.data.exit = true;
     }
 
     fn submit_dependency_changes(state: &mut AppState) {
-        state
+        let dependencies = state.data.dependencies.clone();
+
+        if let Err(error) = state.data.build_file_mut().update_dependencies(&dependencies) {
+            let text = format!("{:#}", error);
+            state.ui_state.popup = Some(Popup::Message { title: "Saving failed".to_string(), text });
+        }
+    }
+
+    fn search_maven(query: String, debounce: Duration, state: &mut AppState, effects: &mut Vec<Effect>) {
+        state.data.latest_search_id += 1;
+        state.data.search_query = query.clone();
+
+        if SearchQuery::parse(&query).is_empty() {
+            state.data.found_dependencies = Default::default();
+            return;
+        }
+
+        state.data.found_dependencies.loading = true;
+        effects.push(Effect::SearchMaven { query, search_id: state.data.latest_search_id, start: 0, debounce });
+    }
+
+    fn load_more_dependencies(state: &mut AppState, effects: &mut Vec<Effect>) {
+        let found = &mut state.data.found_dependencies;
+        if found.loading || !found.has_more() {
+            return;
+        }
+
+        found.loading = true;
+        effects.push(Effect::SearchMaven {
+            query: state.data.search_query.clone(),
+            search_id: state.data.latest_search_id,
+            start: found.next_start(),
+            debounce: Duration::ZERO,
+        });
+    }
+
+    fn get_available_dependency_versions(index: usize, load_more: bool, state: &mut AppState, effects: &mut Vec<Effect>) {
+        let Some(dependency) = state.data.found_dependencies.items.get(index) else {
+            return;
+        };
+
+        let group_id = dependency.g.to_string();
+        let artifact_id = dependency.a.to_string();
+
+        if !load_more {
+            Self::fetch_pom_metadata(&group_id, &artifact_id, &dependency.latest_version, state, effects);
+        }
+
+        let versions = state
+            .data
+            .found_dependency_versions
+            .entry(format!("{}:{}", group_id, artifact_id))
+            .or_default();
+
+        if load_more && (versions.loading || !versions.has_more()) {
+            return;
+        }
+
+        let start = if load_more { versions.next_start() } else { 0 };
+        versions.loading = true;
+
+        let effect = Effect::GetAvailableDependencyVersions { group_id, artifact_id, start };
+        effects.push(effect);
+    }
+
+    /// Class searches list every version of each matching artifact separately,
+    /// so they are grouped per artifact with the hits becoming its versions.
+    fn add_class_search_page(response: SearchResponse, state: &mut AppState) {
+        let found = &mut state.data.found_dependencies;
+        if !found.begin_page(response.start) {
+            return;
+        }
+
+        if response.start == 0 {
+            state.data.found_dependency_versions.clear();
+        }
+
+        let rows = response.docs.len();
+
+        for doc in response.docs {
+            let dependency_id = format!("{}:{}", doc.g, doc.a);
+            let versions = state.data.found_dependency_versions.entry(dependency_id.clone()).or_default();
+
+            if versions.items.iter().any(|version| version.v == doc.v) {
+                continue;
+            }
+
+            match found.items.iter_mut().find(|artifact| artifact.id == dependency_id) {
+                Some(artifact) => artifact.version_count += 1,
+                None => {
+                    let mut artifact = doc.clone();
+                    artifact.id = dependency_id;
+                    artifact.latest_version = doc.v.clone();
+                    artifact.version_count = 1;
+                    found.items.push(artifact);
+                }
+            }
+
+            versions.items.push(GetVersionsResponseDoc {
+                id: doc.id,
+                g: doc.g,
+                a: doc.a,
+                v: doc.v,
+                p: doc.p,
+                timestamp: doc.timestamp,
+                ec: doc.ec,
+                tags: vec![],
+            });
+            versions.num_found = versions.items.len() as u32;
+            versions.fetched = versions.num_found;
+        }
+
+        found.finish_page(response.start, rows, response.num_found);
+    }
+
+    fn add_dependency(index: usize, version_index: usize, state: &mut AppState) {
+        let Some(artifact) = state.data.found_dependencies.items.get(index) else {
+            return;
+        };
+
+        let Some(version) = state
+            .data
+            .found_dependency_versions
+            .get(&format!("{}:{}", artifact.g, artifact.a))
+            .and_then(|versions| versions.items.get(version_index))
+        else {
+            return;
+        };
+
+        let dependency = JavaDependency {
+            group_id: version.g.clone(),
+            artifact_id: version.a.clone(),
+            version: version.v.clone(),
+        };
+
+        let dependencies = &mut state.data.dependencies;
+        match dependencies.iter_mut().find(|existing| existing.is_same_artifact(&dependency)) {
+            Some(existing) => existing.version = dependency.version,
+            None => dependencies.push(dependency),
+        }
+    }
+
+    fn resolve_dependency_tree(state: &mut AppState, effects: &mut Vec<Effect>) {
+        if let ResolutionState::Resolving = state.data.dependency_tree {
+            return;
+        }
+
+        state.data.dependency_tree = ResolutionState::Resolving;
+        effects.push(Effect::ResolveDependencyTree {
+            root: Box::new(state.data.build_file().to_pom()),
+            base_dir: state.data.build_file().directory(),
+        });
+    }
+
+    fn add_exclusion(
+        dependency_group_id: String,
+        dependency_artifact_id: String,
+        group_id: &str,
+        artifact_id: &str,
+        state: &mut AppState,
+        effects: &mut Vec<Effect>,
+    ) {
+        let dependency = JavaDependency {
+            group_id: dependency_group_id,
+            artifact_id: dependency_artifact_id,
+            version: String::new(),
+        };
+
+        if state.data.maven_file.add_exclusion(&dependency, group_id, artifact_id) {
+            Self::resolve_dependency_tree(state, effects);
+            return;
+        }
+
+        let text = format!(
+            "{}:{} is not declared in this POM but inherited from a parent, so the exclusion has to be added there.",
+            dependency.group_id, dependency.artifact_id,
+        );
+        state.ui_state.popup = Some(Popup::Message { title: "Cannot exclude".to_string(), text });
+    }
+
+    fn pin_managed_version(group_id: &str, artifact_id: &str, version: &str, state: &mut AppState, effects: &mut Vec<Effect>) {
+        // The version an inherited declaration comes with wins over the
+        // project's `<dependencyManagement>`.
+        if Self::is_inherited_dependency(group_id, artifact_id, state) {
+            let text = format!(
+                "{}:{} is not declared in this POM but inherited from a parent, so its version has to be changed there.",
+                group_id, artifact_id,
+            );
+            state.ui_state.popup = Some(Popup::Message { title: "Cannot pin version".to_string(), text });
+            return;
+        }
+
+        state.data.maven_file.pin_managed_version(group_id, artifact_id, version);
+        Self::resolve_dependency_tree(state, effects);
+    }
+
+    /// Whether an artifact is a direct dependency of the resolved tree that
+    /// the project's own POM does not declare.
+    fn is_inherited_dependency(group_id: &str, artifact_id: &str, state: &AppState) -> bool {
+        let ResolutionState::Resolved(tree) = &state.data.dependency_tree else {
+            return false;
+        };
+
+        let direct = tree
+            .nodes
+            .iter()
+            .any(|node| node.depth == 1 && node.group_id == group_id && node.artifact_id == artifact_id);
+        let declared = state
             .data
             .maven_file
-            .update_dependencies(&state.data.dependencies);
+            .to_pom()
+            .dependencies
+            .iter()
+            .any(|dependency| dependency.group_id == group_id && dependency.artifact_id == artifact_id);
 
-        // TODO Send event on success and / or on error.
+        return direct && !declared;
+    }
+
+    /// Paths are looked up when the popup is drawn, so they show up once a
+    /// resolution started from here finishes.
+    fn explain_dependency(group_id: String, artifact_id: String, state: &mut AppState, effects: &mut Vec<Effect>) {
+        if let ResolutionState::NotStarted | ResolutionState::Failed(_) = state.data.dependency_tree {
+            Self::resolve_dependency_tree(state, effects);
+        }
+
+        state.ui_state.popup = Some(Popup::DependencyPaths { group_id, artifact_id, scroll: 0 });
+    }
+
+    fn get_artifact_details(index: usize, version_index: usize, state: &mut AppState, effects: &mut Vec<Effect>) {
+        let Some(dependency) = state.data.found_dependencies.items.get(index) else {
+            return;
+        };
+
+        let dependency_id = format!("{}:{}", dependency.g, dependency.a);
+        let Some(version) = state
+            .data
+            .found_dependency_versions
+            .get(&dependency_id)
+            .and_then(|versions| versions.items.get(version_index))
+        else {
+            return;
+        };
+
+        let (group_id, artifact_id, version) = (version.g.clone(), version.a.clone(), version.v.clone());
+        Self::fetch_pom_metadata(&group_id, &artifact_id, &version, state, effects);
+    }
+
+    fn fetch_pom_metadata(group_id: &str, artifact_id: &str, version: &str, state: &AppState, effects: &mut Vec<Effect>) {
+        let coordinates = format!("{}:{}:{}", group_id, artifact_id, version);
+
+        if version.is_empty() || state.data.artifact_metadata.contains_key(&coordinates) {
+            return;
+        }
+
+        effects.push(Effect::FetchPomMetadata {
+            group_id: group_id.to_string(),
+            artifact_id: artifact_id.to_string(),
+            version: version.to_string(),
+        });
     }
 
     fn delete_selected_dependency(index: usize, state: &mut AppState) {
          state.data.dependencies.remove(index);
     }
 
+    /// Opens the editor for a dependency, looking up its published versions.
+    /// Only the version of dependencies not written to the build file yet can
+    /// be changed, and Gradle declarations have no POM-only fields.
+    fn edit_dependency(index: usize, state: &mut AppState, effects: &mut Vec<Effect>) {
+        let Some(dependency) = state.data.dependencies.get(index) else {
+            return;
+        };
+
+        let declared = state
+            .data
+            .build_file()
+            .to_pom()
+            .dependencies
+            .into_iter()
+            .find(|declared| declared.group_id == dependency.group_id && declared.artifact_id == dependency.artifact_id);
+
+        let (fields, note) = match (&declared, state.data.gradle_file.is_some()) {
+            (None, _) => (vec![EditorField::Version], Some("Other fields can be changed once the dependency is written (a)")),
+            (Some(_), true) => (vec![EditorField::Version, EditorField::Scope], Some("Gradle declarations only take a version and scope here")),
+            (Some(_), false) => (EditorField::ALL.to_vec(), None),
+        };
+
+        let mut original = declared.unwrap_or_else(|| PomDependency {
+            group_id: dependency.group_id.clone(),
+            artifact_id: dependency.artifact_id.clone(),
+            ..Default::default()
+        });
+        original.version = dependency.version.clone();
+
+        if !state.data.available_versions.contains_key(&original.id()) {
+            effects.push(Effect::ListVersions { artifacts: vec![(original.group_id.clone(), original.artifact_id.clone())] });
+        }
+
+        let mut editor = DependencyEditor::new(original, fields);
+        editor.note = note;
+        state.ui_state.popup = Some(Popup::EditDependency(Box::new(editor)));
+    }
+
+    /// Applies the edit once it is valid, remembering the previous
+    /// declaration for undoing.
+    fn apply_dependency_edit(state: &mut AppState) {
+        let Some(Popup::EditDependency(editor)) = &state.ui_state.popup else {
+            return;
+        };
+
+        if !editor.problems(state.data.available_versions.get(&editor.original.id())).is_empty() {
+            return;
+        }
+
+        let (before, after) = (editor.original.clone(), editor.edited());
+        state.ui_state.popup = None;
+
+        if before != after {
+            let configurations = match &state.data.gradle_file {
+                Some(gradle_file) => gradle_file.configurations(&before.group_id, &before.artifact_id),
+                None => vec![],
+            };
+
+            Self::set_declaration(&after, state);
+            state.data.dependency_edits.push(DependencyEdit { declared: before, configurations });
+        }
+    }
+
+    fn undo_dependency_edit(state: &mut AppState) {
+        let Some(edit) = state.data.dependency_edits.pop() else {
+            return;
+        };
+
+        Self::set_declaration(&edit.declared, state);
+
+        // The scope alone would turn e.g. `api` into `implementation`.
+        if let Some(gradle_file) = &mut state.data.gradle_file {
+            gradle_file.set_configurations(&edit.declared.group_id, &edit.declared.artifact_id, &edit.configurations);
+        }
+    }
+
+    /// Writes a declaration into the dependency list and the build file.
+    fn set_declaration(declared: &PomDependency, state: &mut AppState) {
+        let dependency = JavaDependency {
+            group_id: declared.group_id.clone(),
+            artifact_id: declared.artifact_id.clone(),
+            version: declared.version.clone(),
+        };
+
+        if let Some(existing) = state.data.dependencies.iter_mut().find(|existing| existing.is_same_artifact(&dependency)) {
+            existing.version = declared.version.clone();
+        }
+
+        match state.data.gradle_file.is_some() {
+            true => {
+                // Rewriting the configuration of an unchanged scope would turn
+                // e.g. `api` into `implementation`.
+                let current = state
+                    .data
+                    .build_file()
+                    .to_pom()
+                    .dependencies
+                    .into_iter()
+                    .find(|current| current.group_id == declared.group_id && current.artifact_id == declared.artifact_id)
+                    .map(|current| current.scope)
+                    .unwrap_or_default();
+                let scope = if declared.scope.is_empty() { "compile" } else { declared.scope.as_str() };
+                let before = if current.is_empty() { "compile" } else { current.as_str() };
+
+                if before != scope {
+                    state.data.build_file_mut().set_dependency_scope(&dependency, scope);
+                }
+            }
+            false => {
+                state.data.maven_file.set_declared_dependency(declared);
+            }
+        }
+    }
+
+    /// Moves a declaration to another position, shifting the ones in between.
+    fn move_dependency(from: usize, to: usize, state: &mut AppState) {
+        let dependencies = &mut state.data.dependencies;
+        if from >= dependencies.len() || to >= dependencies.len() {
+            return;
+        }
+
+        let dependency = dependencies.remove(from);
+        dependencies.insert(to, dependency);
+    }
+
     fn focus_next_view(state: &mut AppState) {
         let views = &state.ui_state.views;
 
         if let Some(current_index) = views.iter().position(|(v, _)| *v == state.ui_state.currently_focused_view) {
             let next_index = (current_index + 1) % views.len();
-            state.ui_state.currently_focused_view = views[next_index].0.clone();
+            let next_view = views[next_index].0.clone();
+            state.ui_state.focus(next_view);
         }
     }
+
+    fn focus_previous_view(state: &mut AppState) {
+        let views = &state.ui_state.views;
+
+        if let Some(current_index) = views.iter().position(|(v, _)| *v == state.ui_state.currently_focused_view) {
+            let previous_index = (current_index + views.len() - 1) % views.len();
+            let previous_view = views[previous_index].0.clone();
+            state.ui_state.focus(previous_view);
+        }
+    }
+
+    fn toggle_help(state: &mut AppState) {
+        state.ui_state.popup = match state.ui_state.popup {
+            Some(Popup::Help) => None,
+            _ => Some(Popup::Help),
+        };
+    }
 }
 
 pub struct AppAsyncOrchestrator {}
@@ -212,18 +1290,140 @@ pub struct AppAsyncOrchestrator {}
 impl AppAsyncOrchestrator {
     pub async fn handle_async_event(effect: Effect, tx: mpsc::Sender<AppEvent>) -> Result<()> {
         match effect {
-            Effect::SearchMaven(search_phrase) => { 
-                let result = MavenRegistry::search_dependencies(search_phrase);
-                let response = result.await?.response.docs;
-                let event = AppEvent::Async(AsyncEvent::MavenDependenciesFound(response));
-                tx.send(event).await?;
+            Effect::SearchMaven { query, search_id, start, debounce } => { 
+                tokio::time::sleep(debounce).await;
+
+                let event = match MavenRegistry::search_dependencies(query, start).await {
+                    Ok(result) => AsyncEvent::MavenDependenciesFound { search_id, response: result.response },
+                    Err(e) => AsyncEvent::SearchFailed { search_id, error: e.to_string() },
+                };
+                tx.send(AppEvent::Async(event)).await?;
             },
-            Effect::GetAvailableDependencyVersions { group_id, artifact_id } => {
-                let result = MavenRegistry::get_available_dependency_versions(group_id, artifact_id);
-                let response = result.await?;
-                let event = AppEvent::Async(AsyncEvent::MavenDependencyVersionsFound(response));
+            Effect::GetAvailableDependencyVersions { group_id, artifact_id, start } => {
+                let result = MavenRegistry::get_available_dependency_versions(group_id.clone(), artifact_id.clone(), start);
+                let event = match result.await {
+                    Ok(result) => AsyncEvent::MavenDependencyVersionsFound { group_id, artifact_id, response: result.response },
+                    Err(e) => AsyncEvent::DependencyVersionsFailed { group_id, artifact_id, error: e.to_string() },
+                };
+                tx.send(AppEvent::Async(event)).await?;
+            }
+            Effect::FetchPomMetadata { group_id, artifact_id, version } => {
+                let coordinates = format!("{}:{}:{}", group_id, artifact_id, version);
+                let content = MavenRegistry::fetch_pom(group_id, artifact_id, version).await?;
+                let metadata = PomMetadata::parse(&content)?;
+                let event = AppEvent::Async(AsyncEvent::PomMetadataLoaded { coordinates, metadata });
                 tx.send(event).await?;
             }
+            Effect::ResolveDependencyTree { root, base_dir } => {
+                let event = match Resolver::new().resolve(*root, base_dir).await {
+                    Ok(tree) => AsyncEvent::DependencyTreeResolved(tree),
+                    Err(e) => AsyncEvent::DependencyResolutionFailed(e.to_string()),
+                };
+                tx.send(AppEvent::Async(event)).await?;
+            }
+            Effect::CollectLicenses { root, base_dir } => {
+                let mut builder = ModelBuilder::new();
+                let declared = root.dependencies.clone();
+
+                let model = match builder.build(*root, base_dir, 0).await {
+                    Ok(model) => model,
+                    Err(e) => {
+                        for dependency in declared {
+                            let lookup = LicenseLookup::Failed(e.to_string());
+                            let (group_id, artifact_id) = (dependency.group_id, dependency.artifact_id);
+                            tx.send(AppEvent::Async(AsyncEvent::LicensesLoaded { group_id, artifact_id, lookup })).await?;
+                        }
+                        return Ok(());
+                    }
+                };
+
+                for dependency in model.dependencies {
+                    let lookup = match builder
+                        .load_effective(&dependency.group_id, &dependency.artifact_id, &dependency.version)
+                        .await
+                    {
+                        Ok(effective) => LicenseLookup::Found {
+                            version: dependency.version.clone(),
+                            licenses: effective.licenses.iter().map(license::spdx_id).collect(),
+                        },
+                        Err(e) => LicenseLookup::Failed(e.to_string()),
+                    };
+
+                    let (group_id, artifact_id) = (dependency.group_id, dependency.artifact_id);
+                    tx.send(AppEvent::Async(AsyncEvent::LicensesLoaded { group_id, artifact_id, lookup })).await?;
+                }
+            }
+            Effect::LoadBoms { root, base_dir } => {
+                let mut builder = ModelBuilder::new();
+                let imports: Vec<PomDependency> = root
+                    .dependency_management
+                    .iter()
+                    .filter(|dependency| dependency.is_bom_import())
+                    .cloned()
+                    .collect();
+
+                let properties = match builder.build(*root, base_dir, 0).await {
+                    Ok(model) => Ok(model.properties),
+                    Err(e) => Err(e.to_string()),
+                };
+
+                for import in imports {
+                    let managed = match &properties {
+                        Ok(properties) => {
+                            let version = resolver::interpolate(&import.version, properties);
+                            builder
+                                .load_effective(&import.group_id, &import.artifact_id, &version)
+                                .await
+                                .map(|bom| bom.dependency_management.iter().map(PomDependency::id).collect())
+                                .map_err(|e| e.to_string())
+                        }
+                        Err(e) => Err(e.clone()),
+                    };
+
+                    let (group_id, artifact_id) = (import.group_id, import.artifact_id);
+                    tx.send(AppEvent::Async(AsyncEvent::BomLoaded { group_id, artifact_id, managed })).await?;
+                }
+            }
+            Effect::FetchLatestVersions { artifacts } => {
+                for (group_id, artifact_id) in artifacts {
+                    let version = MavenRegistry::fetch_latest_version(group_id.clone(), artifact_id.clone())
+                        .await
+                        .map_err(|e| e.to_string());
+
+                    tx.send(AppEvent::Async(AsyncEvent::LatestVersionFound { group_id, artifact_id, version })).await?;
+                }
+            }
+            Effect::ListVersions { artifacts } => {
+                for (group_id, artifact_id) in artifacts {
+                    let versions = MavenRegistry::fetch_versions(group_id.clone(), artifact_id.clone())
+                        .await
+                        .map_err(|e| e.to_string());
+
+                    tx.send(AppEvent::Async(AsyncEvent::VersionsListed { group_id, artifact_id, versions })).await?;
+                }
+            }
+            Effect::LoadParentProperties { root, base_dir } => {
+                let properties = ModelBuilder::new()
+                    .parent_properties(&root, base_dir)
+                    .await
+                    .map_err(|e| e.to_string());
+
+                tx.send(AppEvent::Async(AsyncEvent::ParentPropertiesLoaded(properties))).await?;
+            }
+            Effect::BuildEffectivePom { root, base_dir, context } => {
+                let event = match effective_pom::build(*root, base_dir, *context).await {
+                    Ok(effective) => AsyncEvent::EffectivePomBuilt(Box::new(effective)),
+                    Err(e) => AsyncEvent::EffectivePomFailed(e.to_string()),
+                };
+                tx.send(AppEvent::Async(event)).await?;
+            }
+            Effect::LoadAdvisories { directory } => {
+                let event = match tokio::task::spawn_blocking(move || AdvisoryDatabase::load(&directory)).await? {
+                    Ok(database) => AsyncEvent::AdvisoriesLoaded(database),
+                    Err(e) => AsyncEvent::AdvisoryLoadingFailed(e.to_string()),
+                };
+                tx.send(AppEvent::Async(event)).await?;
+            }
         };
 
         return Ok(());
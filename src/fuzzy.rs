@@ -0,0 +1,106 @@
+/// Score of each matched character.
+const MATCH_SCORE: i64 = 16;
+/// Bonus for a match right after the previous one.
+const CONSECUTIVE_BONUS: i64 = 12;
+/// Bonus for a match at the start of a word, e.g. after `.`, `:` or `-`.
+const BOUNDARY_BONUS: i64 = 8;
+/// Penalty per character skipped between two matches.
+const GAP_PENALTY: i64 = 1;
+
+const SEPARATORS: &[char] = &['.', ':', '-', '_', ' ', '/'];
+
+/// How well a pattern matches a text, with the positions (in chars) of the
+/// matched characters.
+#[derive(Debug, Clone, PartialEq)]
+pub struct FuzzyMatch {
+    pub score: i64,
+    pub positions: Vec<usize>,
+}
+
+/// Matches the characters of `pattern` in order, ignoring case, anywhere in
+/// `text`. Among all alignments the best scoring one is returned, which
+/// favors consecutive characters and starts of words. Whitespace in the
+/// pattern is ignored. An empty pattern matches everything.
+pub fn fuzzy_match(pattern: &str, text: &str) -> Option<FuzzyMatch> {
+    let pattern: Vec<char> = pattern.chars().filter(|char| !char.is_whitespace()).flat_map(char::to_lowercase).collect();
+    let text: Vec<char> = text.chars().collect();
+    let lowercase: Vec<char> = text.iter().map(|char| char.to_lowercase().next().unwrap_or(*char)).collect();
+
+    if pattern.is_empty() {
+        return Some(FuzzyMatch { score: 0, positions: vec![] });
+    }
+
+    let bonus = |index: usize| -> i64 {
+        let at_boundary = index == 0
+            || SEPARATORS.contains(&text[index - 1])
+            || (text[index - 1].is_lowercase() && text[index].is_uppercase());
+        if at_boundary { BOUNDARY_BONUS } else { 0 }
+    };
+
+    // scores[i][j]: best score with pattern[i] matched at text[j], and the
+    // position pattern[i - 1] was matched at.
+    let mut scores: Vec<Vec<Option<(i64, usize)>>> = vec![vec![None; text.len()]; pattern.len()];
+
+    for (i, pattern_char) in pattern.iter().enumerate() {
+        for j in 0..text.len() {
+            if lowercase[j] != *pattern_char {
+                continue;
+            }
+
+            let own = MATCH_SCORE + bonus(j);
+
+            if i == 0 {
+                scores[i][j] = Some((own, 0));
+                continue;
+            }
+
+            scores[i][j] = (0..j)
+                .filter_map(|k| scores[i - 1][k].map(|(score, _)| (score, k)))
+                .map(|(score, k)| {
+                    let link = match j - k - 1 {
+                        0 => CONSECUTIVE_BONUS,
+                        gap => -(gap as i64) * GAP_PENALTY,
+                    };
+                    (score + own + link, k)
+                })
+                .max_by_key(|(score, k)| (*score, *k));
+        }
+    }
+
+    let last = pattern.len() - 1;
+    let (mut position, score) = (0..text.len())
+        .filter_map(|j| scores[last][j].map(|(score, _)| (j, score)))
+        .max_by_key(|(j, score)| (*score, std::cmp::Reverse(*j)))?;
+
+    let mut positions = vec![position];
+    for i in (1..pattern.len()).rev() {
+        position = scores[i][position]?.1;
+        positions.push(position);
+    }
+    positions.reverse();
+
+    return Some(FuzzyMatch { score, positions });
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn matches_characters_in_order() {
+        assert!(fuzzy_match("gva", "com.google.guava:guava").is_some());
+        assert!(fuzzy_match("GUAVA", "com.google.guava:guava").is_some());
+        assert!(fuzzy_match("avug", "com.google.guava:guava").is_none());
+        assert_eq!(fuzzy_match("", "anything").unwrap().score, 0);
+        assert_eq!(fuzzy_match("jt", "junit").unwrap().positions, vec![0, 4]);
+    }
+
+    #[test]
+    fn prefers_consecutive_and_word_start_matches() {
+        let score = |pattern: &str, text: &str| fuzzy_match(pattern, text).unwrap().score;
+
+        assert!(score("core", "jackson-core") > score("core", "commons-compress-re"));
+        assert!(score("sb", "spring-boot") > score("sb", "jsonbox"));
+        assert_eq!(fuzzy_match("boot", "spring-boot-starter").unwrap().positions, vec![7, 8, 9, 10]);
+    }
+}
@@ -0,0 +1,842 @@
+use anyhow::{Context, Result};
+use std::{
+    cmp::Reverse,
+    fs,
+    ops::Range,
+    path::{Path, PathBuf},
+};
+use toml_edit::{DocumentMut, InlineTable, Item, Table, Value};
+
+use crate::{
+    build_file::BuildFile,
+    dependency::{JavaDependency, parse_coordinates},
+    pom::{Pom, PomDependency},
+};
+
+/// Build scripts looked for in the working directory, in order of preference.
+const BUILD_SCRIPTS: &[&str] = &["build.gradle.kts", "build.gradle"];
+
+/// Location of the version catalog, relative to the root project.
+const VERSION_CATALOG: &str = "gradle/libs.versions.toml";
+
+/// Configuration dependencies are added to.
+const DEFAULT_CONFIGURATION: &str = "implementation";
+
+const DEFAULT_INDENT: &str = "    ";
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum GradleDsl {
+    Kotlin,
+    Groovy,
+}
+
+impl GradleDsl {
+    fn of(path: &Path) -> GradleDsl {
+        match path.extension().is_some_and(|extension| extension == "kts") {
+            true => GradleDsl::Kotlin,
+            false => GradleDsl::Groovy,
+        }
+    }
+
+    /// A dependency declaration as written in this DSL.
+    fn declaration(&self, configuration: &str, notation: &str) -> String {
+        match self {
+            GradleDsl::Kotlin => format!("{}({})", configuration, notation),
+            GradleDsl::Groovy => format!("{} {}", configuration, notation),
+        }
+    }
+
+    fn string(&self, value: &str) -> String {
+        match self {
+            GradleDsl::Kotlin => format!("\"{}\"", value),
+            GradleDsl::Groovy => format!("'{}'", value),
+        }
+    }
+}
+
+/// Where the version of a declaration is written.
+#[derive(Debug, Clone, PartialEq)]
+enum VersionSource {
+    /// Byte range of the version in the build script.
+    Inline(Range<usize>),
+    /// End of a `group:artifact` string a version can be appended to.
+    Append(usize),
+    /// Library of the version catalog, by alias.
+    Catalog(String),
+    /// Not declared here, e.g. when a platform provides it.
+    Missing,
+}
+
+/// A dependency declared in the `dependencies` block of a build script.
+#[derive(Debug, Clone, PartialEq)]
+pub struct GradleDeclaration {
+    pub configuration: String,
+    pub dependency: JavaDependency,
+    /// Declared through `platform(...)` or `enforcedPlatform(...)`, importing a BOM.
+    pub platform: bool,
+    /// Byte range of the declaration's lines, including the block of
+    /// `exclude(...)` calls and the like it may open, and the last line break.
+    line: Range<usize>,
+    configuration_range: Range<usize>,
+    version: VersionSource,
+}
+
+/// Configuration dependencies of a Maven scope are declared in.
+fn configuration_of(scope: &str) -> Option<&'static str> {
+    match scope {
+        "" | "compile" => Some(DEFAULT_CONFIGURATION),
+        "provided" => Some("compileOnly"),
+        "runtime" => Some("runtimeOnly"),
+        "test" => Some("testImplementation"),
+        _ => None,
+    }
+}
+
+impl GradleDeclaration {
+    fn declares(&self, group_id: &str, artifact_id: &str) -> bool {
+        return self.dependency.group_id == group_id && self.dependency.artifact_id == artifact_id;
+    }
+
+    /// Scope Maven gives dependencies of the configuration.
+    pub fn scope(&self) -> &'static str {
+        match self.configuration.as_str() {
+            configuration if configuration.starts_with("test") => "test",
+            "compileOnly" | "compileOnlyApi" | "annotationProcessor" | "kapt" | "ksp" => "provided",
+            "runtimeOnly" => "runtime",
+            _ => "",
+        }
+    }
+}
+
+/// Declarations of a build script, with where new ones go.
+struct Scan {
+    declarations: Vec<GradleDeclaration>,
+    /// Start of the line closing the `dependencies` block.
+    block_end: Option<usize>,
+    indent: String,
+}
+
+/// A `gradle/libs.versions.toml` version catalog, edited in place so its
+/// formatting and comments survive.
+#[derive(Debug, Clone)]
+pub struct VersionCatalog {
+    path: PathBuf,
+    document: DocumentMut,
+}
+
+impl VersionCatalog {
+    pub fn parse(path: PathBuf, content: &str) -> Result<VersionCatalog> {
+        let document = content.parse::<DocumentMut>().with_context(|| format!("invalid version catalog {}", path.display()))?;
+
+        return Ok(VersionCatalog { path, document });
+    }
+
+    /// Alias of the library a `libs.` accessor refers to. Gradle turns the
+    /// `-` and `_` separators of aliases into dots.
+    fn alias_of(&self, accessor: &str) -> Option<String> {
+        let libraries = self.document.get("libraries")?.as_table_like()?;
+
+        return libraries
+            .iter()
+            .map(|(alias, _)| alias)
+            .find(|alias| alias.replace(['-', '_'], ".") == accessor)
+            .map(str::to_string);
+    }
+
+    pub fn library(&self, alias: &str) -> Option<JavaDependency> {
+        let library = self.document.get("libraries")?.get(alias)?;
+
+        if let Some(notation) = library.as_str() {
+            return parse_coordinates(notation).map(|(dependency, _)| dependency);
+        }
+
+        let library = library.as_table_like()?;
+        let text = |key: &str| library.get(key).and_then(Item::as_str).map(str::to_string);
+
+        let (group_id, artifact_id) = match text("module") {
+            Some(module) => {
+                let (group_id, artifact_id) = module.split_once(':')?;
+                (group_id.to_string(), artifact_id.to_string())
+            }
+            None => (text("group")?, text("name")?),
+        };
+
+        let version = match library.get("version") {
+            Some(version) if version.is_str() => version.as_str().map(str::to_string),
+            Some(version) => version.as_table_like().and_then(|version| {
+                if let Some(reference) = version.get("ref").and_then(Item::as_str) {
+                    return self.document.get("versions")?.get(reference)?.as_str().map(str::to_string);
+                }
+
+                return ["strictly", "require", "prefer"]
+                    .iter()
+                    .find_map(|key| version.get(key).and_then(Item::as_str).map(str::to_string));
+            }),
+            None => None,
+        };
+
+        return Some(JavaDependency { group_id, artifact_id, version: version.unwrap_or_default() });
+    }
+
+    /// Sets the version of a library. Versions shared through `version.ref`
+    /// are changed for every library using them, as Gradle would.
+    fn set_library_version(&mut self, alias: &str, version: &str) {
+        let Some(library) = self.document.get_mut("libraries").and_then(|libraries| libraries.get_mut(alias)) else {
+            return;
+        };
+
+        if let Some(notation) = library.as_str() {
+            let Some((mut dependency, _)) = parse_coordinates(notation) else {
+                return;
+            };
+
+            dependency.version = version.to_string();
+            set_string(library, &coordinates(&dependency));
+            return;
+        }
+
+        let Some(table) = library.as_table_like_mut() else {
+            return;
+        };
+
+        let reference = table
+            .get("version")
+            .and_then(Item::as_table_like)
+            .and_then(|version| version.get("ref"))
+            .and_then(Item::as_str)
+            .map(str::to_string);
+
+        match (reference, table.get_mut("version")) {
+            (Some(reference), _) => {
+                if let Some(shared) = self.document.get_mut("versions").and_then(|versions| versions.get_mut(&reference)) {
+                    set_string(shared, version);
+                }
+            }
+            (None, Some(declared)) if declared.is_str() => set_string(declared, version),
+            (None, _) => {
+                table.insert("version", Item::Value(Value::from(version)));
+            }
+        }
+    }
+
+    /// Adds a library and returns its alias, named after the artifact. The
+    /// group or a number is added to names already in use.
+    fn add_library(&mut self, dependency: &JavaDependency) -> String {
+        if !self.document.contains_key("libraries") {
+            self.document.insert("libraries", Item::Table(Table::new()));
+        }
+
+        let Some(libraries) = self.document.get_mut("libraries").and_then(Item::as_table_like_mut) else {
+            return String::new();
+        };
+
+        let sanitize = |name: &str| name.replace(|char: char| !char.is_ascii_alphanumeric() && char != '-', "-");
+        let group_name = dependency.group_id.rsplit('.').next().unwrap_or_default();
+
+        // Aliases differing only in their separators share an accessor.
+        let taken = |alias: &str| {
+            libraries
+                .iter()
+                .any(|(existing, _)| existing.replace(['-', '_'], ".") == alias.replace(['-', '_'], "."))
+        };
+
+        let qualified = sanitize(&format!("{}-{}", group_name, dependency.artifact_id));
+        let alias = [sanitize(&dependency.artifact_id), qualified.clone()]
+            .into_iter()
+            .chain((2..).map(|number| format!("{}-{}", qualified, number)))
+            .find(|alias| !taken(alias))
+            .unwrap_or_default();
+
+        let mut library = InlineTable::new();
+        library.insert("module", Value::from(format!("{}:{}", dependency.group_id, dependency.artifact_id)));
+        if !dependency.version.is_empty() {
+            library.insert("version", Value::from(dependency.version.as_str()));
+        }
+
+        libraries.insert(&alias, Item::Value(Value::InlineTable(library)));
+
+        return alias;
+    }
+
+    fn write(&self) -> Result<()> {
+        return write_with_backup(&self.path, &self.document.to_string());
+    }
+}
+
+/// A Gradle build script, `build.gradle.kts` or `build.gradle`, with the
+/// version catalog of its build when there is one. Declarations are
+/// recognized by their first line, which may open a block configuring them.
+#[derive(Debug, Clone)]
+pub struct GradleFile {
+    path: PathBuf,
+    content: String,
+    dsl: GradleDsl,
+    catalog: Option<VersionCatalog>,
+}
+
+impl GradleFile {
+    pub fn from_file(path: PathBuf) -> Result<GradleFile> {
+        let content = fs::read_to_string(&path).with_context(|| format!("cannot read {}", path.display()))?;
+
+        let catalog_path = path
+            .canonicalize()
+            .unwrap_or_else(|_| path.clone())
+            .ancestors()
+            .skip(1)
+            .map(|directory| directory.join(VERSION_CATALOG))
+            .find(|catalog| catalog.is_file());
+
+        let catalog = match catalog_path {
+            Some(catalog) => Some(VersionCatalog::parse(catalog.clone(), &fs::read_to_string(&catalog)?)?),
+            None => None,
+        };
+
+        return Ok(GradleFile::parse(path, content, catalog));
+    }
+
+    pub fn parse(path: PathBuf, content: String, catalog: Option<VersionCatalog>) -> GradleFile {
+        return GradleFile { dsl: GradleDsl::of(&path), path, content, catalog };
+    }
+
+    /// Build script of the working directory, if the project builds with Gradle.
+    pub fn search() -> Option<PathBuf> {
+        return BUILD_SCRIPTS.iter().map(PathBuf::from).find(|path| path.is_file());
+    }
+
+    pub fn declarations(&self) -> Vec<GradleDeclaration> {
+        return self.scan().declarations;
+    }
+
+    fn scan(&self) -> Scan {
+        let mut scan = Scan { declarations: vec![], block_end: None, indent: DEFAULT_INDENT.to_string() };
+        let mut depth: i32 = 0;
+        let mut in_block = false;
+        let mut offset = 0;
+        // Declaration whose block is still open.
+        let mut open_declaration: Option<usize> = None;
+
+        for line in self.content.split_inclusive('\n') {
+            let start = offset;
+            offset += line.len();
+
+            let code = strip_comment(line);
+            let opened = code.matches('{').count() as i32;
+            let closed = code.matches('}').count() as i32;
+
+            if in_block
+                && depth == 1
+                && let Some(declaration) = self.parse_declaration(line, start)
+            {
+                if scan.declarations.is_empty() {
+                    scan.indent = line[..line.len() - line.trim_start().len()].to_string();
+                }
+                scan.declarations.push(declaration);
+                if opened > closed {
+                    open_declaration = Some(scan.declarations.len() - 1);
+                }
+            }
+
+            let is_block_start = depth == 0 && code.trim_start().strip_prefix("dependencies").is_some_and(|rest| rest.trim_start().starts_with('{'));
+            depth += opened - closed;
+
+            if depth <= 1
+                && let Some(index) = open_declaration.take()
+            {
+                scan.declarations[index].line.end = offset;
+            }
+
+            if is_block_start && depth > 0 {
+                in_block = true;
+            } else if in_block && depth == 0 {
+                in_block = false;
+                scan.block_end.get_or_insert(start);
+            }
+        }
+
+        return scan;
+    }
+
+    /// Recognizes `configuration("g:a:v")`, named `group`/`name`/`version`
+    /// arguments, catalog accessors like `libs.some.library` and platforms
+    /// wrapping any of them.
+    fn parse_declaration(&self, line: &str, offset: usize) -> Option<GradleDeclaration> {
+        let code = strip_comment(line);
+        let start = code.len() - code.trim_start().len();
+        let name_length = code[start..].find(|char: char| !char.is_ascii_alphanumeric() && char != '_').unwrap_or(code.len() - start);
+        if name_length == 0 {
+            return None;
+        }
+
+        let configuration = code[start..start + name_length].to_string();
+        let mut position = skip(code, start + name_length, " \t(");
+
+        let mut platform = false;
+        for wrapper in ["platform", "enforcedPlatform"] {
+            if code[position..].starts_with(wrapper) && code[position + wrapper.len()..].starts_with('(') {
+                platform = true;
+                position = skip(code, position + wrapper.len(), " \t(");
+            }
+        }
+
+        let rest = &code[position..];
+        let (dependency, version) = if rest.starts_with(['"', '\'']) {
+            let literal = read_literal(code, position)?;
+            let (dependency, version_range) = parse_coordinates(&code[literal.clone()])?;
+
+            let version = match version_range {
+                Some(range) => VersionSource::Inline(offset + literal.start + range.start..offset + literal.start + range.end),
+                None => VersionSource::Append(offset + literal.end),
+            };
+
+            (dependency, version)
+        } else if let Some(accessor) = rest.strip_prefix("libs.") {
+            let accessor_length = accessor.find(|char: char| !char.is_ascii_alphanumeric() && char != '.' && char != '_').unwrap_or(accessor.len());
+            let catalog = self.catalog.as_ref()?;
+            let alias = catalog.alias_of(&accessor[..accessor_length])?;
+
+            (catalog.library(&alias)?, VersionSource::Catalog(alias))
+        } else {
+            let arguments = read_named_arguments(code, position);
+            let argument = |name: &str| arguments.iter().find(|(key, _)| key == name).map(|(_, range)| range.clone());
+
+            let group_id = code[argument("group")?].to_string();
+            let artifact_id = code[argument("name")?].to_string();
+            let (version, source) = match argument("version") {
+                Some(range) => (code[range.clone()].to_string(), VersionSource::Inline(offset + range.start..offset + range.end)),
+                None => (String::new(), VersionSource::Missing),
+            };
+
+            (JavaDependency { group_id, artifact_id, version }, source)
+        };
+
+        return Some(GradleDeclaration {
+            configuration,
+            dependency,
+            platform,
+            line: offset..offset + line.len(),
+            configuration_range: offset + start..offset + start + name_length,
+            version,
+        });
+    }
+
+    /// Configurations an artifact is declared for, in script order.
+    pub fn configurations(&self, group_id: &str, artifact_id: &str) -> Vec<String> {
+        return self
+            .declarations()
+            .into_iter()
+            .filter(|declaration| !declaration.platform && declaration.declares(group_id, artifact_id))
+            .map(|declaration| declaration.configuration)
+            .collect();
+    }
+
+    /// Puts the declarations of an artifact back into the given
+    /// configurations, as returned by `configurations`.
+    pub fn set_configurations(&mut self, group_id: &str, artifact_id: &str, configurations: &[String]) {
+        let mut edits: Vec<(Range<usize>, &String)> = self
+            .declarations()
+            .into_iter()
+            .filter(|declaration| !declaration.platform && declaration.declares(group_id, artifact_id))
+            .map(|declaration| declaration.configuration_range)
+            .zip(configurations)
+            .collect();
+
+        edits.sort_by_key(|(range, _)| Reverse(range.start));
+        for (range, configuration) in edits {
+            self.content.replace_range(range, configuration);
+        }
+    }
+
+    /// Applies the changes to the script and the catalog in memory.
+    fn apply_dependencies(&mut self, updated: &[JavaDependency]) {
+        let scan = self.scan();
+        let mut edits: Vec<(Range<usize>, String)> = vec![];
+
+        // Platforms are imports like Maven's BOMs and are not listed as dependencies.
+        let declarations: Vec<&GradleDeclaration> = scan.declarations.iter().filter(|declaration| !declaration.platform).collect();
+
+        for declaration in &declarations {
+            let Some(dependency) = updated.iter().find(|dependency| dependency.is_same_artifact(&declaration.dependency)) else {
+                edits.push((declaration.line.clone(), String::new()));
+                continue;
+            };
+
+            if dependency.version.is_empty() || dependency.version == declaration.dependency.version {
+                continue;
+            }
+
+            match &declaration.version {
+                VersionSource::Inline(range) => edits.push((range.clone(), dependency.version.clone())),
+                VersionSource::Append(position) => edits.push((*position..*position, format!(":{}", dependency.version))),
+                VersionSource::Catalog(alias) => {
+                    if let Some(catalog) = self.catalog.as_mut() {
+                        catalog.set_library_version(alias, &dependency.version);
+                    }
+                }
+                VersionSource::Missing => {}
+            }
+        }
+
+        let added: String = updated
+            .iter()
+            .filter(|dependency| !declarations.iter().any(|declaration| declaration.dependency.is_same_artifact(dependency)))
+            .map(|dependency| format!("{}{}\n", scan.indent, self.declaration_of(dependency)))
+            .collect();
+
+        if !added.is_empty() {
+            match scan.block_end {
+                Some(position) => edits.push((position..position, added)),
+                None => {
+                    let separator = if self.content.is_empty() || self.content.ends_with('\n') { "\n" } else { "\n\n" };
+                    let end = self.content.len();
+                    edits.push((end..end, format!("{}dependencies {{\n{}}}\n", separator, added)));
+                }
+            }
+        }
+
+        edits.sort_by_key(|(range, _)| Reverse(range.start));
+        for (range, text) in edits {
+            self.content.replace_range(range, &text);
+        }
+    }
+
+    /// Declaration of a new dependency. With a version catalog, the library
+    /// is added to the catalog and referenced through its accessor.
+    fn declaration_of(&mut self, dependency: &JavaDependency) -> String {
+        let notation = match self.catalog.as_mut() {
+            Some(catalog) => format!("libs.{}", catalog.add_library(dependency).replace(['-', '_'], ".")),
+            None => self.dsl.string(&coordinates(dependency)),
+        };
+
+        return self.dsl.declaration(DEFAULT_CONFIGURATION, &notation);
+    }
+}
+
+impl BuildFile for GradleFile {
+    fn name(&self) -> String {
+        return self.path.file_name().map(|name| name.to_string_lossy().to_string()).unwrap_or_default();
+    }
+
+    fn directory(&self) -> Option<PathBuf> {
+        return self.path.parent().map(Path::to_path_buf);
+    }
+
+    fn get_dependencies(&self) -> Option<Vec<JavaDependency>> {
+        let mut dependencies: Vec<JavaDependency> = vec![];
+
+        // An artifact declared for several configurations is listed once.
+        for declaration in self.declarations().into_iter().filter(|declaration| !declaration.platform) {
+            if !dependencies.iter().any(|dependency| dependency.is_same_artifact(&declaration.dependency)) {
+                dependencies.push(declaration.dependency);
+            }
+        }
+
+        return Some(dependencies);
+    }
+
+    fn update_dependencies(&mut self, updated: &[JavaDependency]) -> Result<()> {
+        self.apply_dependencies(updated);
+
+        write_with_backup(&self.path, &self.content)?;
+        if let Some(catalog) = &self.catalog {
+            catalog.write()?;
+        }
+
+        return Ok(());
+    }
+
+    fn set_dependency_scope(&mut self, dependency: &JavaDependency, scope: &str) -> bool {
+        let Some(configuration) = configuration_of(scope) else {
+            return false;
+        };
+
+        let declarations: Vec<GradleDeclaration> = self
+            .declarations()
+            .into_iter()
+            .filter(|declaration| !declaration.platform && declaration.dependency.is_same_artifact(dependency))
+            .collect();
+
+        // Configurations already in the scope, like `api` or `kapt`, are kept.
+        let mut ranges: Vec<Range<usize>> = declarations
+            .iter()
+            .filter(|declaration| configuration_of(declaration.scope()) != Some(configuration))
+            .map(|declaration| declaration.configuration_range.clone())
+            .collect();
+
+        ranges.sort_by_key(|range| Reverse(range.start));
+        for range in &ranges {
+            self.content.replace_range(range.clone(), configuration);
+        }
+
+        return !declarations.is_empty();
+    }
+
+    fn to_pom(&self) -> Pom {
+        let mut pom = Pom::default();
+
+        for declaration in self.declarations() {
+            let mut dependency = PomDependency {
+                group_id: declaration.dependency.group_id.clone(),
+                artifact_id: declaration.dependency.artifact_id.clone(),
+                version: declaration.dependency.version.clone(),
+                scope: declaration.scope().to_string(),
+                ..Default::default()
+            };
+
+            match declaration.platform {
+                true => {
+                    dependency.scope = "import".to_string();
+                    dependency.type_ = "pom".to_string();
+                    pom.dependency_management.push(dependency);
+                }
+                false => pom.dependencies.push(dependency),
+            }
+        }
+
+        return pom;
+    }
+}
+
+/// Replaces a string value, keeping the whitespace and comment around it.
+fn set_string(item: &mut Item, text: &str) {
+    let Some(value) = item.as_value_mut() else {
+        return;
+    };
+
+    let decor = value.decor().clone();
+    *value = Value::from(text);
+    *value.decor_mut() = decor;
+}
+
+fn coordinates(dependency: &JavaDependency) -> String {
+    match dependency.version.is_empty() {
+        true => format!("{}:{}", dependency.group_id, dependency.artifact_id),
+        false => format!("{}:{}:{}", dependency.group_id, dependency.artifact_id, dependency.version),
+    }
+}
+
+/// The line without its trailing `//` comment.
+fn strip_comment(line: &str) -> &str {
+    let mut quote = None;
+
+    for (index, char) in line.char_indices() {
+        match (quote, char) {
+            (None, '"' | '\'') => quote = Some(char),
+            (Some(open), _) if char == open => quote = None,
+            (None, '/') if line[index + 1..].starts_with('/') => return &line[..index],
+            _ => {}
+        }
+    }
+
+    return line;
+}
+
+fn skip(code: &str, position: usize, chars: &str) -> usize {
+    return code[position..].find(|char: char| !chars.contains(char)).map(|found| position + found).unwrap_or(code.len());
+}
+
+/// Range of the contents of the string literal starting at `position`.
+fn read_literal(code: &str, position: usize) -> Option<Range<usize>> {
+    let quote = code[position..].chars().next()?;
+    let length = code[position + 1..].find(quote)?;
+
+    return Some(position + 1..position + 1 + length);
+}
+
+/// Reads `key: 'value'` (Groovy) or `key = "value"` (Kotlin) arguments.
+fn read_named_arguments(code: &str, position: usize) -> Vec<(String, Range<usize>)> {
+    let mut arguments = vec![];
+    let mut position = position;
+
+    loop {
+        let key_length = code[position..].find(|char: char| !char.is_ascii_alphanumeric()).unwrap_or(code.len() - position);
+        if key_length == 0 {
+            break;
+        }
+
+        let key = code[position..position + key_length].to_string();
+        let separator = skip(code, position + key_length, " \t");
+        if !code[separator..].starts_with([':', '=']) {
+            break;
+        }
+
+        let value = skip(code, separator + 1, " \t");
+        let Some(literal) = read_literal(code, value).filter(|_| code[value..].starts_with(['"', '\''])) else {
+            break;
+        };
+
+        position = skip(code, literal.end + 1, " \t,");
+        arguments.push((key, literal));
+    }
+
+    return arguments;
+}
+
+/// Writes the file, keeping the previous version next to it as `.old`.
+fn write_with_backup(path: &Path, content: &str) -> Result<()> {
+    let mut backup = path.as_os_str().to_owned();
+    backup.push(".old");
+
+    let _ = fs::rename(path, &backup);
+    fs::write(path, content).with_context(|| format!("cannot write {}", path.display()))?;
+
+    return Ok(());
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const KOTLIN_SCRIPT: &str = r#"plugins {
+    java
+}
+
+dependencies {
+    implementation("com.google.guava:guava:32.1.2-jre") // utilities
+    implementation(platform("org.springframework.boot:spring-boot-dependencies:3.2.0"))
+    implementation("org.springframework.boot:spring-boot-starter-web")
+    testImplementation(libs.junit.jupiter)
+    runtimeOnly(group = "org.postgresql", name = "postgresql", version = "42.7.1")
+    implementation(project(":core"))
+}
+"#;
+
+    const CATALOG: &str = r#"[versions]
+junit = "5.10.0" # shared
+
+[libraries]
+junit-jupiter = { module = "org.junit.jupiter:junit-jupiter", version.ref = "junit" }
+"#;
+
+    fn dependency(group_id: &str, artifact_id: &str, version: &str) -> JavaDependency {
+        return JavaDependency { group_id: group_id.to_string(), artifact_id: artifact_id.to_string(), version: version.to_string() };
+    }
+
+    fn kotlin_file() -> GradleFile {
+        let catalog = VersionCatalog::parse(PathBuf::from(VERSION_CATALOG), CATALOG).unwrap();
+        return GradleFile::parse(PathBuf::from("build.gradle.kts"), KOTLIN_SCRIPT.to_string(), Some(catalog));
+    }
+
+    #[test]
+    fn reads_kotlin_declarations_and_catalog_libraries() {
+        let file = kotlin_file();
+
+        assert_eq!(
+            file.get_dependencies().unwrap(),
+            vec![
+                dependency("com.google.guava", "guava", "32.1.2-jre"),
+                dependency("org.springframework.boot", "spring-boot-starter-web", ""),
+                dependency("org.junit.jupiter", "junit-jupiter", "5.10.0"),
+                dependency("org.postgresql", "postgresql", "42.7.1"),
+            ],
+        );
+
+        let pom = file.to_pom();
+        assert_eq!(pom.dependency_management[0].scope, "import");
+        assert_eq!(pom.dependencies[2].scope, "test");
+        assert_eq!(pom.dependencies[3].scope, "runtime");
+    }
+
+    #[test]
+    fn edits_versions_in_place_and_adds_declarations() {
+        let mut file = kotlin_file();
+
+        file.apply_dependencies(&[
+            dependency("com.google.guava", "guava", "33.0.0-jre"),
+            dependency("org.springframework.boot", "spring-boot-starter-web", ""),
+            dependency("org.junit.jupiter", "junit-jupiter", "5.10.1"),
+            dependency("org.slf4j", "slf4j-api", "2.0.9"),
+        ]);
+
+        assert!(file.content.contains("    implementation(\"com.google.guava:guava:33.0.0-jre\") // utilities\n"));
+        assert!(file.content.contains("spring-boot-dependencies:3.2.0"));
+        assert!(!file.content.contains("postgresql"));
+        assert!(file.content.contains("    implementation(libs.slf4j.api)\n}\n"));
+
+        let catalog = file.catalog.unwrap().document.to_string();
+        assert!(catalog.contains("junit = \"5.10.1\" # shared"));
+        assert!(catalog.contains("slf4j-api = { module = \"org.slf4j:slf4j-api\", version = \"2.0.9\" }"));
+    }
+
+    #[test]
+    fn edits_groovy_scripts() {
+        let script = "dependencies {\n\timplementation 'org.apache.commons:commons-lang3'\n\tcompileOnly group: 'org.projectlombok', name: 'lombok', version: '1.18.30'\n}\n";
+        let mut file = GradleFile::parse(PathBuf::from("build.gradle"), script.to_string(), None);
+
+        assert_eq!(file.declarations()[1].scope(), "provided");
+
+        file.apply_dependencies(&[
+            dependency("org.apache.commons", "commons-lang3", "3.14.0"),
+            dependency("org.projectlombok", "lombok", "1.18.32"),
+            dependency("com.google.guava", "guava", "33.0.0-jre"),
+        ]);
+
+        assert!(file.set_dependency_scope(&dependency("org.apache.commons", "commons-lang3", ""), "test"));
+        assert_eq!(
+            file.content,
+            "dependencies {\n\ttestImplementation 'org.apache.commons:commons-lang3:3.14.0'\n\tcompileOnly group: 'org.projectlombok', name: 'lombok', version: '1.18.32'\n\timplementation 'com.google.guava:guava:33.0.0-jre'\n}\n",
+        );
+    }
+
+    #[test]
+    fn adds_catalog_libraries_under_unused_aliases() {
+        let content = "[libraries]\nguava = \"org.example:guava:1.0\"\nguava-guava = \"org.other:guava:1.0\"\n";
+        let mut catalog = VersionCatalog::parse(PathBuf::from(VERSION_CATALOG), content).unwrap();
+
+        assert_eq!(catalog.add_library(&dependency("com.google.guava", "guava", "33.0.0-jre")), "guava-guava-2");
+        assert_eq!(catalog.add_library(&dependency("org.slf4j", "slf4j-api", "2.0.9")), "slf4j-api");
+
+        assert_eq!(catalog.library("guava").unwrap(), dependency("org.example", "guava", "1.0"));
+        assert_eq!(catalog.library("guava-guava").unwrap(), dependency("org.other", "guava", "1.0"));
+        assert_eq!(catalog.library("guava-guava-2").unwrap(), dependency("com.google.guava", "guava", "33.0.0-jre"));
+    }
+
+    #[test]
+    fn removes_declarations_with_their_block() {
+        let script = "dependencies {\n    implementation(\"org.hibernate:hibernate-core:6.4.1.Final\") {\n        exclude(group = \"org.jboss.logging\")\n    }\n    implementation(\"com.google.guava:guava:32.1.2-jre\")\n}\n";
+        let mut file = GradleFile::parse(PathBuf::from("build.gradle.kts"), script.to_string(), None);
+
+        assert_eq!(file.declarations().len(), 2);
+
+        file.apply_dependencies(&[dependency("com.google.guava", "guava", "33.0.0-jre")]);
+
+        assert_eq!(file.content, "dependencies {\n    implementation(\"com.google.guava:guava:33.0.0-jre\")\n}\n");
+    }
+
+    #[test]
+    fn keeps_configurations_of_an_unchanged_scope() {
+        let script = "dependencies {\n    api(\"com.google.guava:guava:32.1.2-jre\")\n    kapt(\"com.google.dagger:dagger-compiler:2.50\")\n    testRuntimeOnly(\"org.junit.platform:junit-platform-launcher:1.10.0\")\n}\n";
+        let mut file = GradleFile::parse(PathBuf::from("build.gradle.kts"), script.to_string(), None);
+
+        file.apply_dependencies(&[
+            dependency("com.google.guava", "guava", "33.0.0-jre"),
+            dependency("com.google.dagger", "dagger-compiler", "2.50"),
+            dependency("org.junit.platform", "junit-platform-launcher", "1.10.0"),
+        ]);
+        assert!(file.set_dependency_scope(&dependency("com.google.guava", "guava", ""), "compile"));
+        assert!(file.set_dependency_scope(&dependency("com.google.dagger", "dagger-compiler", ""), "provided"));
+        assert!(file.set_dependency_scope(&dependency("org.junit.platform", "junit-platform-launcher", ""), "test"));
+
+        assert_eq!(
+            file.content,
+            "dependencies {\n    api(\"com.google.guava:guava:33.0.0-jre\")\n    kapt(\"com.google.dagger:dagger-compiler:2.50\")\n    testRuntimeOnly(\"org.junit.platform:junit-platform-launcher:1.10.0\")\n}\n",
+        );
+
+        assert!(file.set_dependency_scope(&dependency("com.google.guava", "guava", ""), "runtime"));
+        assert!(file.content.contains("    runtimeOnly(\"com.google.guava:guava:33.0.0-jre\")\n"));
+    }
+
+    #[test]
+    fn restores_configurations() {
+        let script = "dependencies {\n    api(\"com.google.guava:guava:33.0.0-jre\")\n    kapt(\"com.google.guava:guava:33.0.0-jre\")\n}\n";
+        let mut file = GradleFile::parse(PathBuf::from("build.gradle.kts"), script.to_string(), None);
+
+        let configurations = file.configurations("com.google.guava", "guava");
+        assert_eq!(configurations, vec!["api", "kapt"]);
+
+        file.set_dependency_scope(&dependency("com.google.guava", "guava", ""), "test");
+        assert_eq!(file.configurations("com.google.guava", "guava"), vec!["testImplementation", "testImplementation"]);
+
+        file.set_configurations("com.google.guava", "guava", &configurations);
+        assert_eq!(file.content, script);
+    }
+}
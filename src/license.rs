@@ -0,0 +1,306 @@
+use anyhow::{Context, Result};
+use serde::Deserialize;
+use std::{
+    fs,
+    path::{Path, PathBuf},
+};
+
+use crate::pom::License;
+
+/// Policy file looked up next to the project's `pom.xml`.
+pub const POLICY_FILE_NAME: &str = "lazymaven-license-policy.json";
+
+/// Report written by the export, next to the project's `pom.xml`.
+pub const REPORT_FILE_NAME: &str = "license-report.csv";
+
+/// License names are matched when they contain all tokens of a rule. Numeric
+/// tokens also match more precise versions, so `2` matches `2.0`. Earlier
+/// rules win, which is why the LGPL comes before the GPL.
+const NAME_RULES: &[(&str, &[&str])] = &[
+    ("Apache-2.0", &["apache", "2"]),
+    ("Apache-2.0", &["asl", "2"]),
+    ("Apache-1.1", &["apache", "1.1"]),
+    ("MIT", &["mit"]),
+    ("BSD-2-Clause", &["bsd", "2"]),
+    ("BSD-2-Clause", &["simplified", "bsd"]),
+    ("BSD-3-Clause", &["bsd", "3"]),
+    ("BSD-3-Clause", &["new", "bsd"]),
+    ("BSD-3-Clause", &["revised", "bsd"]),
+    ("BSD-3-Clause", &["eclipse", "distribution"]),
+    ("BSD-3-Clause", &["edl", "1.0"]),
+    ("EPL-2.0", &["eclipse", "public", "2"]),
+    ("EPL-1.0", &["eclipse", "public", "1"]),
+    ("EPL-2.0", &["epl", "2"]),
+    ("EPL-1.0", &["epl", "1"]),
+    ("LGPL-2.1-only", &["lesser", "2.1"]),
+    ("LGPL-2.1-only", &["lgpl", "2.1"]),
+    ("LGPL-3.0-only", &["lesser", "3"]),
+    ("LGPL-3.0-only", &["lgpl", "3"]),
+    ("GPL-2.0-only WITH Classpath-exception-2.0", &["gpl", "classpath"]),
+    ("GPL-2.0-only WITH Classpath-exception-2.0", &["general", "public", "classpath"]),
+    ("GPL-3.0-only", &["general", "public", "3"]),
+    ("GPL-2.0-only", &["general", "public", "2"]),
+    ("GPL-3.0-only", &["gpl", "3"]),
+    ("GPL-2.0-only", &["gpl", "2"]),
+    ("MPL-2.0", &["mozilla", "2"]),
+    ("MPL-1.1", &["mozilla", "1.1"]),
+    ("MPL-2.0", &["mpl", "2"]),
+    ("CDDL-1.1", &["cddl", "1.1"]),
+    ("CDDL-1.0", &["cddl"]),
+    ("CDDL-1.1", &["common", "development", "distribution", "1.1"]),
+    ("CDDL-1.0", &["common", "development", "distribution"]),
+    ("CC0-1.0", &["cc0"]),
+    ("Unlicense", &["unlicense"]),
+    ("ISC", &["isc"]),
+];
+
+/// Well known license URLs, used when the name alone is not recognized.
+const URL_RULES: &[(&str, &str)] = &[
+    ("apache.org/licenses/license-2.0", "Apache-2.0"),
+    ("opensource.org/licenses/apache-2.0", "Apache-2.0"),
+    ("opensource.org/licenses/mit", "MIT"),
+    ("opensource.org/license/mit", "MIT"),
+    ("eclipse.org/legal/epl-2.0", "EPL-2.0"),
+    ("eclipse.org/legal/epl-v20", "EPL-2.0"),
+    ("eclipse.org/legal/epl-v10", "EPL-1.0"),
+    ("eclipse.org/org/documents/edl-v10", "BSD-3-Clause"),
+    ("opensource.org/licenses/bsd-3-clause", "BSD-3-Clause"),
+    ("opensource.org/licenses/bsd-2-clause", "BSD-2-Clause"),
+    ("gnu.org/licenses/old-licenses/lgpl-2.1", "LGPL-2.1-only"),
+    ("gnu.org/licenses/lgpl", "LGPL-3.0-only"),
+    ("mozilla.org/mpl/2.0", "MPL-2.0"),
+    ("creativecommons.org/publicdomain/zero/1.0", "CC0-1.0"),
+];
+
+/// SPDX identifier of a declared license. Licenses that are not recognized
+/// keep their name, or their URL when they have no name.
+pub fn spdx_id(license: &License) -> String {
+    let known = NAME_RULES.iter().map(|(id, _)| *id).chain(URL_RULES.iter().map(|(_, id)| *id));
+    for id in known {
+        if id.eq_ignore_ascii_case(license.name.trim()) {
+            return id.to_string();
+        }
+    }
+
+    let tokens = name_tokens(&license.name);
+    let by_name = NAME_RULES
+        .iter()
+        .find(|(_, required)| required.iter().all(|required| has_token(&tokens, required)));
+    if let Some((id, _)) = by_name {
+        return id.to_string();
+    }
+
+    let url = license.url.to_lowercase();
+    if let Some((_, id)) = URL_RULES.iter().find(|(fragment, _)| url.contains(fragment)) {
+        return id.to_string();
+    }
+
+    if license.name.is_empty() {
+        return license.url.clone();
+    }
+
+    return license.name.trim().to_string();
+}
+
+/// Lowercase words of a license name, with `v2.0` turned into `2.0`.
+fn name_tokens(name: &str) -> Vec<String> {
+    return name
+        .to_lowercase()
+        .split(|char: char| !char.is_alphanumeric() && char != '.')
+        .map(|token| token.trim_matches('.'))
+        .map(|token| match token.strip_prefix('v') {
+            Some(version) if version.starts_with(|char: char| char.is_ascii_digit()) => version,
+            _ => token,
+        })
+        .filter(|token| !token.is_empty())
+        .map(str::to_string)
+        .collect();
+}
+
+fn has_token(tokens: &[String], required: &str) -> bool {
+    let is_version = required.starts_with(|char: char| char.is_ascii_digit());
+
+    return tokens.iter().any(|token| {
+        token == required || (is_version && token.starts_with(required) && token[required.len()..].starts_with('.'))
+    });
+}
+
+/// Licenses of a dependency, as far as they could be looked up.
+#[derive(Debug, Clone, PartialEq)]
+pub enum LicenseLookup {
+    Found { version: String, licenses: Vec<String> },
+    Failed(String),
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Verdict {
+    Allowed,
+    /// One of the licenses is on the deny list and none is allowed.
+    Denied,
+    /// An allow list exists and none of the licenses is on it.
+    NotAllowed,
+    /// No license is known, so the dependency needs a manual review.
+    Unknown,
+}
+
+impl Verdict {
+    pub fn label(&self) -> &'static str {
+        match self {
+            Verdict::Allowed => "allowed",
+            Verdict::Denied => "denied",
+            Verdict::NotAllowed => "not allowed",
+            Verdict::Unknown => "unknown",
+        }
+    }
+}
+
+/// Licenses the project accepts or rejects, by SPDX identifier or name.
+/// Without an allow list every license that is not denied is accepted.
+#[derive(Debug, Clone, Default, Deserialize)]
+pub struct LicensePolicy {
+    #[serde(default)]
+    pub allow: Vec<String>,
+    #[serde(default)]
+    pub deny: Vec<String>,
+}
+
+impl LicensePolicy {
+    /// Reads the policy file from the given directory. A missing file is an
+    /// empty policy.
+    pub fn load(directory: &Path) -> Result<LicensePolicy> {
+        let path = directory.join(POLICY_FILE_NAME);
+
+        if !path.exists() {
+            return Ok(LicensePolicy::default());
+        }
+
+        let content = fs::read_to_string(&path).with_context(|| format!("cannot read {}", path.display()))?;
+
+        return serde_json::from_str(&content).with_context(|| format!("invalid license policy {}", path.display()));
+    }
+
+    /// A dependency offered under several licenses may be used under any of
+    /// them, so a single acceptable license is enough.
+    pub fn evaluate(&self, licenses: &[String]) -> Verdict {
+        if licenses.is_empty() {
+            return Verdict::Unknown;
+        }
+
+        let listed = |list: &[String], license: &String| list.iter().any(|entry| entry.eq_ignore_ascii_case(license));
+
+        let allowed = licenses.iter().any(|license| {
+            !listed(&self.deny, license) && (self.allow.is_empty() || listed(&self.allow, license))
+        });
+
+        if allowed {
+            return Verdict::Allowed;
+        }
+
+        if licenses.iter().any(|license| listed(&self.deny, license)) {
+            return Verdict::Denied;
+        }
+
+        return Verdict::NotAllowed;
+    }
+}
+
+/// A line of the exported license report.
+pub struct ReportRow<'a> {
+    pub group_id: &'a str,
+    pub artifact_id: &'a str,
+    pub version: &'a str,
+    pub licenses: &'a [String],
+    pub verdict: Verdict,
+}
+
+/// Writes the rows as CSV and returns the path of the report.
+pub fn export_report(directory: &Path, rows: &[ReportRow]) -> Result<PathBuf> {
+    let mut content = String::from("groupId,artifactId,version,licenses,verdict\n");
+
+    for row in rows {
+        let fields = [
+            row.group_id.to_string(),
+            row.artifact_id.to_string(),
+            row.version.to_string(),
+            row.licenses.join(" OR "),
+            row.verdict.label().to_string(),
+        ];
+
+        let fields: Vec<String> = fields.iter().map(|field| csv_field(field)).collect();
+        content.push_str(&fields.join(","));
+        content.push('\n');
+    }
+
+    let path = directory.join(REPORT_FILE_NAME);
+    fs::write(&path, content).with_context(|| format!("cannot write {}", path.display()))?;
+
+    return Ok(path);
+}
+
+fn csv_field(value: &str) -> String {
+    if value.contains([',', '"', '\n']) {
+        return format!("\"{}\"", value.replace('"', "\"\""));
+    }
+
+    return value.to_string();
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn license(name: &str, url: &str) -> License {
+        return License { name: name.to_string(), url: url.to_string() };
+    }
+
+    #[test]
+    fn normalizes_common_license_names() {
+        let cases = [
+            ("The Apache Software License, Version 2.0", "Apache-2.0"),
+            ("Apache License 2", "Apache-2.0"),
+            ("MIT License", "MIT"),
+            ("Eclipse Public License - v 1.0", "EPL-1.0"),
+            ("Eclipse Public License v2.0", "EPL-2.0"),
+            ("Eclipse Distribution License - v 1.0", "BSD-3-Clause"),
+            ("GNU Lesser General Public License, version 2.1", "LGPL-2.1-only"),
+            ("GNU General Public License, version 2 with the Classpath Exception", "GPL-2.0-only WITH Classpath-exception-2.0"),
+            ("New BSD License", "BSD-3-Clause"),
+            ("mpl-2.0", "MPL-2.0"),
+            ("CDDL 1.1", "CDDL-1.1"),
+        ];
+
+        for (name, expected) in cases {
+            assert_eq!(spdx_id(&license(name, "")), expected, "{}", name);
+        }
+    }
+
+    #[test]
+    fn falls_back_to_url_and_name() {
+        assert_eq!(spdx_id(&license("", "https://www.apache.org/licenses/LICENSE-2.0.txt")), "Apache-2.0");
+        assert_eq!(spdx_id(&license("Bouncy Castle Licence", "https://www.bouncycastle.org/licence.html")), "Bouncy Castle Licence");
+        assert_eq!(spdx_id(&license("", "https://example.com/license")), "https://example.com/license");
+    }
+
+    #[test]
+    fn evaluates_policy() {
+        let policy = LicensePolicy {
+            allow: vec!["Apache-2.0".to_string(), "MIT".to_string()],
+            deny: vec!["GPL-3.0-only".to_string()],
+        };
+        let licenses = |ids: &[&str]| ids.iter().map(|id| id.to_string()).collect::<Vec<String>>();
+
+        assert_eq!(policy.evaluate(&licenses(&["apache-2.0"])), Verdict::Allowed);
+        assert_eq!(policy.evaluate(&licenses(&["GPL-3.0-only", "MIT"])), Verdict::Allowed);
+        assert_eq!(policy.evaluate(&licenses(&["GPL-3.0-only"])), Verdict::Denied);
+        assert_eq!(policy.evaluate(&licenses(&["EPL-2.0"])), Verdict::NotAllowed);
+        assert_eq!(policy.evaluate(&[]), Verdict::Unknown);
+
+        assert_eq!(LicensePolicy::default().evaluate(&licenses(&["EPL-2.0"])), Verdict::Allowed);
+    }
+
+    #[test]
+    fn escapes_csv_fields() {
+        assert_eq!(csv_field("Apache-2.0"), "Apache-2.0");
+        assert_eq!(csv_field("CDDL-1.0 OR GPL, with \"exception\""), "\"CDDL-1.0 OR GPL, with \"\"exception\"\"\"");
+    }
+}
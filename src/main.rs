@@ -1,18 +1,41 @@
 mod app;
+mod audit;
+mod batch;
+mod build_file;
 mod dependency;
+mod dependency_editor;
+mod effective_pom;
 mod events;
+mod fuzzy;
+mod gradle;
+mod license;
 mod maven_registry;
+mod maven_version;
+mod pom;
+mod popup;
+mod profile;
+mod project_info;
+mod resolver;
+mod search_query;
+mod search_ranking;
 mod ui;
 mod views;
+mod widgets;
 
 use anyhow::Result;
 use app::App;
+use crossterm::event::{DisableBracketedPaste, EnableBracketedPaste};
 
 #[tokio::main]
 async fn main() -> Result<()> {
     let mut terminal = ratatui::init();
-    App::new()?.run(&mut terminal).await?;
+    crossterm::execute!(std::io::stdout(), EnableBracketedPaste)?;
+
+    let result = App::new()?.run(&mut terminal).await;
+
+    crossterm::execute!(std::io::stdout(), DisableBracketedPaste)?;
     ratatui::restore();
+    result?;
 
     return Ok(());
 }
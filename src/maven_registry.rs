@@ -1,43 +1,53 @@
-use anyhow::Result;
+use anyhow::{Result, anyhow};
+use xmltree::Element;
+use reqwest::Url;
 use serde::{Deserialize, Serialize};
 
+use crate::search_query::{SearchQuery, SearchTerm};
+
 pub struct MavenRegistry {}
 
 static API_URL: &str = "https://search.maven.org";
+static REPOSITORY_URL: &str = "https://repo1.maven.org/maven2";
+
+/// Number of rows requested from the registry per page.
+pub const PAGE_SIZE: u32 = 20;
 
 #[derive(Serialize, Deserialize, Debug)]
 #[serde(rename_all(serialize = "camelCase", deserialize = "camelCase"))]
 pub struct SearchResponse {
-    num_found: u32,
-    start: u32,
+    pub num_found: u32,
+    pub start: u32,
     pub docs: Vec<SearchResponseDoc>,
 }
 
 #[derive(Serialize, Deserialize, Debug, Clone, Default)]
-#[serde(rename_all(serialize = "camelCase", deserialize = "camelCase"))]
+#[serde(default, rename_all(serialize = "camelCase", deserialize = "camelCase"))]
 pub struct SearchResponseDoc {
     pub id: String,
     pub g: String,
     pub a: String,
+    /// Only present in results of the `gav` core, e.g. class name searches.
+    pub v: String,
     repository_id: String,
-    p: String,
-    timestamp: u64,
-    version_count: u32,
+    pub p: String,
+    pub timestamp: u64,
+    pub version_count: u32,
     text: Vec<String>,
-    ec: Vec<String>,
-    latest_version: String,
+    pub ec: Vec<String>,
+    pub latest_version: String,
 }
 
 #[derive(Serialize, Deserialize, Debug, Clone, Default)]
 #[serde(rename_all(serialize = "camelCase", deserialize = "camelCase"))]
 pub struct GetVersionsResponse {
-    num_found: u32,
-    start: u32,
+    pub num_found: u32,
+    pub start: u32,
     pub docs: Vec<GetVersionsResponseDoc>,
 }
 
 #[derive(Serialize, Deserialize, Debug, Clone, Default)]
-#[serde(rename_all(serialize = "camelCase", deserialize = "camelCase"))]
+#[serde(default, rename_all(serialize = "camelCase", deserialize = "camelCase"))]
 pub struct GetVersionsResponseDoc {
     pub id: String,
     pub g: String,
@@ -56,17 +66,28 @@ pub struct MavenResponse<T> {
 }
 
 impl MavenRegistry {
-    fn api_search_preview_url(query: String, rows: u32) -> String {
-        format!(
-            "{}/solrsearch/select?q={}&rows={}&wt=json",
-            API_URL, query, rows
-        )
+    fn api_search_url(query: &SearchQuery, start: u32, rows: u32) -> Result<Url> {
+        let mut params = vec![
+            ("q", query.to_solr()),
+            ("start", start.to_string()),
+            ("rows", rows.to_string()),
+            ("wt", "json".to_string()),
+        ];
+
+        if query.requires_gav_core() {
+            params.push(("core", "gav".to_string()));
+        }
+
+        let url = Url::parse_with_params(&format!("{}/solrsearch/select", API_URL), &params)?;
+
+        return Ok(url);
     }
 
-    pub async fn search_dependencies(search_phrase: String) -> Result<MavenResponse<SearchResponse>> {
+    pub async fn search_dependencies(search_phrase: String, start: u32) -> Result<MavenResponse<SearchResponse>> {
         let client = reqwest::Client::new();
 
-        let request_url = MavenRegistry::api_search_preview_url(search_phrase, 20);
+        let query = SearchQuery::parse(&search_phrase);
+        let request_url = MavenRegistry::api_search_url(&query, start, PAGE_SIZE)?;
 
         let response = client
             .get(request_url)
@@ -79,13 +100,15 @@ impl MavenRegistry {
         return Ok(response);
     }
 
-    pub async fn get_available_dependency_versions(group_id: String, artifact_id: String) -> Result<MavenResponse<GetVersionsResponse>> {
+    pub async fn get_available_dependency_versions(group_id: String, artifact_id: String, start: u32) -> Result<MavenResponse<GetVersionsResponse>> {
         let client = reqwest::Client::new();
 
-        let request_url = format!(
-            "{}/solrsearch/select?q=g:{}+AND+a:{}&core=gav&rows={}&wt=json",
-            API_URL, group_id, artifact_id, 20
-        );
+        let query = SearchQuery {
+            terms: vec![SearchTerm::GroupId(group_id), SearchTerm::ArtifactId(artifact_id)],
+        };
+
+        let mut request_url = MavenRegistry::api_search_url(&query, start, PAGE_SIZE)?;
+        request_url.query_pairs_mut().append_pair("core", "gav");
 
         let response = client
             .get(request_url)
@@ -98,4 +121,127 @@ impl MavenRegistry {
         return Ok(response);
     }
 
+    fn pom_url(group_id: &str, artifact_id: &str, version: &str) -> String {
+        format!(
+            "{}/{}/{}/{}/{}-{}.pom",
+            REPOSITORY_URL, group_id.replace('.', "/"), artifact_id, version, artifact_id, version
+        )
+    }
+
+    pub async fn fetch_pom(group_id: String, artifact_id: String, version: String) -> Result<String> {
+        let client = reqwest::Client::new();
+
+        let request_url = MavenRegistry::pom_url(&group_id, &artifact_id, &version);
+
+        let response = client
+            .get(request_url)
+            .header("User-Agent", "LazyMaven")
+            .send()
+            .await?
+            .error_for_status()?
+            .text()
+            .await?;
+
+        return Ok(response);
+    }
+
+    fn metadata_url(group_id: &str, artifact_id: &str) -> String {
+        return format!("{}/{}/{}/maven-metadata.xml", REPOSITORY_URL, group_id.replace('.', "/"), artifact_id);
+    }
+
+    /// The `<versioning>` element of an artifact's `maven-metadata.xml`.
+    async fn fetch_versioning(group_id: &str, artifact_id: &str) -> Result<Element> {
+        let client = reqwest::Client::new();
+
+        let content = client
+            .get(MavenRegistry::metadata_url(group_id, artifact_id))
+            .header("User-Agent", "LazyMaven")
+            .send()
+            .await?
+            .error_for_status()?
+            .text()
+            .await?;
+
+        let mut metadata = Element::parse(content.as_bytes())?;
+
+        return metadata
+            .take_child("versioning")
+            .ok_or_else(|| anyhow!("no versioning in metadata of {}:{}", group_id, artifact_id));
+    }
+
+    /// Every version of an artifact listed in its `maven-metadata.xml`, oldest first.
+    pub async fn fetch_versions(group_id: String, artifact_id: String) -> Result<Vec<String>> {
+        let versioning = MavenRegistry::fetch_versioning(&group_id, &artifact_id).await?;
+
+        return Ok(MavenRegistry::listed_versions(&versioning));
+    }
+
+    fn listed_versions(versioning: &Element) -> Vec<String> {
+        return versioning
+            .get_child("versions")
+            .map(|versions| {
+                versions
+                    .children
+                    .iter()
+                    .filter_map(|child| child.as_element())
+                    .filter_map(|version| version.get_text())
+                    .map(|text| text.trim().to_string())
+                    .collect()
+            })
+            .unwrap_or_default();
+    }
+
+    /// Latest release of an artifact according to its `maven-metadata.xml`.
+    pub async fn fetch_latest_version(group_id: String, artifact_id: String) -> Result<String> {
+        let versioning = MavenRegistry::fetch_versioning(&group_id, &artifact_id).await?;
+
+        return MavenRegistry::latest_release(&versioning)
+            .ok_or_else(|| anyhow!("no released version of {}:{}", group_id, artifact_id));
+    }
+
+    /// The `<release>` of the metadata, or else its newest listed version
+    /// that is not a SNAPSHOT. `<latest>` is ignored as it may be a SNAPSHOT.
+    fn latest_release(versioning: &Element) -> Option<String> {
+        let release = versioning
+            .get_child("release")
+            .and_then(|element| element.get_text())
+            .map(|text| text.trim().to_string())
+            .filter(|version| !version.is_empty());
+
+        return release.or_else(|| {
+            MavenRegistry::listed_versions(versioning)
+                .into_iter()
+                .rev()
+                .find(|version| !version.ends_with("-SNAPSHOT"))
+        });
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn versioning(xml: &str) -> Element {
+        return Element::parse(xml.as_bytes()).unwrap();
+    }
+
+    #[test]
+    fn prefers_the_release_over_the_latest_version() {
+        let versioning = versioning(
+            "<versioning><latest>2.0-SNAPSHOT</latest><release>1.1</release>\
+             <versions><version>1.1</version><version>2.0-SNAPSHOT</version></versions></versioning>",
+        );
+
+        assert_eq!(MavenRegistry::latest_release(&versioning).as_deref(), Some("1.1"));
+    }
+
+    #[test]
+    fn falls_back_to_the_newest_listed_release() {
+        let versioning = versioning(
+            "<versioning><latest>2.0-SNAPSHOT</latest>\
+             <versions><version>1.0</version><version>1.1</version><version>2.0-SNAPSHOT</version></versions></versioning>",
+        );
+
+        assert_eq!(MavenRegistry::latest_release(&versioning).as_deref(), Some("1.1"));
+    }
 }
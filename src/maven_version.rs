@@ -0,0 +1,544 @@
+use std::cmp::Ordering;
+
+/// A version ordered the way Maven orders them, e.g. `1.0-alpha-1 < 1.0-rc1 < 1.0 < 1.0-sp1 < 1.0.1`.
+///
+/// Follows the rules of Maven's `ComparableVersion`: numbers compare
+/// numerically, well known qualifiers have a fixed order and `-` starts a
+/// sub-list that sorts before a `.` separated number.
+#[derive(Debug, Clone)]
+pub struct MavenVersion {
+    value: String,
+    items: Vec<Item>,
+}
+
+#[derive(Debug, Clone)]
+enum Item {
+    /// Digits without leading zeros, compared by length first to avoid overflow.
+    Number(String),
+    Qualifier(String),
+    List(Vec<Item>),
+}
+
+/// Qualifiers in release order, the empty one standing for the release itself.
+const QUALIFIERS: &[&str] = &["alpha", "beta", "milestone", "rc", "snapshot", "", "sp"];
+
+impl MavenVersion {
+    pub fn parse(value: &str) -> MavenVersion {
+        let value = value.trim();
+        let lowercase = value.to_lowercase();
+        let chars: Vec<char> = lowercase.chars().collect();
+
+        // Path of indices from the root list to the list currently filled.
+        let mut root: Vec<Item> = vec![];
+        let mut path: Vec<usize> = vec![];
+        let mut start = 0;
+        let mut is_digit = false;
+
+        for (i, char) in chars.iter().enumerate() {
+            match char {
+                '.' | '-' => {
+                    let token: String = chars[start..i].iter().collect();
+                    let item = match token.is_empty() {
+                        true => Item::Number("0".to_string()),
+                        false => parse_item(&token, is_digit, false),
+                    };
+                    current_list(&mut root, &path).push(item);
+                    start = i + 1;
+
+                    if *char == '-' {
+                        push_sublist(&mut root, &mut path);
+                    }
+                }
+                char if char.is_ascii_digit() => {
+                    if !is_digit && i > start {
+                        let token: String = chars[start..i].iter().collect();
+                        current_list(&mut root, &path).push(parse_item(&token, false, true));
+                        start = i;
+                        push_sublist(&mut root, &mut path);
+                    }
+                    is_digit = true;
+                }
+                _ => {
+                    if is_digit && i > start {
+                        let token: String = chars[start..i].iter().collect();
+                        current_list(&mut root, &path).push(parse_item(&token, true, false));
+                        start = i;
+                        push_sublist(&mut root, &mut path);
+                    }
+                    is_digit = false;
+                }
+            }
+        }
+
+        if chars.len() > start {
+            let token: String = chars[start..].iter().collect();
+            current_list(&mut root, &path).push(parse_item(&token, is_digit, false));
+        }
+
+        normalize(&mut root);
+
+        return MavenVersion { value: value.to_string(), items: root };
+    }
+
+    pub fn as_str(&self) -> &str {
+        return &self.value;
+    }
+
+    /// Whether the version is an alpha, beta, milestone, release candidate
+    /// or snapshot.
+    pub fn is_prerelease(&self) -> bool {
+        return contains_prerelease(&self.items);
+    }
+
+    /// Leading numbers of the version, e.g. `[33, 0, 0]` for `33.0.0-jre`.
+    fn release_numbers(&self) -> Vec<&str> {
+        return self
+            .value
+            .split(['.', '-'])
+            .map_while(|part| Some(part).filter(|part| !part.is_empty() && part.chars().all(|char| char.is_ascii_digit())))
+            .map(|part| Some(part.trim_start_matches('0')).filter(|part| !part.is_empty()).unwrap_or("0"))
+            .collect();
+    }
+}
+
+fn contains_prerelease(items: &[Item]) -> bool {
+    return items.iter().any(|item| match item {
+        Item::Qualifier(qualifier) => qualifier_rank(qualifier) < qualifier_rank(""),
+        Item::List(items) => contains_prerelease(items),
+        Item::Number(_) => false,
+    });
+}
+
+/// How far an upgrade may move a version.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum UpgradeLevel {
+    /// Within the same major and minor version.
+    Patch,
+    /// Within the same major version.
+    Minor,
+}
+
+impl UpgradeLevel {
+    pub fn label(&self) -> &'static str {
+        match self {
+            UpgradeLevel::Patch => "latest patch",
+            UpgradeLevel::Minor => "latest minor",
+        }
+    }
+}
+
+/// How far a version is behind the latest one.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum Outdatedness {
+    UpToDate,
+    Patch,
+    Minor,
+    Major,
+}
+
+impl Outdatedness {
+    pub fn label(&self) -> &'static str {
+        match self {
+            Outdatedness::UpToDate => "up to date",
+            Outdatedness::Patch => "patch behind",
+            Outdatedness::Minor => "minor behind",
+            Outdatedness::Major => "major behind",
+        }
+    }
+}
+
+/// Which part of `current` has to change to reach `latest`.
+pub fn outdatedness(current: &str, latest: &str) -> Outdatedness {
+    let (current, latest) = (MavenVersion::parse(current), MavenVersion::parse(latest));
+    if latest <= current {
+        return Outdatedness::UpToDate;
+    }
+
+    let (current_numbers, latest_numbers) = (current.release_numbers(), latest.release_numbers());
+    let differs = |index: usize| current_numbers.get(index).unwrap_or(&"0") != latest_numbers.get(index).unwrap_or(&"0");
+
+    if differs(0) {
+        return Outdatedness::Major;
+    }
+    if differs(1) {
+        return Outdatedness::Minor;
+    }
+
+    return Outdatedness::Patch;
+}
+
+/// Highest release among `available` that is newer than `current` and
+/// stays within `level`. Pre-releases are only picked for a pre-release.
+pub fn latest_upgrade(current: &str, available: &[String], level: UpgradeLevel) -> Option<String> {
+    let current = MavenVersion::parse(current);
+    let kept = match level {
+        UpgradeLevel::Patch => 2,
+        UpgradeLevel::Minor => 1,
+    };
+
+    let prefix = |version: &MavenVersion| -> Vec<String> {
+        let mut numbers: Vec<String> = version.release_numbers().iter().take(kept).map(|number| number.to_string()).collect();
+        numbers.resize(kept, "0".to_string());
+        return numbers;
+    };
+
+    let current_prefix = prefix(&current);
+    if current.release_numbers().is_empty() {
+        return None;
+    }
+
+    return available
+        .iter()
+        .map(|version| MavenVersion::parse(version))
+        .filter(|version| *version > current && prefix(version) == current_prefix)
+        .filter(|version| current.is_prerelease() || !version.is_prerelease())
+        .max()
+        .map(|version| version.as_str().to_string());
+}
+
+fn parse_item(token: &str, is_digit: bool, followed_by_digit: bool) -> Item {
+    if is_digit {
+        let trimmed = token.trim_start_matches('0');
+        return Item::Number(if trimmed.is_empty() { "0".to_string() } else { trimmed.to_string() });
+    }
+
+    let qualifier = match token {
+        "a" if followed_by_digit => "alpha",
+        "b" if followed_by_digit => "beta",
+        "m" if followed_by_digit => "milestone",
+        "ga" | "final" | "release" => "",
+        "cr" => "rc",
+        other => other,
+    };
+
+    return Item::Qualifier(qualifier.to_string());
+}
+
+fn current_list<'a>(root: &'a mut Vec<Item>, path: &[usize]) -> &'a mut Vec<Item> {
+    let mut list = root;
+
+    for index in path {
+        list = match &mut list[*index] {
+            Item::List(items) => items,
+            _ => unreachable!("version path only points at lists"),
+        };
+    }
+
+    return list;
+}
+
+fn push_sublist(root: &mut Vec<Item>, path: &mut Vec<usize>) {
+    let list = current_list(root, path);
+    list.push(Item::List(vec![]));
+    path.push(list.len() - 1);
+}
+
+/// Drops items equal to the release (`0`, empty qualifiers and lists) from
+/// the end of each list, so that `1.0.0`, `1.0` and `1-ga` are the same
+/// version. Like Maven, nulls right before a sub-list are dropped as well.
+fn normalize(items: &mut Vec<Item>) {
+    for item in items.iter_mut() {
+        if let Item::List(list) = item {
+            normalize(list);
+        }
+    }
+
+    let mut index = items.len();
+    while index > 0 {
+        index -= 1;
+
+        if items[index].is_null() {
+            items.remove(index);
+        } else if !matches!(items[index], Item::List(_)) {
+            break;
+        }
+    }
+}
+
+impl Item {
+    fn is_null(&self) -> bool {
+        match self {
+            Item::Number(number) => number == "0",
+            Item::Qualifier(qualifier) => qualifier.is_empty(),
+            Item::List(items) => items.is_empty(),
+        }
+    }
+
+    /// Compares against a missing item, which stands for the release.
+    fn compare_to_null(&self) -> Ordering {
+        match self {
+            Item::Number(number) if number == "0" => Ordering::Equal,
+            Item::Number(_) => Ordering::Greater,
+            Item::Qualifier(qualifier) => qualifier_rank(qualifier).cmp(&qualifier_rank("")),
+            Item::List(items) => items.first().map(Item::compare_to_null).unwrap_or(Ordering::Equal),
+        }
+    }
+
+    fn compare(&self, other: &Item) -> Ordering {
+        match (self, other) {
+            (Item::Number(left), Item::Number(right)) => left.len().cmp(&right.len()).then_with(|| left.cmp(right)),
+            (Item::Number(_), _) => Ordering::Greater,
+            (Item::Qualifier(_), Item::Number(_)) => Ordering::Less,
+            (Item::Qualifier(left), Item::Qualifier(right)) => qualifier_rank(left).cmp(&qualifier_rank(right)),
+            (Item::Qualifier(_), Item::List(_)) => Ordering::Less,
+            (Item::List(_), Item::Number(_)) => Ordering::Less,
+            (Item::List(_), Item::Qualifier(_)) => Ordering::Greater,
+            (Item::List(left), Item::List(right)) => compare_lists(left, right),
+        }
+    }
+}
+
+fn compare_lists(left: &[Item], right: &[Item]) -> Ordering {
+    for i in 0..left.len().max(right.len()) {
+        let ordering = match (left.get(i), right.get(i)) {
+            (Some(left), Some(right)) => left.compare(right),
+            (Some(left), None) => left.compare_to_null(),
+            (None, Some(right)) => right.compare_to_null().reverse(),
+            (None, None) => Ordering::Equal,
+        };
+
+        if ordering != Ordering::Equal {
+            return ordering;
+        }
+    }
+
+    return Ordering::Equal;
+}
+
+/// Known qualifiers sort by their position, unknown ones after all of them
+/// in alphabetical order.
+fn qualifier_rank(qualifier: &str) -> (usize, &str) {
+    match QUALIFIERS.iter().position(|known| *known == qualifier) {
+        Some(position) => (position, ""),
+        None => (QUALIFIERS.len(), qualifier),
+    }
+}
+
+impl PartialEq for MavenVersion {
+    fn eq(&self, other: &Self) -> bool {
+        return self.cmp(other) == Ordering::Equal;
+    }
+}
+
+impl Eq for MavenVersion {}
+
+impl PartialOrd for MavenVersion {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        return Some(self.cmp(other));
+    }
+}
+
+impl Ord for MavenVersion {
+    fn cmp(&self, other: &Self) -> Ordering {
+        return compare_lists(&self.items, &other.items);
+    }
+}
+
+/// One interval of a version range. Missing bounds are unbounded.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Restriction {
+    pub lower: Option<MavenVersion>,
+    pub lower_inclusive: bool,
+    pub upper: Option<MavenVersion>,
+    pub upper_inclusive: bool,
+}
+
+impl Restriction {
+    pub fn contains(&self, version: &MavenVersion) -> bool {
+        let above_lower = match &self.lower {
+            Some(lower) if self.lower_inclusive => version >= lower,
+            Some(lower) => version > lower,
+            None => true,
+        };
+
+        let below_upper = match &self.upper {
+            Some(upper) if self.upper_inclusive => version <= upper,
+            Some(upper) => version < upper,
+            None => true,
+        };
+
+        return above_lower && below_upper;
+    }
+}
+
+/// A Maven version range such as `[1.0,2.0)` or `(,1.0],[1.2,)`, matching a
+/// version when any of its intervals does.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct VersionRange {
+    pub restrictions: Vec<Restriction>,
+}
+
+impl VersionRange {
+    /// Parses the bracket syntax of Maven version ranges. A plain version is
+    /// a range containing only that version.
+    pub fn parse(spec: &str) -> Option<VersionRange> {
+        let spec = spec.trim();
+
+        if !spec.starts_with(['[', '(']) {
+            if spec.is_empty() {
+                return None;
+            }
+
+            let version = MavenVersion::parse(spec);
+            return Some(VersionRange {
+                restrictions: vec![Restriction {
+                    lower: Some(version.clone()),
+                    lower_inclusive: true,
+                    upper: Some(version),
+                    upper_inclusive: true,
+                }],
+            });
+        }
+
+        let mut restrictions = vec![];
+        let mut rest = spec;
+
+        while !rest.is_empty() {
+            let lower_inclusive = match rest.chars().next()? {
+                '[' => true,
+                '(' => false,
+                _ => return None,
+            };
+
+            let end = rest.find([']', ')'])?;
+            let upper_inclusive = rest[end..].starts_with(']');
+            let inner = &rest[1..end];
+
+            let restriction = match inner.split_once(',') {
+                Some((lower, upper)) => Restriction {
+                    lower: Some(lower.trim()).filter(|bound| !bound.is_empty()).map(MavenVersion::parse),
+                    lower_inclusive,
+                    upper: Some(upper.trim()).filter(|bound| !bound.is_empty()).map(MavenVersion::parse),
+                    upper_inclusive,
+                },
+                // `[1.0]` pins exactly one version.
+                None if lower_inclusive && upper_inclusive => {
+                    let version = MavenVersion::parse(inner);
+                    Restriction {
+                        lower: Some(version.clone()),
+                        lower_inclusive: true,
+                        upper: Some(version),
+                        upper_inclusive: true,
+                    }
+                }
+                None => return None,
+            };
+
+            restrictions.push(restriction);
+            rest = rest[end + 1..].trim_start_matches([',', ' ']);
+        }
+
+        return Some(VersionRange { restrictions });
+    }
+
+    pub fn contains(&self, version: &MavenVersion) -> bool {
+        return self.restrictions.iter().any(|restriction| restriction.contains(version));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn assert_ordered(versions: &[&str]) {
+        for pair in versions.windows(2) {
+            let (lower, higher) = (MavenVersion::parse(pair[0]), MavenVersion::parse(pair[1]));
+            assert!(lower < higher, "expected {} < {}", pair[0], pair[1]);
+        }
+    }
+
+    #[test]
+    fn orders_numbers_numerically() {
+        assert_ordered(&["1", "1.1", "1.2", "1.10", "2", "10", "100000000000000000000000"]);
+    }
+
+    #[test]
+    fn orders_qualifiers() {
+        assert_ordered(&[
+            "1.0-alpha-1",
+            "1.0-alpha-2",
+            "1.0-beta1",
+            "1.0-m1",
+            "1.0-rc1",
+            "1.0-SNAPSHOT",
+            "1.0",
+            "1.0-sp1",
+            "1.0-whatever",
+            "1.0.1",
+        ]);
+    }
+
+    #[test]
+    fn release_aliases_are_equal() {
+        let release = MavenVersion::parse("1.0");
+
+        for alias in ["1", "1.0.0", "1-ga", "1.0.final", "1.0-RELEASE"] {
+            assert_eq!(MavenVersion::parse(alias), release, "{} should equal 1.0", alias);
+        }
+
+        assert_eq!(MavenVersion::parse("1.0-cr1"), MavenVersion::parse("1.0-rc1"));
+        assert_eq!(MavenVersion::parse("1.0a1"), MavenVersion::parse("1.0-alpha1"));
+    }
+
+    #[test]
+    fn dash_sorts_before_dot() {
+        assert_ordered(&["2.9.10", "2.9.10-1", "2.9.10.1", "2.9.10.8", "2.10.0"]);
+    }
+
+    #[test]
+    fn finds_latest_patch_and_minor_upgrades() {
+        let available: Vec<String> = ["1.2.3", "1.2.4", "1.2.10", "1.3.0", "1.4.0-rc1", "1.4.1", "2.0.0", "1.2.11-SNAPSHOT"]
+            .iter()
+            .map(|version| version.to_string())
+            .collect();
+
+        assert_eq!(latest_upgrade("1.2.3", &available, UpgradeLevel::Patch).as_deref(), Some("1.2.10"));
+        assert_eq!(latest_upgrade("1.2.3", &available, UpgradeLevel::Minor).as_deref(), Some("1.4.1"));
+        assert_eq!(latest_upgrade("1.4.1", &available, UpgradeLevel::Patch), None);
+        assert_eq!(latest_upgrade("${lib.version}", &available, UpgradeLevel::Minor), None);
+        assert!(MavenVersion::parse("1.0-beta2").is_prerelease());
+        assert!(!MavenVersion::parse("33.0.0-jre").is_prerelease());
+    }
+
+    #[test]
+    fn tells_how_outdated_a_version_is() {
+        assert_eq!(outdatedness("1.2.3", "2.0.0"), Outdatedness::Major);
+        assert_eq!(outdatedness("1.2.3", "1.3"), Outdatedness::Minor);
+        assert_eq!(outdatedness("1.2", "1.2.1"), Outdatedness::Patch);
+        assert_eq!(outdatedness("1.2.3-rc1", "1.2.3"), Outdatedness::Patch);
+        assert_eq!(outdatedness("1.2.3", "1.2.3"), Outdatedness::UpToDate);
+        assert_eq!(outdatedness("2.0", "1.9"), Outdatedness::UpToDate);
+    }
+
+    #[test]
+    fn parses_ranges() {
+        let range = VersionRange::parse("[1.0,2.0)").unwrap();
+
+        assert!(range.contains(&MavenVersion::parse("1.0")));
+        assert!(range.contains(&MavenVersion::parse("1.9.9")));
+        assert!(!range.contains(&MavenVersion::parse("2.0")));
+        assert!(!range.contains(&MavenVersion::parse("0.9")));
+    }
+
+    #[test]
+    fn parses_ranges_with_several_intervals() {
+        let range = VersionRange::parse("(,1.0],[1.2,)").unwrap();
+
+        assert!(range.contains(&MavenVersion::parse("0.1")));
+        assert!(range.contains(&MavenVersion::parse("1.0")));
+        assert!(!range.contains(&MavenVersion::parse("1.1")));
+        assert!(range.contains(&MavenVersion::parse("1.2")));
+        assert!(range.contains(&MavenVersion::parse("5")));
+    }
+
+    #[test]
+    fn exact_versions_and_invalid_ranges() {
+        let exact = VersionRange::parse("[1.5]").unwrap();
+
+        assert!(exact.contains(&MavenVersion::parse("1.5.0")));
+        assert!(!exact.contains(&MavenVersion::parse("1.5.1")));
+        assert!(VersionRange::parse("1.5").unwrap().contains(&MavenVersion::parse("1.5")));
+        assert_eq!(VersionRange::parse("(1.5)"), None);
+        assert_eq!(VersionRange::parse("[1.0,2.0"), None);
+    }
+}
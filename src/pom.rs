@@ -0,0 +1,293 @@
+use anyhow::Result;
+use std::io;
+use xmltree::Element;
+
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct License {
+    pub name: String,
+    pub url: String,
+}
+
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct Scm {
+    pub url: String,
+    pub connection: String,
+}
+
+/// Descriptive information of an artifact, read from its published `.pom`.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct PomMetadata {
+    pub name: String,
+    pub description: String,
+    pub url: String,
+    pub licenses: Vec<License>,
+    pub scm: Option<Scm>,
+}
+
+impl PomMetadata {
+    pub fn parse(content: &str) -> Result<PomMetadata> {
+        let root = Element::parse(content.as_bytes())
+            .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))?;
+
+        let scm = root.get_child("scm").map(|scm| Scm {
+            url: child_text(scm, "url"),
+            connection: child_text(scm, "connection"),
+        });
+
+        return Ok(PomMetadata {
+            name: child_text(&root, "name"),
+            description: child_text(&root, "description"),
+            url: child_text(&root, "url"),
+            licenses: licenses_of(&root),
+            scm,
+        });
+    }
+}
+
+/// Trimmed text of a direct child element, empty when it does not exist.
+pub fn child_text(element: &Element, name: &str) -> String {
+    return element
+        .get_child(name)
+        .and_then(|child| child.get_text())
+        .map(|text| text.trim().to_string())
+        .unwrap_or_default();
+}
+
+/// Entries of the `<licenses>` child of the given element.
+pub fn licenses_of(element: &Element) -> Vec<License> {
+    return element
+        .get_child("licenses")
+        .map(|licenses| {
+            licenses
+                .children
+                .iter()
+                .filter_map(|child| child.as_element())
+                .map(|license| License {
+                    name: child_text(license, "name"),
+                    url: child_text(license, "url"),
+                })
+                .collect()
+        })
+        .unwrap_or_default();
+}
+
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct Parent {
+    pub group_id: String,
+    pub artifact_id: String,
+    pub version: String,
+    pub relative_path: Option<String>,
+}
+
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct Exclusion {
+    pub group_id: String,
+    pub artifact_id: String,
+}
+
+impl Exclusion {
+    /// Exclusions may use `*` as a wildcard for either coordinate.
+    pub fn matches(&self, group_id: &str, artifact_id: &str) -> bool {
+        return (self.group_id == "*" || self.group_id == group_id)
+            && (self.artifact_id == "*" || self.artifact_id == artifact_id);
+    }
+}
+
+/// A `<dependency>` entry of a POM, either declared or managed.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct PomDependency {
+    pub group_id: String,
+    pub artifact_id: String,
+    pub version: String,
+    pub scope: String,
+    pub optional: bool,
+    pub classifier: String,
+    pub type_: String,
+    pub exclusions: Vec<Exclusion>,
+}
+
+impl PomDependency {
+    pub fn from_element(element: &Element) -> PomDependency {
+        let exclusions = element
+            .get_child("exclusions")
+            .map(|exclusions| {
+                exclusions
+                    .children
+                    .iter()
+                    .filter_map(|child| child.as_element())
+                    .map(|exclusion| Exclusion {
+                        group_id: child_text(exclusion, "groupId"),
+                        artifact_id: child_text(exclusion, "artifactId"),
+                    })
+                    .collect()
+            })
+            .unwrap_or_default();
+
+        return PomDependency {
+            group_id: child_text(element, "groupId"),
+            artifact_id: child_text(element, "artifactId"),
+            version: child_text(element, "version"),
+            scope: child_text(element, "scope"),
+            optional: child_text(element, "optional") == "true",
+            classifier: child_text(element, "classifier"),
+            type_: child_text(element, "type"),
+            exclusions,
+        };
+    }
+
+    pub fn id(&self) -> String {
+        return format!("{}:{}", self.group_id, self.artifact_id);
+    }
+
+    /// Key used by Maven to match dependencies against their management entries.
+    pub fn management_key(&self) -> String {
+        let type_ = if self.type_.is_empty() { "jar" } else { &self.type_ };
+
+        return format!("{}:{}:{}:{}", self.group_id, self.artifact_id, type_, self.classifier);
+    }
+
+    /// `<scope>import</scope>` entries of `<dependencyManagement>` pull in a BOM.
+    pub fn is_bom_import(&self) -> bool {
+        return self.scope == "import" && self.type_ == "pom";
+    }
+}
+
+/// The parts of a POM needed to build its dependency graph.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct Pom {
+    pub group_id: String,
+    pub artifact_id: String,
+    pub version: String,
+    pub packaging: String,
+    pub parent: Option<Parent>,
+    pub properties: Vec<(String, String)>,
+    pub licenses: Vec<License>,
+    pub dependency_management: Vec<PomDependency>,
+    pub dependencies: Vec<PomDependency>,
+}
+
+impl Pom {
+    pub fn parse(content: &str) -> Result<Pom> {
+        let root = Element::parse(content.as_bytes())
+            .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))?;
+
+        return Ok(Pom::from_element(&root));
+    }
+
+    pub fn from_element(root: &Element) -> Pom {
+        let parent = root.get_child("parent").map(|parent| Parent {
+            group_id: child_text(parent, "groupId"),
+            artifact_id: child_text(parent, "artifactId"),
+            version: child_text(parent, "version"),
+            relative_path: parent.get_child("relativePath").map(|_| child_text(parent, "relativePath")),
+        });
+
+        let properties = root
+            .get_child("properties")
+            .map(|properties| {
+                properties
+                    .children
+                    .iter()
+                    .filter_map(|child| child.as_element())
+                    .map(|property| {
+                        let value = property.get_text().map(|text| text.trim().to_string());
+                        (property.name.clone(), value.unwrap_or_default())
+                    })
+                    .collect()
+            })
+            .unwrap_or_default();
+
+        let dependency_management = root
+            .get_child("dependencyManagement")
+            .map(dependencies_of)
+            .unwrap_or_default();
+
+        return Pom {
+            group_id: child_text(root, "groupId"),
+            artifact_id: child_text(root, "artifactId"),
+            version: child_text(root, "version"),
+            packaging: child_text(root, "packaging"),
+            parent,
+            properties,
+            licenses: licenses_of(root),
+            dependency_management,
+            dependencies: dependencies_of(root),
+        };
+    }
+
+    /// Group id of the project, inherited from the parent when not declared.
+    pub fn group_id(&self) -> &str {
+        match (&self.group_id, &self.parent) {
+            (group_id, Some(parent)) if group_id.is_empty() => &parent.group_id,
+            (group_id, _) => group_id,
+        }
+    }
+
+    /// Version of the project, inherited from the parent when not declared.
+    pub fn version(&self) -> &str {
+        match (&self.version, &self.parent) {
+            (version, Some(parent)) if version.is_empty() => &parent.version,
+            (version, _) => version,
+        }
+    }
+}
+
+/// Entries of the `<dependencies>` child of the given element.
+pub fn dependencies_of(element: &Element) -> Vec<PomDependency> {
+    return element
+        .get_child("dependencies")
+        .map(|dependencies| {
+            dependencies
+                .children
+                .iter()
+                .filter_map(|child| child.as_element())
+                .map(PomDependency::from_element)
+                .collect()
+        })
+        .unwrap_or_default();
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn reads_metadata_of_a_published_pom() {
+        let metadata = PomMetadata::parse(
+            r#"<project xmlns="http://maven.apache.org/POM/4.0.0">
+                <name> Guava: Google Core Libraries for Java </name>
+                <url>https://github.com/google/guava</url>
+                <licenses>
+                    <license>
+                        <name>Apache License, Version 2.0</name>
+                        <url>http://www.apache.org/licenses/LICENSE-2.0.txt</url>
+                    </license>
+                    <license>
+                        <name>Public Domain</name>
+                    </license>
+                </licenses>
+                <scm>
+                    <url>https://github.com/google/guava</url>
+                </scm>
+            </project>"#,
+        )
+        .unwrap();
+
+        assert_eq!(metadata.name, "Guava: Google Core Libraries for Java");
+        assert_eq!(metadata.description, "");
+        assert_eq!(metadata.licenses[1], License { name: "Public Domain".to_string(), url: String::new() });
+        assert_eq!(
+            metadata.scm,
+            Some(Scm { url: "https://github.com/google/guava".to_string(), connection: String::new() }),
+        );
+    }
+
+    #[test]
+    fn leaves_out_missing_licenses_and_scm() {
+        let metadata = PomMetadata::parse("<project><licenses/></project>").unwrap();
+
+        assert!(metadata.licenses.is_empty());
+        assert_eq!(metadata.scm, None);
+        assert!(PomMetadata::parse("<project>").is_err());
+    }
+}
@@ -0,0 +1,349 @@
+use ratatui::{
+    buffer::Buffer,
+    layout::{Constraint, Flex, Layout, Rect},
+    style::{Color, Modifier, Style, Stylize, palette::tailwind::SLATE},
+    text::{Line, Span},
+    widgets::{Block, Borders, Clear, Paragraph, StatefulWidget, Widget, Wrap},
+};
+
+use crate::{
+    batch::{BatchChange, BatchOperation, PlannedChange},
+    dependency_editor::{DependencyEditor, EditorField},
+    maven_version::MavenVersion,
+    events::KeyBinding,
+    resolver::{DependencyTree, NodeStatus, ResolutionState},
+};
+
+#[derive(Debug, Clone)]
+pub enum Popup {
+    Help,
+    /// Every path through which an artifact ends up in the dependency tree.
+    DependencyPaths { group_id: String, artifact_id: String, scroll: u16 },
+    /// Outcome of an action that has no other place to report it.
+    Message { title: String, text: String },
+    /// What a batch operation will change, applied once confirmed.
+    BatchSummary { operation: BatchOperation, artifacts: Vec<(String, String)>, scroll: u16 },
+    /// Form changing a declared dependency.
+    EditDependency(Box<DependencyEditor>),
+}
+
+const KEY_STYLE: Style = Style::new().fg(SLATE.c200).add_modifier(Modifier::BOLD);
+const SECTION_STYLE: Style = Style::new().add_modifier(Modifier::UNDERLINED);
+const PATH_STYLE: Style = Style::new().fg(SLATE.c400);
+const TARGET_STYLE: Style = Style::new().add_modifier(Modifier::BOLD);
+const OMITTED_STYLE: Style = Style::new().fg(Color::Yellow);
+
+/// Returns a rectangle of the given percentage size centered in `area`.
+pub fn centered_rect(area: Rect, percent_x: u16, percent_y: u16) -> Rect {
+    let vertical = Layout::vertical([Constraint::Percentage(percent_y)]).flex(Flex::Center);
+    let horizontal = Layout::horizontal([Constraint::Percentage(percent_x)]).flex(Flex::Center);
+
+    let [area] = vertical.areas(area);
+    let [area] = horizontal.areas(area);
+
+    return area;
+}
+
+/// Renders a paragraph inside `block`, first limiting `scroll` to how far
+/// the content can be scrolled.
+fn render_scrolled(paragraph: Paragraph, block: Block, area: Rect, buffer: &mut Buffer, scroll: &mut u16) {
+    let inner = block.inner(area);
+    let lines = u16::try_from(paragraph.line_count(inner.width)).unwrap_or(u16::MAX);
+    *scroll = (*scroll).min(lines.saturating_sub(inner.height));
+
+    Clear.render(area, buffer);
+    paragraph.block(block).scroll((*scroll, 0)).render(area, buffer);
+}
+
+pub struct HelpPopup<'a> {
+    pub sections: Vec<(&'a str, Vec<KeyBinding>)>,
+}
+
+impl Widget for HelpPopup<'_> {
+    fn render(self, area: Rect, buffer: &mut Buffer) {
+        let area = centered_rect(area, 60, 70);
+
+        let key_width = self
+            .sections
+            .iter()
+            .flat_map(|(_, bindings)| bindings.iter())
+            .map(|binding| binding.label().len())
+            .max()
+            .unwrap_or(0);
+
+        let mut lines: Vec<Line> = vec![];
+
+        for (title, bindings) in self.sections {
+            if !lines.is_empty() {
+                lines.push(Line::raw(""));
+            }
+
+            lines.push(Line::styled(title, SECTION_STYLE));
+
+            for binding in bindings {
+                lines.push(Line::from(vec![
+                    Span::styled(format!("  {:>width$}", binding.label(), width = key_width), KEY_STYLE),
+                    Span::raw("  "),
+                    Span::raw(binding.description),
+                ]));
+            }
+        }
+
+        let block = Block::new()
+            .borders(Borders::ALL)
+            .title(Line::raw(" Help ").centered())
+            .title_bottom(Line::raw(" Esc / ? to close ").centered())
+            .bg(SLATE.c950);
+
+        Clear.render(area, buffer);
+        Paragraph::new(lines).block(block).render(area, buffer);
+    }
+}
+
+pub struct DependencyPathsPopup<'a> {
+    pub group_id: &'a str,
+    pub artifact_id: &'a str,
+    pub resolution: &'a ResolutionState,
+}
+
+impl DependencyPathsPopup<'_> {
+    fn path_lines(&self, tree: &DependencyTree) -> Vec<Line<'static>> {
+        let paths = tree.paths_to_artifact(self.group_id, self.artifact_id);
+
+        if paths.is_empty() {
+            return vec![Line::raw("Not part of the dependency tree")];
+        }
+
+        let mut lines: Vec<Line> = vec![];
+
+        for (number, path) in paths.iter().enumerate() {
+            let Some((target, via)) = path.split_last() else {
+                continue;
+            };
+            let target = &tree.nodes[*target];
+
+            if !lines.is_empty() {
+                lines.push(Line::raw(""));
+            }
+
+            let status = match &target.status {
+                NodeStatus::Included => Span::raw(""),
+                NodeStatus::OmittedForConflict { winner } => {
+                    Span::styled(format!(" (omitted for conflict with {})", winner), OMITTED_STYLE)
+                }
+                NodeStatus::OmittedForDuplicate => Span::styled(" (omitted for duplicate)", PATH_STYLE),
+                NodeStatus::Unresolved(error) => Span::styled(format!(" (unresolved: {})", error), OMITTED_STYLE),
+            };
+
+            lines.push(Line::styled(format!("Path {}", number + 1), SECTION_STYLE));
+
+            for (depth, node) in via.iter().enumerate() {
+                let node = &tree.nodes[*node];
+                let mut text = format!("{}{}", "  ".repeat(depth + 1), node.coordinates());
+                if node.status == NodeStatus::OmittedForDuplicate {
+                    text.push_str(" (duplicate, expanded where included)");
+                }
+                lines.push(Line::styled(text, PATH_STYLE));
+            }
+
+            let mut target_line = vec![
+                Span::raw("  ".repeat(via.len() + 1)),
+                Span::styled(target.coordinates(), TARGET_STYLE),
+            ];
+            if !target.scope.is_empty() {
+                target_line.push(Span::styled(format!(" [{}]", target.scope), PATH_STYLE));
+            }
+            target_line.push(status);
+
+            lines.push(Line::from(target_line));
+        }
+
+        return lines;
+    }
+}
+
+impl StatefulWidget for DependencyPathsPopup<'_> {
+    /// How far the paths are scrolled.
+    type State = u16;
+
+    fn render(self, area: Rect, buffer: &mut Buffer, scroll: &mut u16) {
+        let area = centered_rect(area, 70, 70);
+
+        let lines = match self.resolution {
+            ResolutionState::Resolved(tree) => self.path_lines(tree),
+            ResolutionState::Failed(error) => vec![Line::raw(format!("Resolution failed: {}", error))],
+            _ => vec![Line::raw("Resolving dependencies...")],
+        };
+
+        let block = Block::new()
+            .borders(Borders::ALL)
+            .title(Line::raw(format!(" Why is {}:{} here? ", self.group_id, self.artifact_id)).centered())
+            .title_bottom(Line::raw(" j/k to scroll, Esc to close ").centered())
+            .bg(SLATE.c950);
+
+        render_scrolled(Paragraph::new(lines).wrap(Wrap { trim: false }), block, area, buffer, scroll);
+    }
+}
+
+pub struct MessagePopup<'a> {
+    pub title: &'a str,
+    pub text: &'a str,
+}
+
+impl Widget for MessagePopup<'_> {
+    fn render(self, area: Rect, buffer: &mut Buffer) {
+        let area = centered_rect(area, 50, 20);
+
+        let block = Block::new()
+            .borders(Borders::ALL)
+            .title(Line::raw(format!(" {} ", self.title)).centered())
+            .title_bottom(Line::raw(" Esc to close ").centered())
+            .bg(SLATE.c950);
+
+        Clear.render(area, buffer);
+        Paragraph::new(self.text).block(block).wrap(Wrap { trim: true }).render(area, buffer);
+    }
+}
+
+const REMOVED_STYLE: Style = Style::new().fg(Color::Red);
+const CHANGED_STYLE: Style = Style::new().fg(Color::Green);
+const SKIPPED_STYLE: Style = Style::new().fg(SLATE.c500);
+
+pub struct BatchSummaryPopup<'a> {
+    pub operation: &'a BatchOperation,
+    pub changes: &'a [PlannedChange],
+}
+
+impl StatefulWidget for BatchSummaryPopup<'_> {
+    /// How far the planned changes are scrolled.
+    type State = u16;
+
+    fn render(self, area: Rect, buffer: &mut Buffer, scroll: &mut u16) {
+        let area = centered_rect(area, 70, 60);
+
+        let lines: Vec<Line> = self
+            .changes
+            .iter()
+            .map(|planned| {
+                let style = match planned.change {
+                    BatchChange::Remove => REMOVED_STYLE,
+                    BatchChange::SetVersion(_) | BatchChange::SetScope { .. } => CHANGED_STYLE,
+                    BatchChange::Skip(_) | BatchChange::Pending => SKIPPED_STYLE,
+                };
+
+                Line::from(vec![
+                    Span::styled(format!("{}:{}  ", planned.dependency.group_id, planned.dependency.artifact_id), TARGET_STYLE),
+                    Span::styled(planned.description(), style),
+                ])
+            })
+            .collect();
+
+        let pending = self.changes.iter().any(|planned| planned.change == BatchChange::Pending);
+        let footer = match pending {
+            true => " Looking up versions... Esc to cancel ",
+            false => " Enter to apply, j/k to scroll, Esc to cancel ",
+        };
+
+        let block = Block::new()
+            .borders(Borders::ALL)
+            .title(Line::raw(format!(" {} ", self.operation.title())).centered())
+            .title_bottom(Line::raw(footer).centered())
+            .bg(SLATE.c950);
+
+        render_scrolled(Paragraph::new(lines), block, area, buffer, scroll);
+    }
+}
+
+const FOCUSED_LABEL_STYLE: Style = Style::new().fg(Color::Yellow).add_modifier(Modifier::BOLD);
+const PROBLEM_STYLE: Style = Style::new().fg(Color::Red);
+
+/// Published versions listed below the version field.
+const LISTED_VERSIONS: usize = 8;
+
+pub struct DependencyEditorPopup<'a> {
+    pub editor: &'a DependencyEditor,
+    /// Published versions of the artifact, `None` while they are looked up.
+    pub versions: Option<&'a Result<Vec<String>, String>>,
+}
+
+impl DependencyEditorPopup<'_> {
+    fn version_lines(&self) -> Vec<Line<'static>> {
+        let published = match self.versions {
+            None => return vec![Line::styled("Looking up published versions...", PATH_STYLE)],
+            Some(Err(error)) => return vec![Line::styled(format!("Versions could not be checked: {}", error), OMITTED_STYLE)],
+            Some(Ok(published)) => published,
+        };
+
+        let mut sorted: Vec<&String> = published.iter().collect();
+        sorted.sort_by_key(|version| std::cmp::Reverse(MavenVersion::parse(version)));
+
+        let current = self.editor.version.value();
+        let mut spans = vec![Span::styled(format!("{} published: ", published.len()), PATH_STYLE)];
+        for (i, version) in sorted.iter().take(LISTED_VERSIONS).enumerate() {
+            if i > 0 {
+                spans.push(Span::styled(", ", PATH_STYLE));
+            }
+            match **version == current {
+                true => spans.push(Span::styled(version.to_string(), TARGET_STYLE)),
+                false => spans.push(Span::raw(version.to_string())),
+            }
+        }
+        if sorted.len() > LISTED_VERSIONS {
+            spans.push(Span::styled(", ...", PATH_STYLE));
+        }
+
+        return vec![Line::from(spans)];
+    }
+}
+
+impl Widget for DependencyEditorPopup<'_> {
+    fn render(self, area: Rect, buffer: &mut Buffer) {
+        let area = centered_rect(area, 70, 60);
+        let editor = self.editor;
+
+        let block = Block::new()
+            .borders(Borders::ALL)
+            .title(Line::raw(format!(" Edit {} ", editor.original.id())).centered())
+            .title_bottom(Line::raw(" Tab next field, ↑/↓ pick version, ←/→ scope, Enter to apply, Esc to cancel ").centered())
+            .bg(SLATE.c950);
+        let inner = block.inner(area);
+
+        Clear.render(area, buffer);
+        block.render(area, buffer);
+
+        let [fields_area, details_area] =
+            Layout::vertical([Constraint::Length(editor.fields.len() as u16 + 1), Constraint::Min(0)]).areas(inner);
+        let label_width = 14;
+
+        for (i, field) in editor.fields.iter().enumerate() {
+            let row = Rect { y: fields_area.y + i as u16, height: 1, ..fields_area }.intersection(fields_area);
+            let [label_area, value_area] = Layout::horizontal([Constraint::Length(label_width), Constraint::Min(0)]).areas(row);
+            let focused = i == editor.focused;
+
+            let label = format!("{}{:<12}", if focused { "> " } else { "  " }, field.label());
+            Line::styled(label, if focused { FOCUSED_LABEL_STYLE } else { KEY_STYLE }).render(label_area, buffer);
+
+            match field {
+                EditorField::Version => editor.version.render(value_area, buffer, focused),
+                EditorField::Classifier => editor.classifier.render(value_area, buffer, focused),
+                EditorField::Type => editor.type_.render(value_area, buffer, focused),
+                EditorField::Exclusions => editor.exclusions.render(value_area, buffer, focused),
+                EditorField::Scope => Line::raw(format!("◂ {} ▸", editor.scope)).render(value_area, buffer),
+                EditorField::Optional => Line::raw(if editor.optional { "[x]" } else { "[ ]" }).render(value_area, buffer),
+            }
+        }
+
+        let mut lines = self.version_lines();
+        if let Some(note) = editor.note {
+            lines.push(Line::styled(note, PATH_STYLE));
+        }
+
+        let problems = editor.problems(self.versions);
+        if !problems.is_empty() {
+            lines.push(Line::raw(""));
+            lines.extend(problems.into_iter().map(|problem| Line::styled(problem, PROBLEM_STYLE)));
+        }
+
+        Paragraph::new(lines).wrap(Wrap { trim: true }).render(details_area, buffer);
+    }
+}
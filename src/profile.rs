@@ -0,0 +1,339 @@
+use std::{
+    collections::HashMap,
+    fs,
+    path::{Path, PathBuf},
+};
+use xmltree::Element;
+
+use crate::{
+    maven_version::{MavenVersion, VersionRange},
+    pom::{self, PomDependency, child_text},
+};
+
+/// A `<profile>` of the project, with the dependencies it adds.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct Profile {
+    pub id: String,
+    pub activation: Activation,
+    pub dependencies: Vec<PomDependency>,
+}
+
+impl Profile {
+    pub fn from_element(element: &Element) -> Profile {
+        return Profile {
+            id: child_text(element, "id"),
+            activation: element.get_child("activation").map(Activation::from_element).unwrap_or_default(),
+            dependencies: pom::dependencies_of(element),
+        };
+    }
+}
+
+/// Conditions of `<activation>`. Fields that are not declared are empty.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct Activation {
+    pub active_by_default: bool,
+    pub jdk: String,
+    pub os: Option<OsActivation>,
+    pub property: Option<PropertyActivation>,
+    pub file: Option<FileActivation>,
+}
+
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct OsActivation {
+    pub name: String,
+    pub family: String,
+    pub arch: String,
+    pub version: String,
+}
+
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct PropertyActivation {
+    pub name: String,
+    pub value: String,
+}
+
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct FileActivation {
+    pub exists: String,
+    pub missing: String,
+}
+
+impl Activation {
+    fn from_element(element: &Element) -> Activation {
+        return Activation {
+            active_by_default: child_text(element, "activeByDefault") == "true",
+            jdk: child_text(element, "jdk"),
+            os: element.get_child("os").map(|os| OsActivation {
+                name: child_text(os, "name"),
+                family: child_text(os, "family"),
+                arch: child_text(os, "arch"),
+                version: child_text(os, "version"),
+            }),
+            property: element.get_child("property").map(|property| PropertyActivation {
+                name: child_text(property, "name"),
+                value: child_text(property, "value"),
+            }),
+            file: element.get_child("file").map(|file| FileActivation {
+                exists: child_text(file, "exists"),
+                missing: child_text(file, "missing"),
+            }),
+        };
+    }
+
+    /// Whether the declared conditions hold, all of them having to, as in
+    /// Maven 3.2.2 and later. `None` when there are no conditions.
+    fn triggered(&self, context: &ActivationContext) -> Option<bool> {
+        let conditions = [
+            Some(&self.jdk).filter(|jdk| !jdk.is_empty()).map(|jdk| context.matches_jdk(jdk)),
+            self.os.as_ref().map(|os| context.matches_os(os)),
+            self.property.as_ref().map(|property| context.matches_property(property)),
+            self.file.as_ref().map(|file| context.matches_file(file)),
+        ];
+
+        let conditions: Vec<bool> = conditions.into_iter().flatten().collect();
+        if conditions.is_empty() {
+            return None;
+        }
+
+        return Some(conditions.iter().all(|holds| *holds));
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum ProfileStatus {
+    /// The activation conditions hold.
+    Activated,
+    /// Declared `activeByDefault` and no other profile is activated.
+    ActiveByDefault,
+    Inactive,
+}
+
+impl ProfileStatus {
+    pub fn is_active(&self) -> bool {
+        return *self != ProfileStatus::Inactive;
+    }
+
+    pub fn label(&self) -> &'static str {
+        match self {
+            ProfileStatus::Activated => "active",
+            ProfileStatus::ActiveByDefault => "active by default",
+            ProfileStatus::Inactive => "inactive",
+        }
+    }
+}
+
+/// Evaluates the activation of each profile. Profiles active by default are
+/// only used when no other profile of the POM is activated.
+pub fn evaluate(profiles: &[Profile], context: &ActivationContext) -> Vec<ProfileStatus> {
+    let triggered: Vec<bool> = profiles
+        .iter()
+        .map(|profile| profile.activation.triggered(context) == Some(true))
+        .collect();
+    let any_triggered = triggered.iter().any(|triggered| *triggered);
+
+    return profiles
+        .iter()
+        .zip(triggered)
+        .map(|(profile, triggered)| match triggered {
+            true => ProfileStatus::Activated,
+            false if profile.activation.active_by_default && !any_triggered => ProfileStatus::ActiveByDefault,
+            false => ProfileStatus::Inactive,
+        })
+        .collect();
+}
+
+/// The environment profile activation is evaluated against, described the
+/// way Java reports it.
+#[derive(Debug, Clone, Default)]
+pub struct ActivationContext {
+    pub jdk_version: Option<String>,
+    pub os_name: String,
+    pub os_families: Vec<&'static str>,
+    pub os_arch: String,
+    /// Environment variables as `env.NAME`, plus the Java and OS properties known here.
+    pub properties: HashMap<String, String>,
+    /// Directory relative file conditions are resolved against.
+    pub base_dir: PathBuf,
+}
+
+impl ActivationContext {
+    pub fn detect(base_dir: PathBuf) -> ActivationContext {
+        let (os_name, os_families): (&str, Vec<&'static str>) = match std::env::consts::OS {
+            "linux" => ("Linux", vec!["unix"]),
+            "macos" => ("Mac OS X", vec!["mac", "unix"]),
+            "windows" => ("Windows", vec!["windows", "winnt", "dos"]),
+            "freebsd" => ("FreeBSD", vec!["unix"]),
+            other => (other, vec!["unix"]),
+        };
+
+        let os_arch = match std::env::consts::ARCH {
+            "x86_64" => "amd64",
+            "x86" => "x86",
+            other => other,
+        };
+
+        let jdk_version = detect_jdk_version();
+
+        let mut properties: HashMap<String, String> = std::env::vars()
+            .map(|(name, value)| (format!("env.{}", name), value))
+            .collect();
+        properties.insert("os.name".to_string(), os_name.to_string());
+        properties.insert("os.arch".to_string(), os_arch.to_string());
+        if let Some(version) = &jdk_version {
+            properties.insert("java.version".to_string(), version.clone());
+        }
+
+        return ActivationContext {
+            jdk_version,
+            os_name: os_name.to_string(),
+            os_families,
+            os_arch: os_arch.to_string(),
+            properties,
+            base_dir,
+        };
+    }
+
+    /// `<jdk>` is a version prefix like `1.8` or `17`, or a range like
+    /// `[11,)`, and may be negated with `!`.
+    fn matches_jdk(&self, condition: &str) -> bool {
+        let (negated, condition) = negation(condition);
+
+        let Some(jdk) = &self.jdk_version else {
+            return negated;
+        };
+
+        let matches = match condition.starts_with(['[', '(']) {
+            true => VersionRange::parse(condition).is_some_and(|range| range.contains(&MavenVersion::parse(jdk))),
+            false => jdk == condition || jdk.starts_with(&format!("{}.", condition)),
+        };
+
+        return matches != negated;
+    }
+
+    /// The OS version is not known here, so conditions on it never hold.
+    fn matches_os(&self, os: &OsActivation) -> bool {
+        let matches = |condition: &str, actual: &dyn Fn(&str) -> bool| -> bool {
+            if condition.is_empty() {
+                return true;
+            }
+
+            let (negated, condition) = negation(condition);
+            return actual(&condition.to_lowercase()) != negated;
+        };
+
+        return matches(&os.name, &|name| self.os_name.to_lowercase() == name)
+            && matches(&os.family, &|family| self.os_families.contains(&family))
+            && matches(&os.arch, &|arch| self.os_arch == arch)
+            && matches(&os.version, &|_| false);
+    }
+
+    fn matches_property(&self, property: &PropertyActivation) -> bool {
+        let (negated_name, name) = negation(&property.name);
+        let actual = self.properties.get(name).filter(|value| !value.is_empty());
+
+        if property.value.is_empty() {
+            return actual.is_some() != negated_name;
+        }
+
+        let (negated_value, value) = negation(&property.value);
+        return actual.is_some_and(|actual| actual == value) != negated_value;
+    }
+
+    /// `<exists>` wins over `<missing>` when both are declared, as in Maven.
+    fn matches_file(&self, file: &FileActivation) -> bool {
+        let (path, missing) = match (file.exists.is_empty(), file.missing.is_empty()) {
+            (false, _) => (&file.exists, false),
+            (true, false) => (&file.missing, true),
+            (true, true) => return true,
+        };
+
+        let base_dir = self.base_dir.to_string_lossy();
+        let path = path.replace("${project.basedir}", &base_dir).replace("${basedir}", &base_dir);
+
+        return self.base_dir.join(Path::new(&path)).exists() != missing;
+    }
+}
+
+fn negation(condition: &str) -> (bool, &str) {
+    match condition.strip_prefix('!') {
+        Some(condition) => (true, condition.trim()),
+        None => (false, condition.trim()),
+    }
+}
+
+/// Version of the JDK in `JAVA_HOME`, read from its `release` file.
+fn detect_jdk_version() -> Option<String> {
+    let java_home = PathBuf::from(std::env::var_os("JAVA_HOME")?);
+    let release = fs::read_to_string(java_home.join("release")).ok()?;
+
+    return release
+        .lines()
+        .find_map(|line| line.strip_prefix("JAVA_VERSION="))
+        .map(|version| version.trim().trim_matches('"').to_string());
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn context() -> ActivationContext {
+        return ActivationContext {
+            jdk_version: Some("17.0.2".to_string()),
+            os_name: "Linux".to_string(),
+            os_families: vec!["unix"],
+            os_arch: "amd64".to_string(),
+            properties: HashMap::from([("env.CI".to_string(), "true".to_string())]),
+            base_dir: PathBuf::from(env!("CARGO_MANIFEST_DIR")),
+        };
+    }
+
+    fn profile(activation: &str) -> Profile {
+        let xml = format!("<profile><id>test</id><activation>{}</activation></profile>", activation);
+        return Profile::from_element(&Element::parse(xml.as_bytes()).unwrap());
+    }
+
+    fn status(activation: &str) -> ProfileStatus {
+        return evaluate(&[profile(activation)], &context())[0];
+    }
+
+    #[test]
+    fn matches_jdk_prefixes_and_ranges() {
+        assert_eq!(status("<jdk>17</jdk>"), ProfileStatus::Activated);
+        assert_eq!(status("<jdk>1.7</jdk>"), ProfileStatus::Inactive);
+        assert_eq!(status("<jdk>!1.8</jdk>"), ProfileStatus::Activated);
+        assert_eq!(status("<jdk>[11,)</jdk>"), ProfileStatus::Activated);
+        assert_eq!(status("<jdk>[1.8,11)</jdk>"), ProfileStatus::Inactive);
+    }
+
+    #[test]
+    fn matches_properties_os_and_files() {
+        assert_eq!(status("<property><name>env.CI</name></property>"), ProfileStatus::Activated);
+        assert_eq!(status("<property><name>!env.CI</name></property>"), ProfileStatus::Inactive);
+        assert_eq!(status("<property><name>env.CI</name><value>false</value></property>"), ProfileStatus::Inactive);
+        assert_eq!(status("<property><name>release</name><value>!true</value></property>"), ProfileStatus::Activated);
+
+        assert_eq!(status("<os><family>unix</family><arch>amd64</arch></os>"), ProfileStatus::Activated);
+        assert_eq!(status("<os><family>!windows</family></os>"), ProfileStatus::Activated);
+        assert_eq!(status("<os><name>Windows XP</name></os>"), ProfileStatus::Inactive);
+
+        assert_eq!(status("<file><exists>${basedir}/Cargo.toml</exists></file>"), ProfileStatus::Activated);
+        assert_eq!(status("<file><missing>Cargo.toml</missing></file>"), ProfileStatus::Inactive);
+    }
+
+    #[test]
+    fn requires_all_conditions() {
+        assert_eq!(status("<jdk>17</jdk><property><name>env.CI</name></property>"), ProfileStatus::Activated);
+        assert_eq!(status("<jdk>17</jdk><property><name>env.NIGHTLY</name></property>"), ProfileStatus::Inactive);
+    }
+
+    #[test]
+    fn active_by_default_yields_to_activated_profiles() {
+        let by_default = profile("<activeByDefault>true</activeByDefault>");
+
+        assert_eq!(evaluate(std::slice::from_ref(&by_default), &context()), vec![ProfileStatus::ActiveByDefault]);
+        assert_eq!(
+            evaluate(&[by_default, profile("<jdk>17</jdk>")], &context()),
+            vec![ProfileStatus::Inactive, ProfileStatus::Activated],
+        );
+    }
+}
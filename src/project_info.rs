@@ -0,0 +1,244 @@
+/// An editable field of the project's own description.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum ProjectField {
+    GroupId,
+    ArtifactId,
+    Version,
+    Name,
+    Description,
+    Url,
+    LicenseName(usize),
+    LicenseUrl(usize),
+    DeveloperId(usize),
+    DeveloperName(usize),
+    DeveloperEmail(usize),
+    ScmUrl,
+    ScmConnection,
+    ScmDeveloperConnection,
+    ScmTag,
+}
+
+/// Lists of the project description entries can be added to and removed from.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum ProjectList {
+    Licenses,
+    Developers,
+}
+
+impl ProjectList {
+    /// Names of the list element and of its entries.
+    pub fn tags(&self) -> (&'static str, &'static str) {
+        match self {
+            ProjectList::Licenses => ("licenses", "license"),
+            ProjectList::Developers => ("developers", "developer"),
+        }
+    }
+
+    /// Fields shown for the entry at `index`.
+    pub fn fields(&self, index: usize) -> Vec<ProjectField> {
+        match self {
+            ProjectList::Licenses => vec![ProjectField::LicenseName(index), ProjectField::LicenseUrl(index)],
+            ProjectList::Developers => vec![
+                ProjectField::DeveloperId(index),
+                ProjectField::DeveloperName(index),
+                ProjectField::DeveloperEmail(index),
+            ],
+        }
+    }
+}
+
+impl ProjectField {
+    pub fn label(&self) -> String {
+        match self {
+            ProjectField::GroupId => "groupId".to_string(),
+            ProjectField::ArtifactId => "artifactId".to_string(),
+            ProjectField::Version => "version".to_string(),
+            ProjectField::Name => "name".to_string(),
+            ProjectField::Description => "description".to_string(),
+            ProjectField::Url => "url".to_string(),
+            ProjectField::LicenseName(index) => format!("license {} name", index + 1),
+            ProjectField::LicenseUrl(index) => format!("license {} url", index + 1),
+            ProjectField::DeveloperId(index) => format!("developer {} id", index + 1),
+            ProjectField::DeveloperName(index) => format!("developer {} name", index + 1),
+            ProjectField::DeveloperEmail(index) => format!("developer {} email", index + 1),
+            ProjectField::ScmUrl => "scm url".to_string(),
+            ProjectField::ScmConnection => "scm connection".to_string(),
+            ProjectField::ScmDeveloperConnection => "scm developerConnection".to_string(),
+            ProjectField::ScmTag => "scm tag".to_string(),
+        }
+    }
+
+    /// Elements leading to the field below `<project>`, each naming a child
+    /// and which of the children with that name it is.
+    pub fn path(&self) -> Vec<(&'static str, usize)> {
+        match *self {
+            ProjectField::GroupId => vec![("groupId", 0)],
+            ProjectField::ArtifactId => vec![("artifactId", 0)],
+            ProjectField::Version => vec![("version", 0)],
+            ProjectField::Name => vec![("name", 0)],
+            ProjectField::Description => vec![("description", 0)],
+            ProjectField::Url => vec![("url", 0)],
+            ProjectField::LicenseName(index) => vec![("licenses", 0), ("license", index), ("name", 0)],
+            ProjectField::LicenseUrl(index) => vec![("licenses", 0), ("license", index), ("url", 0)],
+            ProjectField::DeveloperId(index) => vec![("developers", 0), ("developer", index), ("id", 0)],
+            ProjectField::DeveloperName(index) => vec![("developers", 0), ("developer", index), ("name", 0)],
+            ProjectField::DeveloperEmail(index) => vec![("developers", 0), ("developer", index), ("email", 0)],
+            ProjectField::ScmUrl => vec![("scm", 0), ("url", 0)],
+            ProjectField::ScmConnection => vec![("scm", 0), ("connection", 0)],
+            ProjectField::ScmDeveloperConnection => vec![("scm", 0), ("developerConnection", 0)],
+            ProjectField::ScmTag => vec![("scm", 0), ("tag", 0)],
+        }
+    }
+
+    /// The list the field belongs to, with the index of its entry.
+    pub fn list(&self) -> Option<(ProjectList, usize)> {
+        match *self {
+            ProjectField::LicenseName(index) | ProjectField::LicenseUrl(index) => Some((ProjectList::Licenses, index)),
+            ProjectField::DeveloperId(index) | ProjectField::DeveloperName(index) | ProjectField::DeveloperEmail(index) => {
+                Some((ProjectList::Developers, index))
+            }
+            _ => None,
+        }
+    }
+
+    /// Checks a value before it is written. `has_parent` allows the group id
+    /// and version to be left out, as they are inherited then.
+    pub fn validate(&self, value: &str, has_parent: bool) -> Result<(), String> {
+        if value.is_empty() {
+            return match self {
+                ProjectField::ArtifactId => Err("is required".to_string()),
+                ProjectField::GroupId | ProjectField::Version if !has_parent => Err("is required".to_string()),
+                _ => Ok(()),
+            };
+        }
+
+        // Values built from properties are only known once interpolated.
+        let interpolated = value.contains("${");
+
+        match self {
+            ProjectField::GroupId | ProjectField::ArtifactId
+                if !value.chars().all(|char| char.is_ascii_alphanumeric() || "_-.".contains(char)) =>
+            {
+                return Err("may only contain letters, digits, '_', '-' and '.'".to_string());
+            }
+            ProjectField::Version if value.chars().any(|char| char.is_whitespace() || "\\/:\"<>|?*".contains(char)) => {
+                return Err("must not contain whitespace or \\/:\"<>|?*".to_string());
+            }
+            ProjectField::Url | ProjectField::LicenseUrl(_) | ProjectField::ScmUrl
+                if !interpolated && !value.starts_with("http://") && !value.starts_with("https://") =>
+            {
+                return Err("must be an http(s) URL".to_string());
+            }
+            ProjectField::ScmConnection | ProjectField::ScmDeveloperConnection
+                if !interpolated && !value.starts_with("scm:") =>
+            {
+                return Err("must start with scm:".to_string());
+            }
+            ProjectField::DeveloperEmail(_) if !value.contains('@') => {
+                return Err("must be an email address".to_string());
+            }
+            _ => return Ok(()),
+        }
+    }
+}
+
+const SNAPSHOT_SUFFIX: &str = "-SNAPSHOT";
+
+/// Ways the project version can be bumped.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Bump {
+    Major,
+    Minor,
+    Patch,
+    /// `1.2.0-SNAPSHOT` becomes `1.2.0`.
+    Release,
+    /// `1.2.0` becomes `1.2.1-SNAPSHOT`, the next development version.
+    Snapshot,
+}
+
+impl Bump {
+    pub fn description(&self) -> &'static str {
+        match self {
+            Bump::Major => "Bump major version",
+            Bump::Minor => "Bump minor version",
+            Bump::Patch => "Bump patch version",
+            Bump::Release => "Release the snapshot version",
+            Bump::Snapshot => "Start the next snapshot version",
+        }
+    }
+}
+
+/// The version following `version`, or `None` when it does not start with a
+/// number or the bump does not apply, like releasing a version that is no
+/// snapshot. Qualifiers such as `-RC1` are dropped, `-SNAPSHOT` is kept.
+pub fn bump_version(version: &str, bump: Bump) -> Option<String> {
+    let (base, snapshot) = match version.strip_suffix(SNAPSHOT_SUFFIX) {
+        Some(base) => (base, true),
+        None => (version, false),
+    };
+
+    let numeric_end = base.find(|char: char| !char.is_ascii_digit() && char != '.').unwrap_or(base.len());
+    let numbers: Vec<u64> = base[..numeric_end]
+        .trim_end_matches('.')
+        .split('.')
+        .map(|number| number.parse().ok())
+        .collect::<Option<Vec<u64>>>()?;
+
+    let (position, snapshot) = match bump {
+        Bump::Major => (0, snapshot),
+        Bump::Minor => (1, snapshot),
+        Bump::Patch => (2, snapshot),
+        Bump::Release if snapshot => return Some(base.to_string()),
+        Bump::Snapshot if !snapshot => (2, true),
+        Bump::Release | Bump::Snapshot => return None,
+    };
+
+    let mut numbers = numbers;
+    numbers.resize(numbers.len().max(position + 1), 0);
+    numbers[position] += 1;
+    for number in numbers.iter_mut().skip(position + 1) {
+        *number = 0;
+    }
+
+    let numbers: Vec<String> = numbers.iter().map(u64::to_string).collect();
+    let suffix = if snapshot { SNAPSHOT_SUFFIX } else { "" };
+
+    return Some(format!("{}{}", numbers.join("."), suffix));
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn bumps_semantic_versions() {
+        assert_eq!(bump_version("1.2.3", Bump::Major).as_deref(), Some("2.0.0"));
+        assert_eq!(bump_version("1.2.3", Bump::Minor).as_deref(), Some("1.3.0"));
+        assert_eq!(bump_version("1.2.3", Bump::Patch).as_deref(), Some("1.2.4"));
+        assert_eq!(bump_version("1.2", Bump::Patch).as_deref(), Some("1.2.1"));
+        assert_eq!(bump_version("1.2", Bump::Minor).as_deref(), Some("1.3"));
+        assert_eq!(bump_version("2.0.0-RC1", Bump::Patch).as_deref(), Some("2.0.1"));
+        assert_eq!(bump_version("${revision}", Bump::Patch), None);
+    }
+
+    #[test]
+    fn moves_between_snapshots_and_releases() {
+        assert_eq!(bump_version("0.0.1-SNAPSHOT", Bump::Minor).as_deref(), Some("0.1.0-SNAPSHOT"));
+        assert_eq!(bump_version("0.0.1-SNAPSHOT", Bump::Release).as_deref(), Some("0.0.1"));
+        assert_eq!(bump_version("0.0.1", Bump::Snapshot).as_deref(), Some("0.0.2-SNAPSHOT"));
+        assert_eq!(bump_version("0.0.1", Bump::Release), None);
+        assert_eq!(bump_version("0.0.1-SNAPSHOT", Bump::Snapshot), None);
+    }
+
+    #[test]
+    fn validates_fields() {
+        assert!(ProjectField::GroupId.validate("com.example", false).is_ok());
+        assert!(ProjectField::GroupId.validate("", true).is_ok());
+        assert!(ProjectField::GroupId.validate("", false).is_err());
+        assert!(ProjectField::ArtifactId.validate("my app", true).is_err());
+        assert!(ProjectField::Version.validate("1.0/2", false).is_err());
+        assert!(ProjectField::Url.validate("example.com", false).is_err());
+        assert!(ProjectField::ScmConnection.validate("scm:git:https://example.com/repo.git", false).is_ok());
+        assert!(ProjectField::DeveloperEmail(0).validate("jane.example.com", false).is_err());
+    }
+}
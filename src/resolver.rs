@@ -0,0 +1,1033 @@
+use anyhow::{Result, anyhow, bail};
+use std::{
+    collections::{HashMap, HashSet, VecDeque},
+    fs,
+    future::Future,
+    path::{Path, PathBuf},
+    pin::Pin,
+};
+
+use crate::{
+    maven_registry::MavenRegistry,
+    maven_version::VersionRange,
+    pom::{Exclusion, License, Parent, Pom, PomDependency},
+};
+
+/// Parent chains deeper than this are assumed to be cyclic.
+pub const MAX_PARENT_DEPTH: usize = 20;
+
+/// Paths to an artifact listed at most, since following duplicates multiplies them.
+pub const MAX_ARTIFACT_PATHS: usize = 200;
+
+/// Loads POMs from the local repository (`~/.m2/repository`), falling back to Maven Central.
+pub struct PomSource {
+    local_repository: Option<PathBuf>,
+    cache: HashMap<String, Pom>,
+}
+
+impl PomSource {
+    pub fn new() -> Self {
+        let local_repository = std::env::var_os("HOME")
+            .map(|home| PathBuf::from(home).join(".m2").join("repository"));
+
+        return Self {
+            local_repository,
+            cache: HashMap::new(),
+        };
+    }
+
+    pub async fn load(&mut self, group_id: &str, artifact_id: &str, version: &str) -> Result<Pom> {
+        let coordinates = format!("{}:{}:{}", group_id, artifact_id, version);
+
+        if let Some(pom) = self.cache.get(&coordinates) {
+            return Ok(pom.clone());
+        }
+
+        let content = self.load_content(group_id, artifact_id, version).await?;
+        let pom = Pom::parse(&content)?;
+        self.cache.insert(coordinates, pom.clone());
+
+        return Ok(pom);
+    }
+
+    /// Text of a POM, read from the local repository or fetched from Maven Central.
+    pub async fn load_content(&self, group_id: &str, artifact_id: &str, version: &str) -> Result<String> {
+        if let Some(content) = self.read_local(group_id, artifact_id, version) {
+            return Ok(content);
+        }
+
+        return MavenRegistry::fetch_pom(group_id.to_string(), artifact_id.to_string(), version.to_string())
+            .await
+            .map_err(|e| anyhow!("cannot fetch {}:{}:{}: {}", group_id, artifact_id, version, e));
+    }
+
+    /// Text of the parent found through `<relativePath>` next to a POM on
+    /// disk, with its directory. `None` when the file there is another project.
+    pub fn read_relative_parent(parent: &Parent, base_dir: Option<&Path>) -> Option<(String, Option<PathBuf>)> {
+        let relative_path = parent.relative_path.as_deref().unwrap_or("../pom.xml");
+        if relative_path.is_empty() {
+            return None;
+        }
+
+        let mut path = base_dir?.join(relative_path);
+        if path.is_dir() {
+            path = path.join("pom.xml");
+        }
+
+        let content = fs::read_to_string(&path).ok()?;
+        let pom = Pom::parse(&content).ok()?;
+        if pom.artifact_id != parent.artifact_id || pom.group_id() != parent.group_id {
+            return None;
+        }
+
+        return Some((content, path.parent().map(Path::to_path_buf)));
+    }
+
+    fn read_local(&self, group_id: &str, artifact_id: &str, version: &str) -> Option<String> {
+        let path = self
+            .local_repository
+            .as_ref()?
+            .join(group_id.replace('.', "/"))
+            .join(artifact_id)
+            .join(version)
+            .join(format!("{}-{}.pom", artifact_id, version));
+
+        return fs::read_to_string(path).ok();
+    }
+}
+
+/// A POM after inheritance, BOM imports and property interpolation were applied.
+#[derive(Debug, Clone, Default)]
+pub struct EffectivePom {
+    pub group_id: String,
+    pub artifact_id: String,
+    pub version: String,
+    pub properties: HashMap<String, String>,
+    /// Declared licenses, inherited from the parent when the POM has none.
+    pub licenses: Vec<License>,
+    pub dependency_management: Vec<PomDependency>,
+    pub dependencies: Vec<PomDependency>,
+}
+
+/// Builds effective POMs, caching them by coordinates.
+pub struct ModelBuilder {
+    source: PomSource,
+    cache: HashMap<String, EffectivePom>,
+    /// Coordinates of the POMs being built, to detect BOMs importing each other.
+    in_progress: HashSet<String>,
+}
+
+impl ModelBuilder {
+    pub fn new() -> Self {
+        return Self {
+            source: PomSource::new(),
+            cache: HashMap::new(),
+            in_progress: HashSet::new(),
+        };
+    }
+
+    pub async fn load_effective(&mut self, group_id: &str, artifact_id: &str, version: &str) -> Result<EffectivePom> {
+        let coordinates = format!("{}:{}:{}", group_id, artifact_id, version);
+
+        if let Some(model) = self.cache.get(&coordinates) {
+            return Ok(model.clone());
+        }
+
+        if !self.in_progress.insert(coordinates.clone()) {
+            bail!("{} imports itself through its BOMs", coordinates);
+        }
+
+        let model = match self.source.load(group_id, artifact_id, version).await {
+            Ok(pom) => self.build(pom, None, 0).await,
+            Err(e) => Err(e),
+        };
+        self.in_progress.remove(&coordinates);
+
+        let model = model?;
+        self.cache.insert(coordinates, model.clone());
+
+        return Ok(model);
+    }
+
+    /// Builds the effective model of `pom`. `base_dir` is the directory of a POM
+    /// read from disk, used to find parents through `<relativePath>`.
+    pub fn build<'a>(
+        &'a mut self,
+        pom: Pom,
+        base_dir: Option<PathBuf>,
+        depth: usize,
+    ) -> Pin<Box<dyn Future<Output = Result<EffectivePom>> + Send + 'a>> {
+        return Box::pin(async move {
+            if depth > MAX_PARENT_DEPTH {
+                bail!("parent chain of {}:{} is too deep", pom.group_id(), pom.artifact_id);
+            }
+
+            let parent = match &pom.parent {
+                Some(parent) => {
+                    let (parent_pom, parent_dir) = self.load_parent(parent, base_dir.as_deref()).await?;
+                    Some(self.build(parent_pom, parent_dir, depth + 1).await?)
+                }
+                None => None,
+            };
+
+            let mut properties = parent
+                .as_ref()
+                .map(|parent| parent.properties.clone())
+                .unwrap_or_default();
+            properties.extend(pom.properties.iter().cloned());
+            properties.extend(project_properties(&pom));
+
+            let mut dependency_management: Vec<PomDependency> = pom
+                .dependency_management
+                .iter()
+                .map(|dependency| interpolate_dependency(dependency, &properties))
+                .collect();
+
+            if let Some(parent) = &parent {
+                merge_management(&mut dependency_management, &parent.dependency_management);
+            }
+
+            let imports: Vec<PomDependency> = dependency_management
+                .iter()
+                .filter(|dependency| dependency.is_bom_import())
+                .cloned()
+                .collect();
+            dependency_management.retain(|dependency| !dependency.is_bom_import());
+
+            for import in imports {
+                let bom = self
+                    .load_effective(&import.group_id, &import.artifact_id, &import.version)
+                    .await?;
+                merge_management(&mut dependency_management, &bom.dependency_management);
+            }
+
+            let mut dependencies = parent
+                .as_ref()
+                .map(|parent| parent.dependencies.clone())
+                .unwrap_or_default();
+
+            for dependency in &pom.dependencies {
+                let dependency = interpolate_dependency(dependency, &properties);
+                let key = dependency.management_key();
+
+                match dependencies.iter_mut().find(|existing| existing.management_key() == key) {
+                    Some(existing) => *existing = dependency,
+                    None => dependencies.push(dependency),
+                }
+            }
+
+            for dependency in dependencies.iter_mut() {
+                apply_management(dependency, &dependency_management);
+            }
+
+            let licenses = match (pom.licenses.is_empty(), &parent) {
+                (true, Some(parent)) => parent.licenses.clone(),
+                _ => pom.licenses.clone(),
+            };
+
+            return Ok(EffectivePom {
+                group_id: pom.group_id().to_string(),
+                artifact_id: pom.artifact_id.clone(),
+                version: pom.version().to_string(),
+                properties,
+                licenses,
+                dependency_management,
+                dependencies,
+            });
+        });
+    }
+
+    /// Properties the POM inherits from its parent chain, empty without a parent.
+    pub async fn parent_properties(&mut self, pom: &Pom, base_dir: Option<PathBuf>) -> Result<HashMap<String, String>> {
+        let Some(parent) = &pom.parent else {
+            return Ok(HashMap::new());
+        };
+
+        let (parent_pom, parent_dir) = self.load_parent(parent, base_dir.as_deref()).await?;
+        let model = self.build(parent_pom, parent_dir, 1).await?;
+
+        return Ok(model.properties);
+    }
+
+    /// Loads the parent next to the POM on disk when its coordinates match,
+    /// otherwise from the repository.
+    async fn load_parent(&mut self, parent: &Parent, base_dir: Option<&Path>) -> Result<(Pom, Option<PathBuf>)> {
+        if let Some((content, parent_dir)) = PomSource::read_relative_parent(parent, base_dir) {
+            return Ok((Pom::parse(&content)?, parent_dir));
+        }
+
+        let pom = self
+            .source
+            .load(&parent.group_id, &parent.artifact_id, &parent.version)
+            .await?;
+
+        return Ok((pom, None));
+    }
+}
+
+/// Built-in `project.*` properties available for interpolation.
+fn project_properties(pom: &Pom) -> Vec<(String, String)> {
+    let mut properties = vec![];
+
+    for prefix in ["project", "pom"] {
+        properties.push((format!("{}.groupId", prefix), pom.group_id().to_string()));
+        properties.push((format!("{}.artifactId", prefix), pom.artifact_id.clone()));
+        properties.push((format!("{}.version", prefix), pom.version().to_string()));
+
+        if let Some(parent) = &pom.parent {
+            properties.push((format!("{}.parent.groupId", prefix), parent.group_id.clone()));
+            properties.push((format!("{}.parent.artifactId", prefix), parent.artifact_id.clone()));
+            properties.push((format!("{}.parent.version", prefix), parent.version.clone()));
+        }
+    }
+
+    return properties;
+}
+
+/// Replaces `${name}` references, following nested references a few levels deep.
+/// Unknown properties are left as they are.
+pub fn interpolate(value: &str, properties: &HashMap<String, String>) -> String {
+    let mut result = value.to_string();
+
+    for _ in 0..10 {
+        if !result.contains("${") {
+            break;
+        }
+
+        let mut next = String::with_capacity(result.len());
+        let mut rest = result.as_str();
+
+        while let Some(start) = rest.find("${") {
+            let Some(end) = rest[start..].find('}') else {
+                break;
+            };
+
+            let name = &rest[start + 2..start + end];
+            next.push_str(&rest[..start]);
+
+            match properties.get(name) {
+                Some(value) => next.push_str(value),
+                None => next.push_str(&rest[start..start + end + 1]),
+            }
+
+            rest = &rest[start + end + 1..];
+        }
+
+        next.push_str(rest);
+
+        if next == result {
+            break;
+        }
+        result = next;
+    }
+
+    return result;
+}
+
+fn interpolate_dependency(dependency: &PomDependency, properties: &HashMap<String, String>) -> PomDependency {
+    let mut dependency = dependency.clone();
+
+    dependency.group_id = interpolate(&dependency.group_id, properties);
+    dependency.artifact_id = interpolate(&dependency.artifact_id, properties);
+    dependency.version = interpolate(&dependency.version, properties);
+    dependency.scope = interpolate(&dependency.scope, properties);
+    dependency.classifier = interpolate(&dependency.classifier, properties);
+    dependency.type_ = interpolate(&dependency.type_, properties);
+
+    return dependency;
+}
+
+/// Adds entries that are not managed yet; earlier entries take precedence.
+fn merge_management(management: &mut Vec<PomDependency>, inherited: &[PomDependency]) {
+    for entry in inherited {
+        let key = entry.management_key();
+
+        if !management.iter().any(|existing| existing.management_key() == key) {
+            management.push(entry.clone());
+        }
+    }
+}
+
+/// Fills in version and scope of a declared dependency from `<dependencyManagement>`.
+fn apply_management(dependency: &mut PomDependency, management: &[PomDependency]) {
+    let key = dependency.management_key();
+
+    let Some(managed) = management.iter().find(|entry| entry.management_key() == key) else {
+        return;
+    };
+
+    if dependency.version.is_empty() {
+        dependency.version = managed.version.clone();
+    }
+    if dependency.scope.is_empty() {
+        dependency.scope = managed.scope.clone();
+    }
+    if dependency.exclusions.is_empty() {
+        dependency.exclusions = managed.exclusions.clone();
+    }
+}
+
+/// Scope of a transitive dependency given the scope it was reached through,
+/// or `None` when it does not end up on the classpath at all.
+fn transitive_scope(parent_scope: &str, scope: &str) -> Option<String> {
+    let scope = match scope {
+        "" | "compile" => parent_scope,
+        "runtime" if parent_scope == "compile" => "runtime",
+        "runtime" => parent_scope,
+        _ => return None,
+    };
+
+    return Some(scope.to_string());
+}
+
+/// Version ranges cannot be resolved without repository metadata, so the
+/// bound most likely to exist is used: an inclusive upper bound, otherwise the lower one.
+fn pick_version(version: &str) -> String {
+    let Some(range) = VersionRange::parse(version).filter(|_| version.starts_with(['[', '('])) else {
+        return version.to_string();
+    };
+
+    let Some(restriction) = range.restrictions.last() else {
+        return version.to_string();
+    };
+
+    let picked = match (&restriction.upper, &restriction.lower) {
+        (Some(upper), _) if restriction.upper_inclusive => upper,
+        (_, Some(lower)) => lower,
+        _ => return version.to_string(),
+    };
+
+    return picked.as_str().to_string();
+}
+
+/// Progress of resolving the project's dependency graph.
+#[derive(Debug, Default)]
+pub enum ResolutionState {
+    #[default]
+    NotStarted,
+    Resolving,
+    Resolved(DependencyTree),
+    Failed(String),
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum NodeStatus {
+    Included,
+    /// Another version of the same artifact is nearer to the root.
+    OmittedForConflict { winner: String },
+    /// The same version of the artifact is already included elsewhere.
+    OmittedForDuplicate,
+    Unresolved(String),
+}
+
+#[derive(Debug, Clone)]
+pub struct DependencyNode {
+    pub group_id: String,
+    pub artifact_id: String,
+    pub version: String,
+    /// Version asked for by the declaring POM before the project's
+    /// `<dependencyManagement>` was applied.
+    pub requested_version: String,
+    pub scope: String,
+    pub optional: bool,
+    pub depth: usize,
+    pub parent: Option<usize>,
+    pub children: Vec<usize>,
+    pub status: NodeStatus,
+}
+
+impl DependencyNode {
+    pub fn id(&self) -> String {
+        return format!("{}:{}", self.group_id, self.artifact_id);
+    }
+
+    pub fn coordinates(&self) -> String {
+        return format!("{}:{}:{}", self.group_id, self.artifact_id, self.version);
+    }
+
+    pub fn is_managed(&self) -> bool {
+        return !self.requested_version.is_empty() && self.requested_version != self.version;
+    }
+}
+
+/// Resolved dependency graph of the project. The first node is the project itself.
+#[derive(Debug, Clone, Default)]
+pub struct DependencyTree {
+    pub nodes: Vec<DependencyNode>,
+    /// Versions that lost mediation, sorted, keyed by `groupId:artifactId`.
+    overridden: HashMap<String, Vec<String>>,
+}
+
+/// A version of a conflicting artifact and the paths through which it was requested.
+#[derive(Debug, Clone)]
+pub struct ConflictVersion {
+    pub version: String,
+    pub paths: Vec<Vec<usize>>,
+}
+
+/// An artifact reached through several paths asking for different versions.
+#[derive(Debug, Clone)]
+pub struct Conflict {
+    pub group_id: String,
+    pub artifact_id: String,
+    pub winner: String,
+    pub versions: Vec<ConflictVersion>,
+}
+
+impl DependencyTree {
+    pub fn new(nodes: Vec<DependencyNode>) -> Self {
+        let mut overridden: HashMap<String, Vec<String>> = HashMap::new();
+
+        for node in nodes.iter().filter(|node| matches!(node.status, NodeStatus::OmittedForConflict { .. })) {
+            overridden.entry(node.id()).or_default().push(node.version.clone());
+        }
+        for versions in overridden.values_mut() {
+            versions.sort();
+            versions.dedup();
+        }
+
+        return Self { nodes, overridden };
+    }
+
+    /// Node indices from the project down to the given node.
+    pub fn path_to(&self, index: usize) -> Vec<usize> {
+        let mut path = vec![index];
+        let mut current = index;
+
+        while let Some(parent) = self.nodes[current].parent {
+            path.push(parent);
+            current = parent;
+        }
+
+        path.reverse();
+
+        return path;
+    }
+
+    /// Paths from the project to every occurrence of an artifact, including
+    /// the ones omitted during mediation. Dependencies omitted as duplicates
+    /// are not expanded in the tree, so paths through them continue with the
+    /// subtree of the included occurrence.
+    pub fn paths_to_artifact(&self, group_id: &str, artifact_id: &str) -> Vec<Vec<usize>> {
+        let mut paths: Vec<Vec<usize>> = self
+            .nodes
+            .iter()
+            .enumerate()
+            .skip(1)
+            .filter(|(_, node)| node.group_id == group_id && node.artifact_id == artifact_id)
+            .map(|(index, _)| self.path_to(index))
+            .collect();
+
+        let mut next = 0;
+        while next < paths.len() && paths.len() < MAX_ARTIFACT_PATHS {
+            let path = paths[next].clone();
+            next += 1;
+
+            for (position, index) in path.iter().enumerate().skip(1) {
+                for duplicate in self.duplicates_of(*index) {
+                    let mut expanded = self.path_to(duplicate);
+                    expanded.extend_from_slice(&path[position + 1..]);
+
+                    if self.is_cyclic(&expanded) || paths.contains(&expanded) {
+                        continue;
+                    }
+                    paths.push(expanded);
+                }
+            }
+        }
+
+        paths.truncate(MAX_ARTIFACT_PATHS);
+
+        return paths;
+    }
+
+    /// Nodes omitted because the given included node has the same artifact and version.
+    fn duplicates_of(&self, index: usize) -> Vec<usize> {
+        let node = &self.nodes[index];
+        if node.status != NodeStatus::Included {
+            return vec![];
+        }
+
+        return self
+            .nodes
+            .iter()
+            .enumerate()
+            .filter(|(_, other)| {
+                other.status == NodeStatus::OmittedForDuplicate && other.id() == node.id() && other.version == node.version
+            })
+            .map(|(index, _)| index)
+            .collect();
+    }
+
+    /// Whether an artifact occurs twice on a path, as with dependencies that
+    /// depend on each other.
+    fn is_cyclic(&self, path: &[usize]) -> bool {
+        let mut seen = HashSet::new();
+        return !path.iter().all(|index| seen.insert(self.nodes[*index].id()));
+    }
+
+    /// Version of an artifact that won mediation, if it is part of the tree.
+    pub fn resolved_version(&self, group_id: &str, artifact_id: &str) -> Option<&str> {
+        return self
+            .nodes
+            .iter()
+            .skip(1)
+            .find(|node| {
+                node.status == NodeStatus::Included && node.group_id == group_id && node.artifact_id == artifact_id
+            })
+            .map(|node| node.version.as_str());
+    }
+
+    /// Every artifact requested in more than one version, winners first.
+    pub fn conflicts(&self) -> Vec<Conflict> {
+        let mut conflicts: Vec<Conflict> = vec![];
+
+        for (index, node) in self.nodes.iter().enumerate().skip(1) {
+            if matches!(node.status, NodeStatus::Unresolved(_)) {
+                continue;
+            }
+
+            let position = conflicts
+                .iter()
+                .position(|conflict| conflict.group_id == node.group_id && conflict.artifact_id == node.artifact_id);
+
+            let conflict = match position {
+                Some(position) => &mut conflicts[position],
+                None => {
+                    conflicts.push(Conflict {
+                        group_id: node.group_id.clone(),
+                        artifact_id: node.artifact_id.clone(),
+                        winner: String::new(),
+                        versions: vec![],
+                    });
+                    conflicts.last_mut().unwrap()
+                }
+            };
+
+            if node.status == NodeStatus::Included {
+                conflict.winner = node.version.clone();
+            }
+
+            let path = self.path_to(index);
+            match conflict.versions.iter_mut().find(|version| version.version == node.version) {
+                Some(version) => version.paths.push(path),
+                None => conflict.versions.push(ConflictVersion {
+                    version: node.version.clone(),
+                    paths: vec![path],
+                }),
+            }
+        }
+
+        conflicts.retain(|conflict| conflict.versions.len() > 1);
+
+        for conflict in conflicts.iter_mut() {
+            let winner = conflict.winner.clone();
+            conflict.versions.sort_by_key(|version| version.version != winner);
+        }
+
+        return conflicts;
+    }
+
+    /// Versions of the same artifact that lost mediation against the given node.
+    pub fn overridden_versions(&self, index: usize) -> &[String] {
+        return self.overridden.get(&self.nodes[index].id()).map(Vec::as_slice).unwrap_or_default();
+    }
+}
+
+/// Resolves the transitive dependency graph following Maven's rules: scopes
+/// are narrowed along the path, optional and excluded dependencies are
+/// dropped and the nearest declaration of an artifact wins.
+pub struct Resolver {
+    builder: ModelBuilder,
+}
+
+impl Resolver {
+    pub fn new() -> Self {
+        return Self {
+            builder: ModelBuilder::new(),
+        };
+    }
+
+    pub async fn resolve(&mut self, root: Pom, base_dir: Option<PathBuf>) -> Result<DependencyTree> {
+        let root_model = self.builder.build(root, base_dir, 0).await?;
+
+        let mut management: HashMap<String, PomDependency> = HashMap::new();
+        for entry in &root_model.dependency_management {
+            management.entry(entry.id()).or_insert_with(|| entry.clone());
+        }
+
+        let mut nodes = vec![DependencyNode {
+            group_id: root_model.group_id.clone(),
+            artifact_id: root_model.artifact_id.clone(),
+            version: root_model.version.clone(),
+            requested_version: root_model.version.clone(),
+            scope: String::new(),
+            optional: false,
+            depth: 0,
+            parent: None,
+            children: vec![],
+            status: NodeStatus::Included,
+        }];
+
+        let mut winners: HashMap<String, String> = HashMap::new();
+        let mut queue: VecDeque<(usize, Vec<PomDependency>, Vec<Exclusion>)> =
+            VecDeque::from([(0, root_model.dependencies.clone(), vec![])]);
+
+        while let Some((parent_index, dependencies, exclusions)) = queue.pop_front() {
+            let parent_depth = nodes[parent_index].depth;
+            let parent_scope = nodes[parent_index].scope.clone();
+
+            for dependency in dependencies {
+                let is_transitive = parent_depth > 0;
+
+                if is_transitive
+                    && (dependency.optional
+                        || exclusions.iter().any(|exclusion| exclusion.matches(&dependency.group_id, &dependency.artifact_id)))
+                {
+                    continue;
+                }
+
+                let mut scope = match is_transitive {
+                    true => match transitive_scope(&parent_scope, &dependency.scope) {
+                        Some(scope) => scope,
+                        None => continue,
+                    },
+                    false if dependency.scope.is_empty() => "compile".to_string(),
+                    false => dependency.scope.clone(),
+                };
+
+                let mut version = dependency.version.clone();
+
+                if is_transitive && let Some(managed) = management.get(&dependency.id()) {
+                    if !managed.version.is_empty() {
+                        version = managed.version.clone();
+                    }
+                    if !managed.scope.is_empty() {
+                        scope = managed.scope.clone();
+                    }
+                }
+
+                let version = pick_version(&version);
+                let id = dependency.id();
+
+                let status = match winners.get(&id) {
+                    Some(winner) if *winner == version => NodeStatus::OmittedForDuplicate,
+                    Some(winner) => NodeStatus::OmittedForConflict { winner: winner.clone() },
+                    None if version.is_empty() => NodeStatus::Unresolved("no version declared or managed".to_string()),
+                    None => NodeStatus::Included,
+                };
+
+                let node_index = nodes.len();
+                nodes[parent_index].children.push(node_index);
+                nodes.push(DependencyNode {
+                    group_id: dependency.group_id.clone(),
+                    artifact_id: dependency.artifact_id.clone(),
+                    version: version.clone(),
+                    requested_version: dependency.version.clone(),
+                    scope: scope.clone(),
+                    optional: dependency.optional,
+                    depth: parent_depth + 1,
+                    parent: Some(parent_index),
+                    children: vec![],
+                    status: status.clone(),
+                });
+
+                if status != NodeStatus::Included {
+                    continue;
+                }
+
+                winners.insert(id, version.clone());
+
+                // System dependencies point at local files and have no POM to follow.
+                if scope == "system" {
+                    continue;
+                }
+
+                match self
+                    .builder
+                    .load_effective(&dependency.group_id, &dependency.artifact_id, &version)
+                    .await
+                {
+                    Ok(model) => {
+                        let mut child_exclusions = exclusions.clone();
+                        child_exclusions.extend(dependency.exclusions.iter().cloned());
+                        queue.push_back((node_index, model.dependencies, child_exclusions));
+                    }
+                    Err(e) => nodes[node_index].status = NodeStatus::Unresolved(e.to_string()),
+                }
+            }
+        }
+
+        return Ok(DependencyTree::new(nodes));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// A POM with the given coordinates and the rest of its content.
+    fn pom(coordinates: &str, content: &str) -> String {
+        let parts: Vec<&str> = coordinates.split(':').collect();
+
+        return format!(
+            "<project><groupId>{}</groupId><artifactId>{}</artifactId><version>{}</version>{}</project>",
+            parts[0], parts[1], parts[2], content,
+        );
+    }
+
+    /// A `<dependency>` element with optional extra children like `<scope>`.
+    fn dependency(coordinates: &str, extra: &str) -> String {
+        let parts: Vec<&str> = coordinates.split(':').collect();
+        let version = parts.get(2).map(|version| format!("<version>{}</version>", version)).unwrap_or_default();
+
+        return format!(
+            "<dependency><groupId>{}</groupId><artifactId>{}</artifactId>{}{}</dependency>",
+            parts[0], parts[1], version, extra,
+        );
+    }
+
+    fn dependencies(entries: &[String]) -> String {
+        return format!("<dependencies>{}</dependencies>", entries.concat());
+    }
+
+    /// A builder reading POMs from memory only, keyed by coordinates.
+    fn builder(poms: &[(&str, String)]) -> ModelBuilder {
+        let cache = poms
+            .iter()
+            .map(|(coordinates, content)| (coordinates.to_string(), Pom::parse(content).unwrap()))
+            .collect();
+
+        return ModelBuilder {
+            source: PomSource { local_repository: None, cache },
+            cache: HashMap::new(),
+            in_progress: HashSet::new(),
+        };
+    }
+
+    fn node<'a>(tree: &'a DependencyTree, artifact_id: &str, version: &str) -> &'a DependencyNode {
+        return tree
+            .nodes
+            .iter()
+            .find(|node| node.artifact_id == artifact_id && node.version == version)
+            .unwrap_or_else(|| panic!("{}:{} is not in the tree", artifact_id, version));
+    }
+
+    #[test]
+    fn interpolates_nested_and_unknown_properties() {
+        let properties: HashMap<String, String> = [("base", "1.2"), ("lib.version", "${base}.3"), ("loop", "${loop}")]
+            .iter()
+            .map(|(name, value)| (name.to_string(), value.to_string()))
+            .collect();
+
+        assert_eq!(interpolate("${lib.version}-${base}", &properties), "1.2.3-1.2");
+        assert_eq!(interpolate("${missing}/${base}", &properties), "${missing}/1.2");
+        assert_eq!(interpolate("${loop}", &properties), "${loop}");
+        assert_eq!(interpolate("${base", &properties), "${base");
+    }
+
+    #[test]
+    fn narrows_scopes_along_the_path() {
+        assert_eq!(transitive_scope("compile", "").as_deref(), Some("compile"));
+        assert_eq!(transitive_scope("compile", "runtime").as_deref(), Some("runtime"));
+        assert_eq!(transitive_scope("runtime", "compile").as_deref(), Some("runtime"));
+        assert_eq!(transitive_scope("test", "runtime").as_deref(), Some("test"));
+        assert_eq!(transitive_scope("provided", "compile").as_deref(), Some("provided"));
+        assert_eq!(transitive_scope("compile", "test"), None);
+        assert_eq!(transitive_scope("compile", "provided"), None);
+        assert_eq!(transitive_scope("compile", "system"), None);
+    }
+
+    #[test]
+    fn picks_a_bound_of_version_ranges() {
+        assert_eq!(pick_version("1.0"), "1.0");
+        assert_eq!(pick_version(""), "");
+        assert_eq!(pick_version("[1.0,2.0]"), "2.0");
+        assert_eq!(pick_version("[1.0,2.0)"), "1.0");
+        assert_eq!(pick_version("[1.5,)"), "1.5");
+        assert_eq!(pick_version("(,1.0],[1.2,)"), "1.2");
+    }
+
+    #[tokio::test]
+    async fn nearest_declaration_wins_and_dropped_dependencies_are_left_out() {
+        let project = pom(
+            "org.example:app:1.0",
+            &dependencies(&[
+                dependency("org.example:a:1.0", ""),
+                dependency("org.example:b:1.0", ""),
+                dependency("org.example:c:1.0", "<scope>test</scope>"),
+                dependency(
+                    "org.example:d:1.0",
+                    "<exclusions><exclusion><groupId>org.x</groupId><artifactId>x</artifactId></exclusion></exclusions>",
+                ),
+            ]),
+        );
+        let poms = [
+            (
+                "org.example:a:1.0",
+                pom(
+                    "org.example:a:1.0",
+                    &dependencies(&[
+                        dependency("org.example:shared:2.0", "<scope>runtime</scope>"),
+                        dependency("org.example:extra:1.0", "<optional>true</optional>"),
+                    ]),
+                ),
+            ),
+            ("org.example:b:1.0", pom("org.example:b:1.0", &dependencies(&[dependency("org.example:mid:1.0", "")]))),
+            (
+                "org.example:mid:1.0",
+                pom(
+                    "org.example:mid:1.0",
+                    &dependencies(&[dependency("org.example:shared:1.0", ""), dependency("org.example:a:1.0", "")]),
+                ),
+            ),
+            (
+                "org.example:c:1.0",
+                pom(
+                    "org.example:c:1.0",
+                    &dependencies(&[
+                        dependency("org.example:e:1.0", ""),
+                        dependency("org.example:servlet:1.0", "<scope>provided</scope>"),
+                    ]),
+                ),
+            ),
+            (
+                "org.example:d:1.0",
+                pom(
+                    "org.example:d:1.0",
+                    &dependencies(&[dependency("org.x:x:1.0", ""), dependency("org.example:f:[1.0,2.0]", "")]),
+                ),
+            ),
+            ("org.example:shared:2.0", pom("org.example:shared:2.0", "")),
+            ("org.example:e:1.0", pom("org.example:e:1.0", "")),
+            ("org.example:f:2.0", pom("org.example:f:2.0", "")),
+        ];
+
+        let mut resolver = Resolver { builder: builder(&poms) };
+        let tree = resolver.resolve(Pom::parse(&project).unwrap(), None).await.unwrap();
+
+        assert_eq!(node(&tree, "shared", "2.0").status, NodeStatus::Included);
+        assert_eq!(node(&tree, "shared", "2.0").scope, "runtime");
+        assert_eq!(node(&tree, "shared", "1.0").status, NodeStatus::OmittedForConflict { winner: "2.0".to_string() });
+        assert_eq!(node(&tree, "a", "1.0").status, NodeStatus::Included);
+        assert_eq!(tree.paths_to_artifact("org.example", "a").len(), 2);
+        assert_eq!(tree.paths_to_artifact("org.example", "extra"), Vec::<Vec<usize>>::new());
+        assert_eq!(tree.resolved_version("org.example", "shared"), Some("2.0"));
+
+        assert_eq!(node(&tree, "e", "1.0").scope, "test");
+        assert_eq!(node(&tree, "f", "2.0").status, NodeStatus::Included);
+        for dropped in ["extra", "servlet", "x"] {
+            assert!(tree.nodes.iter().all(|node| node.artifact_id != dropped), "{} is in the tree", dropped);
+        }
+
+        let conflicts = tree.conflicts();
+        assert_eq!(conflicts.len(), 1);
+        assert_eq!(conflicts[0].winner, "2.0");
+        assert_eq!(conflicts[0].versions[0].version, "2.0");
+    }
+
+    #[tokio::test]
+    async fn declared_and_inherited_management_take_precedence_over_imports() {
+        let managed = |entries: &[String]| format!("<dependencyManagement>{}</dependencyManagement>", dependencies(entries));
+        let import = "<type>pom</type><scope>import</scope>";
+
+        let parent = pom("org.example:parent:1.0", &managed(&[dependency("org.example:z:4.0", "")]));
+        let project = format!(
+            "<project><parent><groupId>org.example</groupId><artifactId>parent</artifactId><version>1.0</version></parent>\
+             <artifactId>app</artifactId><properties><first.bom>1.0</first.bom></properties>{}{}</project>",
+            managed(&[
+                dependency("org.example:x:1.0", ""),
+                dependency("org.example:first-bom:${first.bom}", import),
+                dependency("org.example:second-bom:1.0", import),
+            ]),
+            dependencies(&[
+                dependency("org.example:x", ""),
+                dependency("org.example:y", ""),
+                dependency("org.example:z", ""),
+                dependency("org.example:w", ""),
+            ]),
+        );
+        let poms = [
+            ("org.example:parent:1.0", parent),
+            (
+                "org.example:first-bom:1.0",
+                pom(
+                    "org.example:first-bom:1.0",
+                    &managed(&[dependency("org.example:x:2.0", ""), dependency("org.example:y:2.0", "")]),
+                ),
+            ),
+            (
+                "org.example:second-bom:1.0",
+                pom(
+                    "org.example:second-bom:1.0",
+                    &managed(&[
+                        dependency("org.example:y:3.0", ""),
+                        dependency("org.example:z:3.0", ""),
+                        dependency("org.example:w:3.0", "<scope>runtime</scope>"),
+                    ]),
+                ),
+            ),
+        ];
+
+        let mut builder = builder(&poms);
+        let model = builder.build(Pom::parse(&project).unwrap(), None, 0).await.unwrap();
+
+        let versions: Vec<(&str, &str, &str)> = model
+            .dependencies
+            .iter()
+            .map(|dependency| (dependency.artifact_id.as_str(), dependency.version.as_str(), dependency.scope.as_str()))
+            .collect();
+        assert_eq!(versions, vec![("x", "1.0", ""), ("y", "2.0", ""), ("z", "4.0", ""), ("w", "3.0", "runtime")]);
+        assert!(model.dependency_management.iter().all(|entry| !entry.is_bom_import()));
+        assert_eq!(model.version, "1.0");
+    }
+
+    #[tokio::test]
+    async fn stops_at_boms_importing_each_other() {
+        let import = |coordinates: &str| {
+            let entry = dependency(coordinates, "<type>pom</type><scope>import</scope>");
+            format!("<dependencyManagement><dependencies>{}</dependencies></dependencyManagement>", entry)
+        };
+        let poms = [
+            ("org.example:a:1.0", pom("org.example:a:1.0", &import("org.example:b:1.0"))),
+            ("org.example:b:1.0", pom("org.example:b:1.0", &import("org.example:a:1.0"))),
+        ];
+
+        let mut builder = builder(&poms);
+        let error = builder.load_effective("org.example", "a", "1.0").await.unwrap_err();
+
+        assert!(error.to_string().contains("org.example:a:1.0 imports itself"));
+        assert!(builder.in_progress.is_empty());
+    }
+
+    #[tokio::test]
+    async fn follows_paths_through_omitted_duplicates() {
+        let project = pom(
+            "org.example:app:1.0",
+            &dependencies(&[dependency("org.example:a:1.0", ""), dependency("org.example:b:1.0", "")]),
+        );
+        let poms = [
+            ("org.example:a:1.0", pom("org.example:a:1.0", &dependencies(&[dependency("org.example:t:1.0", "")]))),
+            (
+                "org.example:b:1.0",
+                pom(
+                    "org.example:b:1.0",
+                    &dependencies(&[dependency("org.example:a:1.0", ""), dependency("org.example:app:1.0", "")]),
+                ),
+            ),
+            ("org.example:app:1.0", project.clone()),
+            ("org.example:t:1.0", pom("org.example:t:1.0", "")),
+        ];
+
+        let mut resolver = Resolver { builder: builder(&poms) };
+        let tree = resolver.resolve(Pom::parse(&project).unwrap(), None).await.unwrap();
+
+        let paths: Vec<Vec<&str>> = tree
+            .paths_to_artifact("org.example", "t")
+            .iter()
+            .map(|path| path.iter().map(|index| tree.nodes[*index].artifact_id.as_str()).collect())
+            .collect();
+
+        assert_eq!(paths, vec![vec!["app", "a", "t"], vec!["app", "b", "a", "t"]]);
+    }
+}
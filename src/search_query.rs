@@ -0,0 +1,261 @@
+/// A single term of a search phrase, either free text or a `field:value` filter.
+#[derive(Debug, PartialEq, Clone)]
+pub enum SearchTerm {
+    Text(String),
+    GroupId(String),
+    ArtifactId(String),
+    Version(String),
+    ClassName(String),
+    FullyQualifiedClassName(String),
+    Tags(String),
+}
+
+/// Search phrase typed by the user, e.g. `g:org.foo a:bar jackson`.
+///
+/// Known prefixes are translated to the Solr fields of Maven Central,
+/// everything else is searched as free text.
+#[derive(Debug, PartialEq, Clone, Default)]
+pub struct SearchQuery {
+    pub terms: Vec<SearchTerm>,
+}
+
+/// Characters with a meaning in the Solr query syntax. `*` is left alone so
+/// wildcards keep working.
+const SOLR_SPECIAL_CHARS: &[char] = &[
+    '+', '-', '&', '|', '!', '(', ')', '{', '}', '[', ']', '^', '"', '~', '?', ':', '\\', '/',
+];
+
+impl SearchQuery {
+    pub fn parse(input: &str) -> SearchQuery {
+        let terms = tokenize(input)
+            .into_iter()
+            .filter_map(|(token, quoted)| SearchTerm::parse(&token, quoted))
+            .collect();
+
+        return SearchQuery { terms };
+    }
+
+    pub fn is_empty(&self) -> bool {
+        return self.terms.is_empty();
+    }
+
+    /// Filtering by version is only possible on the `gav` core, which lists
+    /// every released version instead of one entry per artifact.
+    pub fn requires_gav_core(&self) -> bool {
+        return self.terms.iter().any(|term| matches!(term, SearchTerm::Version(_)));
+    }
+
+    /// Class searches return one entry per released version of every artifact
+    /// containing the class.
+    pub fn is_class_search(&self) -> bool {
+        return self.terms.iter().any(|term| {
+            matches!(term, SearchTerm::ClassName(_) | SearchTerm::FullyQualifiedClassName(_))
+        });
+    }
+
+    pub fn to_solr(&self) -> String {
+        return self
+            .terms
+            .iter()
+            .map(SearchTerm::to_solr)
+            .collect::<Vec<String>>()
+            .join(" AND ");
+    }
+}
+
+impl SearchTerm {
+    fn parse(token: &str, quoted: bool) -> Option<SearchTerm> {
+        if token.is_empty() {
+            return None;
+        }
+
+        if quoted {
+            return Some(SearchTerm::Text(token.to_string()));
+        }
+
+        let Some((prefix, value)) = token.split_once(':') else {
+            return Some(SearchTerm::Text(token.to_string()));
+        };
+
+        let value = value.trim_matches('"').to_string();
+        if value.is_empty() {
+            return None;
+        }
+
+        let term = match prefix {
+            "g" => SearchTerm::GroupId(value),
+            "a" => SearchTerm::ArtifactId(value),
+            "v" => SearchTerm::Version(value),
+            "c" => SearchTerm::ClassName(value),
+            "fc" => SearchTerm::FullyQualifiedClassName(value),
+            "tags" => SearchTerm::Tags(value),
+            _ => SearchTerm::Text(token.to_string()),
+        };
+
+        return Some(term);
+    }
+
+    fn to_solr(&self) -> String {
+        let (field, value) = match self {
+            SearchTerm::Text(value) => return escape_solr(value),
+            SearchTerm::GroupId(value) => ("g", value),
+            SearchTerm::ArtifactId(value) => ("a", value),
+            SearchTerm::Version(value) => ("v", value),
+            SearchTerm::ClassName(value) => ("c", value),
+            SearchTerm::FullyQualifiedClassName(value) => ("fc", value),
+            SearchTerm::Tags(value) => ("tags", value),
+        };
+
+        return format!("{}:{}", field, escape_solr(value));
+    }
+}
+
+fn escape_solr(value: &str) -> String {
+    if value.contains(char::is_whitespace) {
+        return format!("\"{}\"", value.replace('\\', "\\\\").replace('"', "\\\""));
+    }
+
+    let mut escaped = String::with_capacity(value.len());
+    for char in value.chars() {
+        if SOLR_SPECIAL_CHARS.contains(&char) {
+            escaped.push('\\');
+        }
+        escaped.push(char);
+    }
+
+    return escaped;
+}
+
+/// Splits the input on whitespace while keeping double quoted phrases together.
+/// Each token is returned with a flag telling whether it was fully quoted.
+fn tokenize(input: &str) -> Vec<(String, bool)> {
+    let mut tokens = vec![];
+    let mut current = String::new();
+    let mut in_quotes = false;
+    let mut fully_quoted = false;
+
+    for char in input.chars() {
+        match char {
+            '"' => {
+                if current.is_empty() && !in_quotes {
+                    fully_quoted = true;
+                }
+                in_quotes = !in_quotes;
+                current.push(char);
+            }
+            char if char.is_whitespace() && !in_quotes => {
+                if !current.is_empty() {
+                    tokens.push(finish_token(&mut current, fully_quoted));
+                }
+                fully_quoted = false;
+            }
+            char => current.push(char),
+        }
+    }
+
+    if !current.is_empty() {
+        tokens.push(finish_token(&mut current, fully_quoted));
+    }
+
+    return tokens;
+}
+
+fn finish_token(current: &mut String, fully_quoted: bool) -> (String, bool) {
+    let token = std::mem::take(current);
+
+    if fully_quoted && token.len() >= 2 && token.ends_with('"') {
+        return (token[1..token.len() - 1].to_string(), true);
+    }
+
+    return (token, false);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_free_text() {
+        let query = SearchQuery::parse("jackson databind");
+
+        assert_eq!(
+            query.terms,
+            vec![
+                SearchTerm::Text("jackson".to_string()),
+                SearchTerm::Text("databind".to_string()),
+            ]
+        );
+        assert_eq!(query.to_solr(), "jackson AND databind");
+    }
+
+    #[test]
+    fn parses_field_prefixes() {
+        let query = SearchQuery::parse("g:org.foo a:bar v:1.2 c:ClassName fc:fully.qualified.Class tags:json");
+
+        assert_eq!(
+            query.terms,
+            vec![
+                SearchTerm::GroupId("org.foo".to_string()),
+                SearchTerm::ArtifactId("bar".to_string()),
+                SearchTerm::Version("1.2".to_string()),
+                SearchTerm::ClassName("ClassName".to_string()),
+                SearchTerm::FullyQualifiedClassName("fully.qualified.Class".to_string()),
+                SearchTerm::Tags("json".to_string()),
+            ]
+        );
+        assert_eq!(
+            query.to_solr(),
+            "g:org.foo AND a:bar AND v:1.2 AND c:ClassName AND fc:fully.qualified.Class AND tags:json"
+        );
+    }
+
+    #[test]
+    fn version_filter_requires_gav_core() {
+        assert!(SearchQuery::parse("a:guava v:33.0.0-jre").requires_gav_core());
+        assert!(!SearchQuery::parse("a:guava").requires_gav_core());
+    }
+
+    #[test]
+    fn class_prefixes_make_a_class_search() {
+        assert!(SearchQuery::parse("c:JsonNode").is_class_search());
+        assert!(SearchQuery::parse("fc:com.fasterxml.jackson.databind.JsonNode").is_class_search());
+        assert!(!SearchQuery::parse("jackson").is_class_search());
+    }
+
+    #[test]
+    fn unknown_prefix_is_escaped_free_text() {
+        let query = SearchQuery::parse("org.foo:bar");
+
+        assert_eq!(query.terms, vec![SearchTerm::Text("org.foo:bar".to_string())]);
+        assert_eq!(query.to_solr(), "org.foo\\:bar");
+    }
+
+    #[test]
+    fn quoted_phrases_stay_together() {
+        let query = SearchQuery::parse("\"spring boot\" g:\"org.springframework.boot\"");
+
+        assert_eq!(
+            query.terms,
+            vec![
+                SearchTerm::Text("spring boot".to_string()),
+                SearchTerm::GroupId("org.springframework.boot".to_string()),
+            ]
+        );
+        assert_eq!(query.to_solr(), "\"spring boot\" AND g:org.springframework.boot");
+    }
+
+    #[test]
+    fn empty_values_are_ignored() {
+        let query = SearchQuery::parse("  g:  a:bar ");
+
+        assert_eq!(query.terms, vec![SearchTerm::ArtifactId("bar".to_string())]);
+        assert!(SearchQuery::parse("   ").is_empty());
+    }
+
+    #[test]
+    fn escapes_special_characters() {
+        let query = SearchQuery::parse("v:[1.0,2.0) a:foo-bar*");
+
+        assert_eq!(query.to_solr(), "v:\\[1.0,2.0\\) AND a:foo\\-bar*");
+    }
+}
@@ -0,0 +1,152 @@
+use std::collections::HashSet;
+
+use crate::{
+    fuzzy::fuzzy_match,
+    maven_registry::SearchResponseDoc,
+    search_query::{SearchQuery, SearchTerm},
+};
+
+/// Bonus when a searched word is exactly the artifact id.
+const EXACT_ARTIFACT_BONUS: i64 = 64;
+/// Bonus for artifacts of a groupId the project already depends on.
+const SAME_GROUP_BONUS: i64 = 32;
+/// Bonus per doubling of the number of released versions.
+const VERSION_COUNT_WEIGHT: f64 = 4.0;
+/// Bonus for a release within the last year, lost over `RECENCY_YEARS`.
+const RECENCY_BONUS: i64 = 24;
+const RECENCY_YEARS: i64 = 4;
+
+const MILLIS_PER_YEAR: u64 = 365 * 86_400_000;
+
+/// A search result with its local score and the characters of its id
+/// matched by the search phrase.
+#[derive(Debug, Clone, PartialEq)]
+pub struct RankedArtifact {
+    /// Index in the results as returned by the registry.
+    pub index: usize,
+    pub score: i64,
+    pub positions: Vec<usize>,
+}
+
+/// Words of the phrase the artifact ids are matched against.
+fn patterns(query: &SearchQuery) -> Vec<&str> {
+    return query
+        .terms
+        .iter()
+        .filter_map(|term| match term {
+            SearchTerm::Text(text) | SearchTerm::GroupId(text) | SearchTerm::ArtifactId(text) => Some(text.as_str()),
+            _ => None,
+        })
+        .map(|pattern| pattern.trim_matches('*'))
+        .filter(|pattern| !pattern.is_empty())
+        .collect();
+}
+
+/// Scores one result. Words are matched against the artifact id first and
+/// the whole `groupId:artifactId` otherwise. Words the id does not match add
+/// nothing, since the registry may have matched them on other fields.
+fn score(doc: &SearchResponseDoc, patterns: &[&str], project_groups: &HashSet<String>, now_millis: u64) -> (i64, Vec<usize>) {
+    let id = format!("{}:{}", doc.g, doc.a);
+    let artifact_offset = doc.g.chars().count() + 1;
+    let mut score = 0;
+    let mut positions: Vec<usize> = vec![];
+
+    for pattern in patterns {
+        let found = match fuzzy_match(pattern, &doc.a) {
+            Some(mut found) => {
+                found.positions.iter_mut().for_each(|position| *position += artifact_offset);
+                Some(found)
+            }
+            None => fuzzy_match(pattern, &id),
+        };
+
+        if let Some(found) = found {
+            score += found.score;
+            positions.extend(found.positions);
+        }
+
+        if doc.a.eq_ignore_ascii_case(pattern) {
+            score += EXACT_ARTIFACT_BONUS;
+        }
+    }
+
+    if project_groups.contains(&doc.g) {
+        score += SAME_GROUP_BONUS;
+    }
+
+    score += ((doc.version_count as f64 + 1.0).log2() * VERSION_COUNT_WEIGHT) as i64;
+
+    if doc.timestamp > 0 {
+        let years = (now_millis.saturating_sub(doc.timestamp) / MILLIS_PER_YEAR) as i64;
+        score += (RECENCY_BONUS - years * RECENCY_BONUS / RECENCY_YEARS).max(0);
+    }
+
+    positions.sort_unstable();
+    positions.dedup();
+
+    return (score, positions);
+}
+
+/// Orders `docs` by how well they match `query`, how popular and recently
+/// released they are, and whether the project already uses their groupId.
+/// Equal scores keep the registry's order.
+pub fn rank(query: &SearchQuery, docs: &[SearchResponseDoc], project_groups: &HashSet<String>, now_millis: u64) -> Vec<RankedArtifact> {
+    let patterns = patterns(query);
+
+    let mut ranked: Vec<RankedArtifact> = docs
+        .iter()
+        .enumerate()
+        .map(|(index, doc)| {
+            let (score, positions) = score(doc, &patterns, project_groups, now_millis);
+            RankedArtifact { index, score, positions }
+        })
+        .collect();
+
+    ranked.sort_by_key(|artifact| std::cmp::Reverse(artifact.score));
+
+    return ranked;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const NOW: u64 = 1_700_000_000_000;
+
+    fn doc(group_id: &str, artifact_id: &str, version_count: u32, timestamp: u64) -> SearchResponseDoc {
+        let mut doc = SearchResponseDoc::default();
+        doc.id = format!("{}:{}", group_id, artifact_id);
+        doc.g = group_id.to_string();
+        doc.a = artifact_id.to_string();
+        doc.version_count = version_count;
+        doc.timestamp = timestamp;
+
+        return doc;
+    }
+
+    #[test]
+    fn ranks_the_obvious_artifact_first() {
+        let docs = vec![
+            doc("io.github.someone", "guava-extras", 2, NOW - 6 * MILLIS_PER_YEAR),
+            doc("org.example", "guava-testlib-ish", 1, NOW),
+            doc("com.google.guava", "guava", 120, NOW),
+        ];
+
+        let ranked = rank(&SearchQuery::parse("guava"), &docs, &HashSet::new(), NOW);
+
+        assert_eq!(ranked[0].index, 2);
+        assert_eq!(ranked[0].positions, vec![17, 18, 19, 20, 21]);
+    }
+
+    #[test]
+    fn prefers_groups_of_the_project() {
+        let docs = vec![doc("org.other", "client", 10, NOW), doc("org.mine", "client", 10, NOW)];
+        let groups = HashSet::from(["org.mine".to_string()]);
+
+        let ranked = rank(&SearchQuery::parse("client"), &docs, &groups, NOW);
+        assert_eq!(ranked.iter().map(|artifact| artifact.index).collect::<Vec<_>>(), vec![1, 0]);
+
+        let ranked = rank(&SearchQuery::parse("client"), &docs, &HashSet::new(), NOW);
+        assert_eq!(ranked.iter().map(|artifact| artifact.index).collect::<Vec<_>>(), vec![0, 1]);
+    }
+}
@@ -1,52 +1,151 @@
 use crate::{
     app::{UIState, Data},
-    views::{View, ViewId, dependency_view::DependencyView, dependency_search_view::DependencySearchView}
+    batch,
+    events::{AppIntentHandler, EventContext},
+    popup::{BatchSummaryPopup, DependencyEditorPopup, DependencyPathsPopup, HelpPopup, MessagePopup, Popup},
+    views::{Pane, ViewId}
 };
 use ratatui::{
     Frame,
-    layout::{Constraint, Direction},
-    style::{Color, palette::tailwind::SLATE},
+    buffer::Buffer,
+    layout::{Constraint, Direction, Rect},
+    style::{Color, Modifier, Style, palette::tailwind::SLATE},
+    widgets::{StatefulWidget, Tabs, Widget},
 };
-use std::collections::BTreeMap;
 
 const NORMAL_ROW_BG: Color = SLATE.c950;
 const ALT_ROW_BG_COLOR: Color = SLATE.c900;
+const TAB_HIGHLIGHT_STYLE: Style = Style::new().fg(SLATE.c100).add_modifier(Modifier::BOLD);
 
 pub struct UI;
 
 impl UI {
 
     pub fn render(f: &mut Frame, ui_state: &mut UIState, app_state: &Data) {
+        let area = f.area();
         let chunks = ratatui::layout::Layout::default()
             .direction(Direction::Horizontal)
             .constraints([Constraint::Percentage(50), Constraint::Percentage(50)])
-            .split(f.area());
+            .split(area);
 
-        let views: &mut Vec<(ViewId, Box<dyn View>)> = &mut ui_state.views;
+        let buffer = f.buffer_mut();
 
-        let mut buffer = f.buffer_mut();
+        for (pane, pane_area) in [(Pane::Left, chunks[0]), (Pane::Right, chunks[1])] {
+            Self::render_pane(buffer, pane_area, pane, ui_state, app_state);
+        }
 
+        if let Some(Popup::Help) = ui_state.popup {
+            Self::render_help(buffer, area, ui_state, app_state);
+        }
 
-        for (view_id, view) in views.iter_mut() {
-            match view_id {
-                ViewId::DependencyView => view.render(buffer, chunks[0], app_state),
-                ViewId::DependencySearchView => view.render(buffer, chunks[1], app_state),
+        match &mut ui_state.popup {
+            Some(Popup::DependencyPaths { group_id, artifact_id, scroll }) => {
+                let popup = DependencyPathsPopup { group_id, artifact_id, resolution: &app_state.dependency_tree };
+                popup.render(area, buffer, scroll);
+            }
+            Some(Popup::Message { title, text }) => MessagePopup { title, text }.render(area, buffer),
+            Some(Popup::BatchSummary { operation, artifacts, scroll }) => {
+                let changes = batch::plan_for(app_state, operation, artifacts);
+                BatchSummaryPopup { operation, changes: &changes }.render(area, buffer, scroll);
+            }
+            Some(Popup::EditDependency(editor)) => {
+                let versions = app_state.available_versions.get(&editor.original.id());
+                DependencyEditorPopup { editor, versions }.render(area, buffer);
             }
+            Some(Popup::Help) | None => (),
+        }
+    }
+
+    /// Renders the visible view of a pane, with tabs for switching when the
+    /// pane holds more than one view.
+    fn render_pane(buffer: &mut Buffer, area: Rect, pane: Pane, ui_state: &mut UIState, app_state: &Data) {
+        let Some(visible) = ui_state.visible_views.get(&pane).cloned() else {
+            return;
+        };
+
+        let pane_views: Vec<ViewId> = ui_state
+            .views
+            .iter()
+            .map(|(view_id, _)| view_id.clone())
+            .filter(|view_id| view_id.pane() == pane)
+            .collect();
+
+        let mut view_area = area;
+
+        if pane_views.len() > 1 {
+            let layout = ratatui::layout::Layout::default()
+                .direction(Direction::Vertical)
+                .constraints([Constraint::Length(1), Constraint::Min(0)])
+                .split(area);
+
+            let selected = pane_views.iter().position(|view_id| *view_id == visible);
+            let titles: Vec<&str> = pane_views.iter().map(ViewId::title).collect();
+
+            Tabs::new(titles)
+                .select(selected)
+                .highlight_style(TAB_HIGHLIGHT_STYLE)
+                .render(layout[0], buffer);
+
+            view_area = layout[1];
         }
 
+        if let Some((_, view)) = ui_state.views.iter_mut().find(|(view_id, _)| *view_id == visible) {
+            view.render(buffer, view_area, app_state);
+        }
+    }
+
+    fn render_help(buffer: &mut Buffer, area: Rect, ui_state: &UIState, app_state: &Data) {
+        let focused = &ui_state.currently_focused_view;
+        let ctx = EventContext {
+            mode: app_state.mode,
+            currently_focused_view: focused,
+        };
+
+        let mut sections = vec![("Global", AppIntentHandler::keybindings(&ctx))];
+
+        if let Some((view_id, view)) = ui_state.views.iter().find(|(view_id, _)| view_id == focused) {
+            sections.push((view_id.title(), view.keybindings()));
+        }
+
+        HelpPopup { sections }.render(area, buffer);
     }
 }
 
 pub fn alternate_colors(i: usize) -> Color {
-    if i % 2 == 0 {
+    if i.is_multiple_of(2) {
         NORMAL_ROW_BG
     } else {
         ALT_ROW_BG_COLOR
     }
 }
 
-#[derive(Debug, Clone)]
-pub enum Navigation {
-    Next,
-    Previous,
+/// Formats a Unix timestamp in milliseconds as an ISO calendar date.
+pub fn format_date(timestamp_millis: u64) -> String {
+    // Civil-from-days conversion, see http://howardhinnant.github.io/date_algorithms.html
+    let days = (timestamp_millis / 86_400_000) as i64 + 719_468;
+    let era = days.div_euclid(146_097);
+    let day_of_era = days.rem_euclid(146_097);
+    let year_of_era = (day_of_era - day_of_era / 1460 + day_of_era / 36_524 - day_of_era / 146_096) / 365;
+    let day_of_year = day_of_era - (365 * year_of_era + year_of_era / 4 - year_of_era / 100);
+    let month_index = (5 * day_of_year + 2) / 153;
+    let day = day_of_year - (153 * month_index + 2) / 5 + 1;
+    let month = if month_index < 10 { month_index + 3 } else { month_index - 9 };
+    let year = year_of_era + era * 400 + if month <= 2 { 1 } else { 0 };
+
+    return format!("{:04}-{:02}-{:02}", year, month, day);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn formats_timestamps_as_dates() {
+        assert_eq!(format_date(0), "1970-01-01");
+        assert_eq!(format_date(951_782_400_000), "2000-02-29");
+        assert_eq!(format_date(1_709_164_800_000), "2024-02-29");
+        assert_eq!(format_date(1_709_251_199_999), "2024-02-29");
+        assert_eq!(format_date(1_709_251_200_000), "2024-03-01");
+        assert_eq!(format_date(1_700_000_000_000), "2023-11-14");
+    }
 }
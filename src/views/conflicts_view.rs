@@ -0,0 +1,282 @@
+use ratatui::{
+    buffer::Buffer,
+    prelude::{Color, Modifier, Rect, Style, style::palette::tailwind::SLATE},
+    style::Stylize,
+    text::{Line, Span},
+    widgets::{Block, HighlightSpacing, List, ListItem, ListState, Paragraph, StatefulWidget, Widget},
+};
+use crate::{
+    views::View,
+    app::Data,
+    resolver::{Conflict, DependencyTree, ResolutionState},
+    ui::alternate_colors,
+    events::{Intent, KeyBinding}
+};
+
+use crossterm::event::{Event, KeyCode};
+use std::collections::HashMap;
+
+const SELECTED_STYLE: Style = Style::new().bg(SLATE.c800).add_modifier(Modifier::BOLD);
+const WINNER_STYLE: Style = Style::new().fg(Color::Green);
+const OMITTED_STYLE: Style = Style::new().fg(Color::Yellow);
+const PATH_STYLE: Style = Style::new().fg(SLATE.c400);
+
+#[derive(Debug, Clone, Copy)]
+enum ConflictsViewAction {
+    SelectNext,
+    SelectPrevious,
+    PinVersion,
+    Exclude,
+    Resolve,
+    Explain,
+}
+
+impl ConflictsViewAction {
+    fn description(&self) -> &'static str {
+        match self {
+            ConflictsViewAction::SelectNext => "Select next row",
+            ConflictsViewAction::SelectPrevious => "Select previous row",
+            ConflictsViewAction::PinVersion => "Pin selected version in dependencyManagement",
+            ConflictsViewAction::Exclude => "Exclude artifact from the direct dependency of selected path",
+            ConflictsViewAction::Resolve => "Resolve dependency tree",
+            ConflictsViewAction::Explain => "Show all paths to selected artifact",
+        }
+    }
+}
+
+/// A visible line of the conflict list: the artifact, one of its versions,
+/// or one of the paths requesting that version.
+#[derive(Debug, Clone, Copy)]
+enum ConflictRow {
+    Artifact { conflict: usize },
+    Version { conflict: usize, version: usize },
+    Path { conflict: usize, version: usize, path: usize },
+}
+
+pub struct ConflictsView {
+    list_state: ListState,
+    conflicts: Vec<Conflict>,
+    rows: Vec<ConflictRow>,
+    /// Coordinates of the direct dependency each path goes through, taken
+    /// from the tree at render time.
+    direct_dependencies: HashMap<(usize, usize, usize), (String, String)>,
+}
+
+impl ConflictsView {
+    pub fn new() -> Self {
+        Self {
+            list_state: Default::default(),
+            conflicts: vec![],
+            rows: vec![],
+            direct_dependencies: HashMap::new(),
+        }
+    }
+
+    fn get_mapping(&self) -> HashMap<KeyCode, ConflictsViewAction> {
+        return HashMap::from([
+            (KeyCode::Char('j'), ConflictsViewAction::SelectNext),
+            (KeyCode::Char('k'), ConflictsViewAction::SelectPrevious),
+            (KeyCode::Char('p'), ConflictsViewAction::PinVersion),
+            (KeyCode::Char('x'), ConflictsViewAction::Exclude),
+            (KeyCode::Char('r'), ConflictsViewAction::Resolve),
+            (KeyCode::Char('w'), ConflictsViewAction::Explain),
+        ]);
+    }
+
+    fn collect_rows(&mut self, tree: &DependencyTree) {
+        self.conflicts = tree.conflicts();
+        self.rows.clear();
+        self.direct_dependencies.clear();
+
+        for (conflict_index, conflict) in self.conflicts.iter().enumerate() {
+            self.rows.push(ConflictRow::Artifact { conflict: conflict_index });
+
+            for (version_index, version) in conflict.versions.iter().enumerate() {
+                self.rows.push(ConflictRow::Version { conflict: conflict_index, version: version_index });
+
+                for (path_index, path) in version.paths.iter().enumerate() {
+                    self.rows.push(ConflictRow::Path {
+                        conflict: conflict_index,
+                        version: version_index,
+                        path: path_index,
+                    });
+
+                    // Paths of direct dependencies have nothing to exclude from.
+                    if path.len() > 2 {
+                        let direct = &tree.nodes[path[1]];
+                        self.direct_dependencies.insert(
+                            (conflict_index, version_index, path_index),
+                            (direct.group_id.clone(), direct.artifact_id.clone()),
+                        );
+                    }
+                }
+            }
+        }
+    }
+
+    fn render_row(&self, tree: &DependencyTree, row: &ConflictRow) -> Line<'static> {
+        match *row {
+            ConflictRow::Artifact { conflict } => {
+                let conflict = &self.conflicts[conflict];
+
+                return Line::from(vec![
+                    Span::raw(format!("{}:{}", conflict.group_id, conflict.artifact_id)).bold(),
+                    Span::raw(" → "),
+                    Span::styled(conflict.winner.clone(), WINNER_STYLE),
+                ]);
+            }
+            ConflictRow::Version { conflict, version } => {
+                let conflict = &self.conflicts[conflict];
+                let version = &conflict.versions[version];
+
+                let (label, style) = if version.version == conflict.winner {
+                    ("selected", WINNER_STYLE)
+                } else {
+                    ("omitted", OMITTED_STYLE)
+                };
+
+                return Line::from(vec![
+                    Span::raw("  "),
+                    Span::styled(format!("{} ({})", version.version, label), style),
+                    Span::styled(format!(" requested by {} path(s)", version.paths.len()), PATH_STYLE),
+                ]);
+            }
+            ConflictRow::Path { conflict, version, path } => {
+                let path = &self.conflicts[conflict].versions[version].paths[path];
+
+                let text = path
+                    .iter()
+                    .map(|node| tree.nodes[*node].coordinates())
+                    .collect::<Vec<String>>()
+                    .join(" > ");
+
+                return Line::from(vec![Span::raw("    "), Span::styled(text, PATH_STYLE)]);
+            }
+        }
+    }
+
+    fn selected_row(&self) -> Option<ConflictRow> {
+        let selected = self.list_state.selected()?;
+
+        return self.rows.get(selected).copied();
+    }
+
+    fn pin_version(&self) -> Option<Intent> {
+        let (conflict, version) = match self.selected_row()? {
+            ConflictRow::Artifact { conflict } => {
+                let conflict = &self.conflicts[conflict];
+                (conflict, conflict.winner.clone())
+            }
+            ConflictRow::Version { conflict, version } | ConflictRow::Path { conflict, version, .. } => {
+                let conflict = &self.conflicts[conflict];
+                (conflict, conflict.versions[version].version.clone())
+            }
+        };
+
+        return Some(Intent::PinManagedVersion {
+            group_id: conflict.group_id.clone(),
+            artifact_id: conflict.artifact_id.clone(),
+            version,
+        });
+    }
+
+    fn explain(&self) -> Option<Intent> {
+        let conflict = match self.selected_row()? {
+            ConflictRow::Artifact { conflict }
+            | ConflictRow::Version { conflict, .. }
+            | ConflictRow::Path { conflict, .. } => &self.conflicts[conflict],
+        };
+
+        return Some(Intent::ExplainDependency {
+            group_id: conflict.group_id.clone(),
+            artifact_id: conflict.artifact_id.clone(),
+        });
+    }
+
+    fn exclude(&self) -> Option<Intent> {
+        let ConflictRow::Path { conflict, version, path } = self.selected_row()? else {
+            return None;
+        };
+
+        let (dependency_group_id, dependency_artifact_id) =
+            self.direct_dependencies.get(&(conflict, version, path))?.clone();
+        let conflict = &self.conflicts[conflict];
+
+        return Some(Intent::AddExclusion {
+            dependency_group_id,
+            dependency_artifact_id,
+            group_id: conflict.group_id.clone(),
+            artifact_id: conflict.artifact_id.clone(),
+        });
+    }
+}
+
+impl View for ConflictsView {
+
+    fn render(&mut self, buffer: &mut Buffer, area: Rect, state: &Data) {
+        let block = Block::new().title(Line::raw("Version Conflicts").centered());
+
+        let tree = match &state.dependency_tree {
+            ResolutionState::Resolved(tree) => tree,
+            other => {
+                self.conflicts.clear();
+                self.rows.clear();
+
+                let message = match other {
+                    ResolutionState::Resolving => "Resolving dependencies...".to_string(),
+                    ResolutionState::Failed(error) => format!("Resolution failed: {}", error),
+                    _ => "Press r to resolve the dependency tree".to_string(),
+                };
+
+                Paragraph::new(message).block(block).render(area, buffer);
+                return;
+            }
+        };
+
+        self.collect_rows(tree);
+
+        if self.rows.is_empty() {
+            Paragraph::new("No version conflicts").block(block).render(area, buffer);
+            return;
+        }
+
+        let items: Vec<ListItem> = self
+            .rows
+            .iter()
+            .enumerate()
+            .map(|(i, row)| {
+                let color = alternate_colors(i);
+                ListItem::new(self.render_row(tree, row)).bg(color)
+            })
+            .collect();
+
+        let list = List::new(items)
+            .block(block)
+            .highlight_style(SELECTED_STYLE)
+            .highlight_symbol(">")
+            .highlight_spacing(HighlightSpacing::Always);
+
+        StatefulWidget::render(list, area, buffer, &mut self.list_state);
+    }
+
+    fn handle_event(&mut self, event: &Event) -> Option<Intent> {
+        if let Event::Key(key_event) = event {
+            let action = *self.get_mapping().get(&key_event.code)?;
+
+            match action {
+                ConflictsViewAction::SelectNext => self.list_state.select_next(),
+                ConflictsViewAction::SelectPrevious => self.list_state.select_previous(),
+                ConflictsViewAction::PinVersion => return self.pin_version(),
+                ConflictsViewAction::Exclude => return self.exclude(),
+                ConflictsViewAction::Resolve => return Some(Intent::ResolveDependencyTree),
+                ConflictsViewAction::Explain => return self.explain(),
+            };
+        }
+
+        return None;
+    }
+
+    fn keybindings(&self) -> Vec<KeyBinding> {
+        return KeyBinding::from_mapping(&self.get_mapping(), ConflictsViewAction::description);
+    }
+}
@@ -1,28 +1,91 @@
 use ratatui::{
     buffer::Buffer,
     layout::{Constraint, Direction, Layout, Rect},
-    style::{Modifier, Style, Stylize, palette::tailwind::SLATE},
-    text::Line,
-    widgets::{ListState, Block, HighlightSpacing, List, ListItem, StatefulWidget, Widget},
+    style::{Color, Modifier, Style, Stylize, palette::tailwind::SLATE},
+    text::{Line, Span},
+    widgets::{ListState, Block, Borders, HighlightSpacing, List, ListItem, Paragraph, StatefulWidget, Widget, Wrap},
 };
 
 use crossterm::event::{KeyCode, Event};
+use std::{
+    collections::{HashMap, HashSet},
+    time::{SystemTime, UNIX_EPOCH},
+};
 
 use crate::{
-    app::{Data, UIState}, 
-    ui::alternate_colors, 
+    app::{Data, Paginated}, 
+    maven_registry::{GetVersionsResponseDoc, SearchResponseDoc},
+    search_query::SearchQuery,
+    search_ranking::{self, RankedArtifact},
+    ui::{alternate_colors, format_date}, 
     views::View,
-    events::Intent
+    events::{Intent, KeyBinding},
+    widgets::text_input::TextInput,
 };
 
 const SELECTED_STYLE: Style = Style::new().bg(SLATE.c800).add_modifier(Modifier::BOLD);
+const LABEL_STYLE: Style = Style::new().fg(SLATE.c400);
+const MATCH_STYLE: Style = Style::new().fg(Color::Yellow).add_modifier(Modifier::BOLD);
+
+#[derive(Debug, Clone, Copy)]
+enum DependencySearchViewAction {
+    StartEditing,
+    StopEditing,
+    Search,
+    SelectNext,
+    SelectPrevious,
+    FocusVersions,
+    FocusArtifacts,
+    LoadVersions,
+    LoadDetails,
+    AddDependency,
+    ToggleSearchMode,
+    ToggleRanking,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum SearchMode {
+    Artifact,
+    ClassName,
+}
+
+impl DependencySearchViewAction {
+    fn description(&self) -> &'static str {
+        match self {
+            DependencySearchViewAction::StartEditing => "Edit search phrase",
+            DependencySearchViewAction::StopEditing => "Stop editing search phrase",
+            DependencySearchViewAction::Search => "Search Maven Central",
+            DependencySearchViewAction::SelectNext => "Select next entry",
+            DependencySearchViewAction::SelectPrevious => "Select previous entry",
+            DependencySearchViewAction::FocusVersions => "Move to versions list",
+            DependencySearchViewAction::FocusArtifacts => "Move to artifacts list",
+            DependencySearchViewAction::LoadVersions => "Load versions of selected artifact",
+            DependencySearchViewAction::LoadDetails => "Load details of selected version",
+            DependencySearchViewAction::AddDependency => "Add selected version to dependencies",
+            DependencySearchViewAction::ToggleSearchMode => "Toggle artifact / class name search",
+            DependencySearchViewAction::ToggleRanking => "Toggle local ranking / registry order",
+        }
+    }
+}
 
 pub struct DependencySearchView {
     list_state: ListState,
     versions_list_state: ListState,
+    /// Id of the artifact the selection in `versions_list_state` belongs to.
+    versions_artifact: Option<String>,
     version_list_focused: bool,
     input_mode: bool,
-    input: String,
+    input: TextInput,
+    search_mode: SearchMode,
+    /// Whether results are re-ranked locally instead of kept in registry order.
+    local_ranking: bool,
+    /// Listed artifacts in display order, from the last render.
+    ranked: Vec<RankedArtifact>,
+    /// Search id, query, revision of the results and ranking mode `ranked`
+    /// was computed for.
+    ranked_for: Option<(u64, String, u64, bool)>,
+    artifact_count: usize,
+    version_count: usize,
 }
 
 impl DependencySearchView {
@@ -30,11 +93,204 @@ impl DependencySearchView {
         Self {
             list_state: Default::default(),
             versions_list_state: Default::default(),
+            versions_artifact: None,
             version_list_focused: false,
             input_mode: false,
-            input: Default::default(),
+            input: TextInput::new(),
+            search_mode: SearchMode::Artifact,
+            local_ranking: true,
+            ranked: vec![],
+            ranked_for: None,
+            artifact_count: 0,
+            version_count: 0,
+        }
+    }
+
+    fn get_mapping(&self) -> HashMap<KeyCode, DependencySearchViewAction> {
+        if self.input_mode {
+            return HashMap::from([
+                (KeyCode::Esc, DependencySearchViewAction::StopEditing),
+                (KeyCode::Enter, DependencySearchViewAction::Search),
+            ]);
+        }
+
+        return HashMap::from([
+            (KeyCode::Char('i'), DependencySearchViewAction::StartEditing),
+            (KeyCode::Char('s'), DependencySearchViewAction::Search),
+            (KeyCode::Char('j'), DependencySearchViewAction::SelectNext),
+            (KeyCode::Char('k'), DependencySearchViewAction::SelectPrevious),
+            (KeyCode::Char('l'), DependencySearchViewAction::FocusVersions),
+            (KeyCode::Char('h'), DependencySearchViewAction::FocusArtifacts),
+            (KeyCode::Char('c'), DependencySearchViewAction::ToggleSearchMode),
+            (KeyCode::Char('r'), DependencySearchViewAction::ToggleRanking),
+            (KeyCode::Char('+'), DependencySearchViewAction::AddDependency),
+            match self.version_list_focused {
+                true => (KeyCode::Enter, DependencySearchViewAction::LoadDetails),
+                false => (KeyCode::Enter, DependencySearchViewAction::LoadVersions),
+            },
+        ]);
+    }
+
+    /// Phrase sent to the registry. In class name mode plain words are searched
+    /// as simple (`c:`) or fully qualified (`fc:`) class names.
+    fn search_phrase(&self) -> String {
+        let input = self.input.value();
+
+        if self.search_mode == SearchMode::Artifact {
+            return input;
         }
+
+        return input
+            .split_whitespace()
+            .map(|word| match (word.contains(':'), word.contains('.')) {
+                (true, _) => word.to_string(),
+                (false, true) => format!("fc:{}", word),
+                (false, false) => format!("c:{}", word),
+            })
+            .collect::<Vec<String>>()
+            .join(" ");
+    }
+
+    /// Index in `found_dependencies` of the selected artifact.
+    fn selected_artifact(&self) -> Option<usize> {
+        return self.ranked.get(self.list_state.selected()?).map(|artifact| artifact.index);
+    }
+
+    /// Found artifacts in the order they are listed.
+    fn rank(&self, state: &Data) -> Vec<RankedArtifact> {
+        let docs = &state.found_dependencies.items;
+        if !self.local_ranking {
+            return (0..docs.len()).map(|index| RankedArtifact { index, score: 0, positions: vec![] }).collect();
+        }
+
+        let project_groups: HashSet<String> = state.dependencies.iter().map(|dependency| dependency.group_id.clone()).collect();
+        let now = SystemTime::now().duration_since(UNIX_EPOCH).map_or(0, |elapsed| elapsed.as_millis() as u64);
+
+        return search_ranking::rank(&SearchQuery::parse(&state.search_query), docs, &project_groups, now);
     }
+
+    /// The selected artifact and version, when the version belongs to it.
+    fn selected_version(&self) -> Option<(usize, usize)> {
+        self.versions_artifact.as_ref()?;
+        let version_index = self.versions_list_state.selected().filter(|selected| *selected < self.version_count)?;
+
+        return Some((self.selected_artifact()?, version_index));
+    }
+
+    /// Drops the version selection once another artifact is selected.
+    fn forget_selected_version(&mut self) {
+        self.versions_list_state = ListState::default();
+        self.versions_artifact = None;
+    }
+
+    fn move_artifact_selection(&mut self, step: fn(&mut ListState)) {
+        let previous = self.selected_artifact();
+        step(&mut self.list_state);
+
+        if self.selected_artifact() != previous {
+            self.forget_selected_version();
+        }
+    }
+
+    /// Moves the selection of the focused list down and asks for the next
+    /// page once the last loaded entry is reached.
+    fn select_next(&mut self) -> Option<Intent> {
+        if self.version_list_focused {
+            self.versions_list_state.select_next();
+
+            let index = self.selected_artifact()?;
+            let selected = self.versions_list_state.selected()?;
+            if selected + 1 >= self.version_count {
+                return Some(Intent::LoadMoreDependencyVersions { index });
+            }
+        } else {
+            self.move_artifact_selection(ListState::select_next);
+
+            let selected = self.list_state.selected()?;
+            if selected + 1 >= self.artifact_count {
+                return Some(Intent::LoadMoreDependencies);
+            }
+        }
+
+        return None;
+    }
+}
+
+fn detail_line<'a>(label: &'a str, value: String) -> Line<'a> {
+    return Line::from(vec![
+        Span::styled(format!("{:<12}", label), LABEL_STYLE),
+        Span::raw(value),
+    ]);
+}
+
+/// Describes the selected artifact, or one of its versions when picked, together
+/// with metadata of the corresponding `.pom` once it has been fetched.
+fn detail_lines<'a>(artifact: &'a SearchResponseDoc, version: Option<&'a GetVersionsResponseDoc>, state: &'a Data) -> Vec<Line<'a>> {
+    let mut lines = vec![detail_line("Artifact", format!("{}:{}", artifact.g, artifact.a))];
+
+    let selected_version = match version {
+        Some(version) => {
+            lines.push(detail_line("Version", version.v.clone()));
+            lines.push(detail_line("Packaging", version.p.clone()));
+            lines.push(detail_line("Files", version.ec.join(", ")));
+            lines.push(detail_line("Released", format_date(version.timestamp)));
+            &version.v
+        }
+        None => {
+            lines.push(detail_line("Latest", artifact.latest_version.clone()));
+            lines.push(detail_line("Packaging", artifact.p.clone()));
+            lines.push(detail_line("Files", artifact.ec.join(", ")));
+            lines.push(detail_line("Released", format_date(artifact.timestamp)));
+            lines.push(detail_line("Versions", artifact.version_count.to_string()));
+            &artifact.latest_version
+        }
+    };
+
+    let coordinates = format!("{}:{}:{}", artifact.g, artifact.a, selected_version);
+    let Some(metadata) = state.artifact_metadata.get(&coordinates) else {
+        lines.push(Line::raw(""));
+        lines.push(Line::styled("Press Enter to load POM details", LABEL_STYLE));
+        return lines;
+    };
+
+    let licenses = metadata
+        .licenses
+        .iter()
+        .map(|license| license.name.clone())
+        .collect::<Vec<String>>()
+        .join(", ");
+
+    lines.push(detail_line("Name", metadata.name.clone()));
+    lines.push(detail_line("License", licenses));
+    lines.push(detail_line("Homepage", metadata.url.clone()));
+    if let Some(scm) = &metadata.scm {
+        lines.push(detail_line("SCM", scm.url.clone()));
+    }
+    lines.push(Line::raw(""));
+    lines.push(Line::raw(metadata.description.clone()));
+
+    return lines;
+}
+
+fn footer<T>(page: &Paginated<T>) -> Line<'static> {
+    let mut text = format!("showing {} of {}", page.items.len(), page.num_found);
+
+    if page.loading {
+        text.push_str(" (loading...)");
+    } else if let Some(error) = &page.error {
+        text.push_str(&format!(" (loading failed: {})", error));
+    }
+
+    return Line::raw(text).right_aligned();
+}
+
+fn split_footer(area: Rect) -> (Rect, Rect) {
+    let layout = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints(vec![Constraint::Min(0), Constraint::Length(1)])
+        .split(area);
+
+    return (layout[0], layout[1]);
 }
 
 impl View for DependencySearchView {
@@ -46,6 +302,7 @@ impl View for DependencySearchView {
                 Constraint::Percentage(10),
                 Constraint::Percentage(10),
                 Constraint::Percentage(50),
+                Constraint::Percentage(30),
             ])
             .split(area);
 
@@ -57,107 +314,208 @@ impl View for DependencySearchView {
             ])
             .split(layout[2]);
 
-        let block = Block::new().title(Line::raw("Search Dependencies").centered());
+        let title = match self.search_mode {
+            SearchMode::Artifact => "Search Dependencies",
+            SearchMode::ClassName => "Search Dependencies by Class Name",
+        };
+
+        let block = Block::new().title(Line::raw(title).centered());
         block.render(layout[0], buffer);
 
-        let text_input = Block::new().title(Line::raw(&self.input).centered());
-        text_input.render(layout[1], buffer);
+        self.input.render(layout[1], buffer, self.input_mode);
+
+        let (artifacts_area, artifacts_footer_area) = split_footer(dependencies_layout[0]);
+        let (versions_area, versions_footer_area) = split_footer(dependencies_layout[1]);
+
+        // Pages arriving later are ranked in between, so the selection
+        // follows the artifact rather than the row.
+        let selected = self.selected_artifact();
+        let ranked_for = (
+            state.latest_search_id,
+            state.search_query.clone(),
+            state.found_dependencies.revision,
+            self.local_ranking,
+        );
+        if self.ranked_for.as_ref() != Some(&ranked_for) {
+            self.ranked = self.rank(state);
+            self.ranked_for = Some(ranked_for);
+        }
+        if let Some(selected) = selected
+            && let Some(row) = self.ranked.iter().position(|artifact| artifact.index == selected)
+        {
+            self.list_state.select(Some(row));
+        }
+
+        let artifact_id = self
+            .selected_artifact()
+            .and_then(|index| state.found_dependencies.items.get(index))
+            .map(|artifact| artifact.id.clone());
+        if artifact_id != self.versions_artifact {
+            self.forget_selected_version();
+            self.versions_artifact = artifact_id;
+        }
 
-        let items: Vec<ListItem> = state
-            .found_dependencies
+        let items: Vec<ListItem> = self
+            .ranked
             .iter()
             .enumerate()
-            .map(|(i, dependency)| {
-                let item = String::from(format!("{}", dependency.id));
+            .map(|(i, artifact)| {
+                let dependency = &state.found_dependencies.items[artifact.index];
+                let spans: Vec<Span> = dependency
+                    .id
+                    .chars()
+                    .enumerate()
+                    .map(|(position, char)| match artifact.positions.contains(&position) {
+                        true => Span::styled(char.to_string(), MATCH_STYLE),
+                        false => Span::raw(char.to_string()),
+                    })
+                    .collect();
 
                 let color = alternate_colors(i);
-                ListItem::new(item).bg(color)
+                ListItem::new(Line::from(spans)).bg(color)
             })
             .collect();
 
+        self.artifact_count = items.len();
+
         let list = List::new(items)
             .highlight_style(SELECTED_STYLE)
             .highlight_symbol(">")
             .highlight_spacing(HighlightSpacing::Always);
 
-        StatefulWidget::render(list, dependencies_layout[0], buffer, &mut self.list_state);
+        StatefulWidget::render(list, artifacts_area, buffer, &mut self.list_state);
+        footer(&state.found_dependencies).render(artifacts_footer_area, buffer);
 
-        if let Some(index) = self.list_state.selected() {
-            if let Some(currently_selected) = state.found_dependencies.get(index) {
-                let id = format!("{}:{}", currently_selected.g, currently_selected.a);
-                
-                let mut sum = state.found_dependency_versions.get(&id);
-                let default = &Vec::new();
+        self.version_count = 0;
 
-                let versions = sum.get_or_insert(default);
+        if let Some(index) = self.selected_artifact()
+            && let Some(currently_selected) = state.found_dependencies.items.get(index)
+            && let Some(versions) = state
+                .found_dependency_versions
+                .get(&format!("{}:{}", currently_selected.g, currently_selected.a))
+        {
+            let items: Vec<ListItem> = versions 
+                .items
+                .iter()
+                .enumerate()
+                .map(|(i, version)| {
+                    let item = version.v.to_string();
 
-                let items: Vec<ListItem> = versions 
-                    .iter()
-                    .enumerate()
-                    .map(|(i, version)| {
-                        let item = String::from(format!("{}", version.v));
+                    let color = alternate_colors(i);
+                    ListItem::new(item).bg(color)
+                })
+                .collect();
 
-                        let color = alternate_colors(i);
-                        ListItem::new(item).bg(color)
-                    })
-                    .collect();
+            self.version_count = items.len();
 
-                let list = List::new(items)
-                    .highlight_style(SELECTED_STYLE)
-                    .highlight_symbol(">")
-                    .highlight_spacing(HighlightSpacing::Always);
+            let list = List::new(items)
+                .highlight_style(SELECTED_STYLE)
+                .highlight_symbol(">")
+                .highlight_spacing(HighlightSpacing::Always);
 
-                StatefulWidget::render(list, dependencies_layout[1], buffer, &mut self.versions_list_state);
-            }
+            StatefulWidget::render(list, versions_area, buffer, &mut self.versions_list_state);
+            footer(versions).render(versions_footer_area, buffer);
         }
 
+        if let Some(index) = self.selected_artifact()
+            && let Some(artifact) = state.found_dependencies.items.get(index)
+        {
+            let version = self
+                .versions_list_state
+                .selected()
+                .filter(|_| self.version_list_focused)
+                .and_then(|version_index| {
+                    state
+                        .found_dependency_versions
+                        .get(&format!("{}:{}", artifact.g, artifact.a))?
+                        .items
+                        .get(version_index)
+                });
 
+            let details = Paragraph::new(detail_lines(artifact, version, state))
+                .block(Block::new().borders(Borders::TOP).title(Line::raw("Details")))
+                .wrap(Wrap { trim: true });
+
+            details.render(layout[3], buffer);
+        }
     }
 
     fn handle_event(&mut self, event: &Event) -> Option<Intent> {
+        if let Event::Paste(_) = event
+            && self.input_mode
+            && self.input.handle_event(event)
+        {
+            return Some(Intent::SearchQueryChanged(self.search_phrase()));
+        }
+
         if let Event::Key(key_event) = event {
             let keycode = key_event.code;
 
-            if self.input_mode {
-                match keycode {
-                    KeyCode::Esc => {
-                        self.input_mode = false;
-                    }
-                    KeyCode::Backspace => {
-                        self.input.pop();
-                    }
-                    KeyCode::Char(char) => self.input.push(char),
-                    _ => (),
-                };
+            let Some(action) = self.get_mapping().get(&keycode).copied() else {
+                if self.input_mode && self.input.handle_event(event) {
+                    return Some(Intent::SearchQueryChanged(self.search_phrase()));
+                }
 
                 return None;
-            }
-
-            let focused_list = match self.version_list_focused {
-                true => &mut self.versions_list_state,
-                false => &mut self.list_state,
             };
 
-            match keycode {
-                KeyCode::Char('i') => {
+            match action {
+                DependencySearchViewAction::StartEditing => {
                     self.input_mode = true;
+                    return Some(Intent::EnterInputMode);
                 }
-                KeyCode::Char('s') => {
-                    return Some(Intent::FindNewDependencies(self.input.to_string()));
+                DependencySearchViewAction::StopEditing => {
+                    self.input_mode = false;
+                    return Some(Intent::LeaveInputMode);
                 }
-                KeyCode::Char('j') => focused_list.select_next(),
-                KeyCode::Char('l') => self.version_list_focused = true,
-                KeyCode::Char('h') => self.version_list_focused = false,
-                KeyCode::Char('k') => focused_list.select_previous(),
-                KeyCode::Enter => {
-                    if let Some(index) = self.list_state.selected() {
+                DependencySearchViewAction::Search => {
+                    self.input.push_history();
+                    return Some(Intent::FindNewDependencies(self.search_phrase()));
+                }
+                DependencySearchViewAction::SelectNext => return self.select_next(),
+                DependencySearchViewAction::SelectPrevious => match self.version_list_focused {
+                    true => self.versions_list_state.select_previous(),
+                    false => self.move_artifact_selection(ListState::select_previous),
+                },
+                DependencySearchViewAction::FocusVersions => self.version_list_focused = true,
+                DependencySearchViewAction::FocusArtifacts => self.version_list_focused = false,
+                DependencySearchViewAction::LoadVersions => {
+                    if let Some(index) = self.selected_artifact() {
                         return Some(Intent::GetAvailableDependencyVersions { index });
                     }
                 }
-                _ => ()
+                DependencySearchViewAction::ToggleSearchMode => {
+                    self.search_mode = match self.search_mode {
+                        SearchMode::Artifact => SearchMode::ClassName,
+                        SearchMode::ClassName => SearchMode::Artifact,
+                    };
+
+                    return Some(Intent::FindNewDependencies(self.search_phrase()));
+                }
+                DependencySearchViewAction::ToggleRanking => self.local_ranking = !self.local_ranking,
+                DependencySearchViewAction::AddDependency => {
+                    if let Some((index, version_index)) = self.selected_version() {
+                        return Some(Intent::AddDependency { index, version_index });
+                    }
+                }
+                DependencySearchViewAction::LoadDetails => {
+                    if let Some((index, version_index)) = self.selected_version() {
+                        return Some(Intent::GetArtifactDetails { index, version_index });
+                    }
+                }
             }
         }
 
         return None;
     }
+
+    fn keybindings(&self) -> Vec<KeyBinding> {
+        let mut bindings = KeyBinding::from_mapping(&self.get_mapping(), DependencySearchViewAction::description);
+
+        if self.input_mode {
+            bindings.extend(self.input.keybindings());
+        }
+
+        return bindings;
+    }
 }
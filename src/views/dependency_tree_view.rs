@@ -0,0 +1,288 @@
+use ratatui::{
+    buffer::Buffer,
+    prelude::{Color, Modifier, Rect, Style, style::palette::tailwind::SLATE},
+    style::Stylize,
+    text::{Line, Span},
+    widgets::{ListState, Block, HighlightSpacing, List, ListItem, Paragraph, StatefulWidget, Widget},
+};
+use crate::{
+    views::View,
+    app::Data,
+    resolver::{DependencyTree, NodeStatus, ResolutionState},
+    ui::alternate_colors,
+    events::{Intent, KeyBinding}
+};
+
+use crossterm::event::{Event, KeyCode};
+use std::collections::{HashMap, HashSet};
+
+const SELECTED_STYLE: Style = Style::new().bg(SLATE.c800).add_modifier(Modifier::BOLD);
+const SCOPE_STYLE: Style = Style::new().fg(SLATE.c400);
+const MANAGED_STYLE: Style = Style::new().fg(Color::Cyan);
+const CONFLICT_STYLE: Style = Style::new().fg(Color::Yellow);
+const WINNER_STYLE: Style = Style::new().fg(Color::Green);
+const OMITTED_STYLE: Style = Style::new().fg(SLATE.c500);
+const ERROR_STYLE: Style = Style::new().fg(Color::Red);
+
+#[derive(Debug, Clone, Copy)]
+enum DependencyTreeViewAction {
+    SelectNext,
+    SelectPrevious,
+    Expand,
+    Collapse,
+    Toggle,
+    ExpandAll,
+    CollapseAll,
+    Resolve,
+    Explain,
+}
+
+impl DependencyTreeViewAction {
+    fn description(&self) -> &'static str {
+        match self {
+            DependencyTreeViewAction::SelectNext => "Select next node",
+            DependencyTreeViewAction::SelectPrevious => "Select previous node",
+            DependencyTreeViewAction::Expand => "Expand node",
+            DependencyTreeViewAction::Collapse => "Collapse node / go to parent",
+            DependencyTreeViewAction::Toggle => "Toggle node",
+            DependencyTreeViewAction::ExpandAll => "Expand all nodes",
+            DependencyTreeViewAction::CollapseAll => "Collapse all nodes",
+            DependencyTreeViewAction::Resolve => "Resolve dependency tree",
+            DependencyTreeViewAction::Explain => "Show why selected artifact is here",
+        }
+    }
+}
+
+/// A visible line of the tree, remembered from the last render.
+struct TreeRow {
+    node: usize,
+    group_id: String,
+    artifact_id: String,
+    has_children: bool,
+    parent_row: Option<usize>,
+}
+
+pub struct DependencyTreeView {
+    list_state: ListState,
+    collapsed: HashSet<usize>,
+    rows: Vec<TreeRow>,
+    expandable: Vec<usize>,
+}
+
+impl DependencyTreeView {
+    pub fn new() -> Self {
+        Self {
+            list_state: Default::default(),
+            collapsed: HashSet::new(),
+            rows: vec![],
+            expandable: vec![],
+        }
+    }
+
+    fn get_mapping(&self) -> HashMap<KeyCode, DependencyTreeViewAction> {
+        return HashMap::from([
+            (KeyCode::Char('j'), DependencyTreeViewAction::SelectNext),
+            (KeyCode::Char('k'), DependencyTreeViewAction::SelectPrevious),
+            (KeyCode::Char('l'), DependencyTreeViewAction::Expand),
+            (KeyCode::Char('h'), DependencyTreeViewAction::Collapse),
+            (KeyCode::Enter, DependencyTreeViewAction::Toggle),
+            (KeyCode::Char('E'), DependencyTreeViewAction::ExpandAll),
+            (KeyCode::Char('C'), DependencyTreeViewAction::CollapseAll),
+            (KeyCode::Char('r'), DependencyTreeViewAction::Resolve),
+            (KeyCode::Char('w'), DependencyTreeViewAction::Explain),
+        ]);
+    }
+
+    fn collect_rows(&mut self, tree: &DependencyTree) {
+        self.rows.clear();
+        self.expandable = tree
+            .nodes
+            .iter()
+            .enumerate()
+            .filter(|(_, node)| !node.children.is_empty())
+            .map(|(index, _)| index)
+            .collect();
+
+        let mut stack: Vec<(usize, Option<usize>)> = vec![(0, None)];
+
+        while let Some((node_index, parent_row)) = stack.pop() {
+            let node = &tree.nodes[node_index];
+            let row_index = self.rows.len();
+
+            self.rows.push(TreeRow {
+                node: node_index,
+                group_id: node.group_id.clone(),
+                artifact_id: node.artifact_id.clone(),
+                has_children: !node.children.is_empty(),
+                parent_row,
+            });
+
+            if self.collapsed.contains(&node_index) {
+                continue;
+            }
+
+            for child in node.children.iter().rev() {
+                stack.push((*child, Some(row_index)));
+            }
+        }
+    }
+
+    fn render_row(&self, tree: &DependencyTree, row: &TreeRow) -> Line<'static> {
+        let node = &tree.nodes[row.node];
+
+        let marker = match (row.has_children, self.collapsed.contains(&row.node)) {
+            (false, _) => "  ",
+            (true, true) => "▸ ",
+            (true, false) => "▾ ",
+        };
+
+        let mut spans = vec![
+            Span::raw("  ".repeat(node.depth)),
+            Span::raw(marker),
+            Span::raw(node.coordinates()),
+        ];
+
+        if !node.scope.is_empty() {
+            spans.push(Span::styled(format!(" [{}]", node.scope), SCOPE_STYLE));
+        }
+        if node.optional {
+            spans.push(Span::styled(" (optional)", SCOPE_STYLE));
+        }
+        if node.is_managed() {
+            spans.push(Span::styled(format!(" (managed from {})", node.requested_version), MANAGED_STYLE));
+        }
+
+        match &node.status {
+            NodeStatus::Included => {
+                let overridden = tree.overridden_versions(row.node);
+                if !overridden.is_empty() {
+                    spans.push(Span::styled(format!(" (wins over {})", overridden.join(", ")), WINNER_STYLE));
+                }
+            }
+            NodeStatus::OmittedForConflict { winner } => {
+                spans.push(Span::styled(format!(" (omitted for conflict with {})", winner), CONFLICT_STYLE));
+            }
+            NodeStatus::OmittedForDuplicate => {
+                spans.push(Span::styled(" (omitted for duplicate)", OMITTED_STYLE));
+            }
+            NodeStatus::Unresolved(error) => {
+                spans.push(Span::styled(format!(" (unresolved: {})", error), ERROR_STYLE));
+            }
+        }
+
+        return Line::from(spans);
+    }
+
+    fn collapse(&mut self) {
+        let Some(selected) = self.list_state.selected() else {
+            return;
+        };
+        let Some(row) = self.rows.get(selected) else {
+            return;
+        };
+
+        if row.has_children && !self.collapsed.contains(&row.node) {
+            self.collapsed.insert(row.node);
+        } else if let Some(parent_row) = row.parent_row {
+            self.list_state.select(Some(parent_row));
+        }
+    }
+
+    fn selected_node(&self) -> Option<usize> {
+        let selected = self.list_state.selected()?;
+
+        return self.rows.get(selected).map(|row| row.node);
+    }
+}
+
+impl View for DependencyTreeView {
+
+    fn render(&mut self, buffer: &mut Buffer, area: Rect, state: &Data) {
+        let block = Block::new().title(Line::raw("Dependency Tree").centered());
+
+        let tree = match &state.dependency_tree {
+            ResolutionState::Resolved(tree) => tree,
+            other => {
+                self.rows.clear();
+
+                let message = match other {
+                    ResolutionState::Resolving => "Resolving dependencies...".to_string(),
+                    ResolutionState::Failed(error) => format!("Resolution failed: {}", error),
+                    _ => "Press r to resolve the dependency tree".to_string(),
+                };
+
+                Paragraph::new(message).block(block).render(area, buffer);
+                return;
+            }
+        };
+
+        self.collect_rows(tree);
+
+        let items: Vec<ListItem> = self
+            .rows
+            .iter()
+            .enumerate()
+            .map(|(i, row)| {
+                let color = alternate_colors(i);
+                ListItem::new(self.render_row(tree, row)).bg(color)
+            })
+            .collect();
+
+        let list = List::new(items)
+            .block(block)
+            .highlight_style(SELECTED_STYLE)
+            .highlight_symbol(">")
+            .highlight_spacing(HighlightSpacing::Always);
+
+        StatefulWidget::render(list, area, buffer, &mut self.list_state);
+    }
+
+    fn handle_event(&mut self, event: &Event) -> Option<Intent> {
+        if let Event::Key(key_event) = event {
+            let action = *self.get_mapping().get(&key_event.code)?;
+
+            match action {
+                DependencyTreeViewAction::SelectNext => self.list_state.select_next(),
+                DependencyTreeViewAction::SelectPrevious => self.list_state.select_previous(),
+                DependencyTreeViewAction::Expand => {
+                    if let Some(node) = self.selected_node() {
+                        self.collapsed.remove(&node);
+                    }
+                }
+                DependencyTreeViewAction::Collapse => self.collapse(),
+                DependencyTreeViewAction::Toggle => {
+                    if let Some(node) = self.selected_node()
+                        && !self.collapsed.remove(&node)
+                    {
+                        self.collapsed.insert(node);
+                    }
+                }
+                DependencyTreeViewAction::ExpandAll => self.collapsed.clear(),
+                DependencyTreeViewAction::CollapseAll => {
+                    // Keep the project itself open so its direct dependencies stay visible.
+                    self.collapsed = self.expandable.iter().copied().filter(|node| *node != 0).collect();
+                    self.list_state.select(Some(0));
+                }
+                DependencyTreeViewAction::Resolve => {
+                    self.collapsed.clear();
+                    return Some(Intent::ResolveDependencyTree);
+                }
+                DependencyTreeViewAction::Explain => {
+                    // The project itself has no path leading to it.
+                    let row = self.rows.get(self.list_state.selected()?).filter(|row| row.node != 0)?;
+
+                    return Some(Intent::ExplainDependency {
+                        group_id: row.group_id.clone(),
+                        artifact_id: row.artifact_id.clone(),
+                    });
+                }
+            };
+        }
+
+        return None;
+    }
+
+    fn keybindings(&self) -> Vec<KeyBinding> {
+        return KeyBinding::from_mapping(&self.get_mapping(), DependencyTreeViewAction::description);
+    }
+}
@@ -2,77 +2,857 @@ use ratatui::{
     buffer::Buffer,
     prelude::{Color, Modifier, Rect, Style, style::palette::tailwind::SLATE},
     style::Stylize,
-    text::Line,
+    text::{Line, Span},
     widgets::{ListState, Block, HighlightSpacing, List, ListItem, StatefulWidget},
 };
 use crate::{
     views::View,
-    app::{Data, UIState}, 
-    ui::alternate_colors, 
-    events::Intent
+    app::Data,
+    audit::{AuditState, Finding, Severity},
+    batch::{BatchOperation, SCOPES},
+    dependency::{self, DeclarationOrder, JavaDependency},
+    fuzzy::fuzzy_match,
+    license::{LicenseLookup, Verdict},
+    maven_version::{self, Outdatedness, UpgradeLevel},
+    resolver::ResolutionState,
+    ui::alternate_colors,
+    events::{Intent, KeyBinding},
+    widgets::prompt::Prompt,
 };
 
 use crossterm::event::{Event, KeyCode};
+use std::{
+    cmp::Reverse,
+    collections::{HashMap, HashSet},
+};
 
 const SELECTED_STYLE: Style = Style::new().bg(SLATE.c800).add_modifier(Modifier::BOLD);
+const AUDIT_STATUS_STYLE: Style = Style::new().fg(SLATE.c400);
+const PENDING_LICENSE_STYLE: Style = Style::new().fg(SLATE.c500);
+const MARK_STYLE: Style = Style::new().fg(Color::Yellow).add_modifier(Modifier::BOLD);
+const ERROR_STYLE: Style = Style::new().fg(Color::Red);
+const GROUP_STYLE: Style = Style::new().fg(Color::Cyan).add_modifier(Modifier::BOLD);
+const OUTDATED_STYLE: Style = Style::new().fg(Color::Yellow);
+
+fn verdict_style(verdict: Verdict) -> Style {
+    match verdict {
+        Verdict::Allowed => return Style::new().fg(Color::Green),
+        Verdict::Denied => return Style::new().fg(Color::Red).add_modifier(Modifier::BOLD),
+        Verdict::NotAllowed => return Style::new().fg(Color::Yellow),
+        Verdict::Unknown => return Style::new().fg(SLATE.c400),
+    }
+}
+
+fn severity_style(severity: Severity) -> Style {
+    let color = match severity {
+        Severity::Critical => Color::Magenta,
+        Severity::High => Color::Red,
+        Severity::Moderate => Color::Yellow,
+        Severity::Low | Severity::Unknown => Color::Blue,
+    };
+
+    return Style::new().fg(color).add_modifier(Modifier::BOLD);
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum SortMode {
+    Declaration,
+    Alphabetical,
+    Scope,
+    Outdatedness,
+}
+
+impl SortMode {
+    fn label(&self) -> &'static str {
+        match self {
+            SortMode::Declaration => "declaration order",
+            SortMode::Alphabetical => "name",
+            SortMode::Scope => "scope",
+            SortMode::Outdatedness => "outdatedness",
+        }
+    }
+
+    fn next(&self) -> SortMode {
+        match self {
+            SortMode::Declaration => SortMode::Alphabetical,
+            SortMode::Alphabetical => SortMode::Scope,
+            SortMode::Scope => SortMode::Outdatedness,
+            SortMode::Outdatedness => SortMode::Declaration,
+        }
+    }
+}
+
+/// What the input at the bottom of the view is for.
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum Edit {
+    Scope,
+    Filter,
+}
+
+/// A line of the list.
+#[derive(Debug, Clone, PartialEq)]
+enum Row {
+    /// Header of the dependencies sharing a groupId, when grouping.
+    Group { group_id: String, count: usize },
+    /// A dependency, by its index in `Data::dependencies`.
+    Dependency(usize),
+}
+
+#[derive(Debug, Clone, Copy)]
+enum DependencyViewAction {
+    SelectNext,
+    SelectPrevious,
+    DeleteSelected,
+    Explain,
+    ExportLicenses,
+    ExtractVersion,
+    ToggleMark,
+    ToggleVisual,
+    ClearMarks,
+    BatchDelete,
+    BatchUpgrade(UpgradeLevel),
+    BatchScope,
+    Filter,
+    CycleSort,
+    ToggleGrouping,
+    ToggleGroup,
+    MoveUp,
+    MoveDown,
+    SortDeclarations,
+    Edit,
+    Undo,
+    StopEditing,
+    Submit,
+}
+
+impl DependencyViewAction {
+    fn description(&self) -> &'static str {
+        match self {
+            DependencyViewAction::SelectNext => "Select next dependency",
+            DependencyViewAction::SelectPrevious => "Select previous dependency",
+            DependencyViewAction::DeleteSelected => "Delete selected dependency",
+            DependencyViewAction::Explain => "Show why selected dependency is here",
+            DependencyViewAction::ExportLicenses => "Export license report",
+            DependencyViewAction::ExtractVersion => "Extract version of selected dependency into a property",
+            DependencyViewAction::ToggleMark => "Mark or unmark selected dependency",
+            DependencyViewAction::ToggleVisual => "Start or end marking a range",
+            DependencyViewAction::ClearMarks => "Clear marks and filter",
+            DependencyViewAction::BatchDelete => "Delete marked dependencies",
+            DependencyViewAction::BatchUpgrade(UpgradeLevel::Patch) => "Upgrade marked dependencies to latest patch",
+            DependencyViewAction::BatchUpgrade(UpgradeLevel::Minor) => "Upgrade marked dependencies to latest minor",
+            DependencyViewAction::BatchScope => "Change scope of marked dependencies",
+            DependencyViewAction::Filter => "Filter dependencies",
+            DependencyViewAction::CycleSort => "Change sort order",
+            DependencyViewAction::ToggleGrouping => "Group by groupId",
+            DependencyViewAction::ToggleGroup => "Collapse or expand selected group",
+            DependencyViewAction::MoveUp => "Move selected declaration up",
+            DependencyViewAction::MoveDown => "Move selected declaration down",
+            DependencyViewAction::SortDeclarations => "Sort declarations like the list (by name or scope)",
+            DependencyViewAction::Edit => "Edit selected dependency",
+            DependencyViewAction::Undo => "Undo last dependency edit",
+            DependencyViewAction::StopEditing => "Cancel editing",
+            DependencyViewAction::Submit => "Apply",
+        }
+    }
+}
 
 pub struct DependencyView {
     list_state: ListState,
+    /// Group and artifact ids of the dependencies, by index in
+    /// `Data::dependencies`, from the last render.
+    dependency_ids: Vec<(String, String)>,
+    /// Dependencies matching the filter, in the sort order, from the last render.
+    visible: Vec<usize>,
+    /// Lines of the list, from the last render.
+    rows: Vec<Row>,
+    /// Dependencies batch operations apply to, by group and artifact id.
+    marked: HashSet<(String, String)>,
+    /// Row a range being marked started at.
+    visual_anchor: Option<usize>,
+    prompt: Prompt<Edit>,
+    scope_error: Option<String>,
+    /// Fuzzy pattern matched against `groupId:artifactId:version`.
+    filter: String,
+    sort: SortMode,
+    grouped: bool,
+    /// GroupIds whose dependencies are hidden under their header.
+    collapsed: HashSet<String>,
+    /// Whether declarations can be reordered, which only pom.xml files support.
+    reorderable: bool,
 }
 
 impl DependencyView {
     pub fn new () -> Self {
         Self {
             list_state: Default::default(),
+            dependency_ids: vec![],
+            visible: vec![],
+            rows: vec![],
+            marked: HashSet::new(),
+            visual_anchor: None,
+            prompt: Prompt::new(),
+            scope_error: None,
+            filter: String::new(),
+            sort: SortMode::Declaration,
+            grouped: false,
+            collapsed: HashSet::new(),
+            reorderable: true,
         }
     }
+
+    /// Pattern the list is filtered with, following the input while it is
+    /// being typed.
+    fn filter_pattern(&self) -> String {
+        match self.prompt.editing() {
+            Some(Edit::Filter) => return self.prompt.value(),
+            _ => return self.filter.clone(),
+        }
+    }
+
+    fn outdatedness(dependency: &JavaDependency, state: &Data) -> Option<Outdatedness> {
+        let key = format!("{}:{}", dependency.group_id, dependency.artifact_id);
+        let Some(Ok(latest)) = state.latest_versions.get(&key) else {
+            return None;
+        };
+
+        return Some(Self::audited_version(dependency, state).map_or(Outdatedness::UpToDate, |version| {
+            maven_version::outdatedness(version, latest)
+        }));
+    }
+
+    /// Indices of the dependencies matching the filter, in the sort order.
+    fn visible_dependencies(&self, state: &Data) -> Vec<usize> {
+        let pattern = self.filter_pattern();
+        let mut visible: Vec<usize> = state
+            .dependencies
+            .iter()
+            .enumerate()
+            .filter(|(_, dependency)| {
+                let text = format!("{}:{}:{}", dependency.group_id, dependency.artifact_id, dependency.version);
+                fuzzy_match(&pattern, &text).is_some()
+            })
+            .map(|(i, _)| i)
+            .collect();
+
+        let dependencies = &state.dependencies;
+        match self.sort {
+            SortMode::Declaration => {}
+            SortMode::Alphabetical => visible.sort_by_cached_key(|i| {
+                (dependencies[*i].group_id.to_lowercase(), dependencies[*i].artifact_id.to_lowercase())
+            }),
+            SortMode::Scope => {
+                let pom = state.build_file().to_pom();
+                let scopes: HashMap<(&str, &str), &str> = pom
+                    .dependencies
+                    .iter()
+                    .map(|declared| ((declared.group_id.as_str(), declared.artifact_id.as_str()), declared.scope.as_str()))
+                    .collect();
+
+                visible.sort_by_cached_key(|i| {
+                    let dependency = &dependencies[*i];
+                    let scope = scopes.get(&(dependency.group_id.as_str(), dependency.artifact_id.as_str()));
+                    let rank = dependency::scope_rank(scope.copied().unwrap_or_default());
+                    (rank, dependency.group_id.to_lowercase(), dependency.artifact_id.to_lowercase())
+                });
+            }
+            // Most outdated first, not looked up yet last.
+            SortMode::Outdatedness => visible.sort_by_key(|i| Reverse(Self::outdatedness(&dependencies[*i], state))),
+        }
+
+        return visible;
+    }
+
+    /// Rows for the visible dependencies, under a header per groupId when
+    /// grouping. Groups keep the position of their first dependency.
+    fn build_rows(&self, state: &Data) -> Vec<Row> {
+        if !self.grouped {
+            return self.visible.iter().copied().map(Row::Dependency).collect();
+        }
+
+        let mut groups: Vec<(&str, Vec<usize>)> = vec![];
+        for i in self.visible.iter().copied() {
+            let group_id = state.dependencies[i].group_id.as_str();
+            match groups.iter_mut().find(|(group, _)| *group == group_id) {
+                Some((_, members)) => members.push(i),
+                None => groups.push((group_id, vec![i])),
+            }
+        }
+
+        let mut rows = vec![];
+        for (group_id, members) in groups {
+            rows.push(Row::Group { group_id: group_id.to_string(), count: members.len() });
+            if !self.collapsed.contains(group_id) {
+                rows.extend(members.into_iter().map(Row::Dependency));
+            }
+        }
+
+        return rows;
+    }
+
+    /// Index in `Data::dependencies` of the selected dependency, if a
+    /// dependency rather than a group header is selected.
+    fn selected_dependency(&self) -> Option<usize> {
+        match self.rows.get(self.list_state.selected()?) {
+            Some(Row::Dependency(index)) => return Some(*index),
+            _ => return None,
+        }
+    }
+
+    /// Dependencies under the header at `row`, including collapsed ones.
+    fn group_members(&self, row: usize) -> Vec<(String, String)> {
+        let Some(Row::Group { group_id, .. }) = self.rows.get(row) else {
+            return vec![];
+        };
+
+        return self
+            .visible
+            .iter()
+            .filter_map(|index| self.dependency_ids.get(*index))
+            .filter(|(group, _)| group == group_id)
+            .cloned()
+            .collect();
+    }
+
+    /// Dependencies a row stands for: itself, or the group under a header.
+    fn row_ids(&self, row: usize) -> Vec<(String, String)> {
+        match self.rows.get(row) {
+            Some(Row::Dependency(index)) => return self.dependency_ids.get(*index).cloned().into_iter().collect(),
+            Some(Row::Group { .. }) => return self.group_members(row),
+            None => return vec![],
+        }
+    }
+
+    /// Rows of the range being marked.
+    fn visual_rows(&self) -> Vec<usize> {
+        let (Some(anchor), Some(selected)) = (self.visual_anchor, self.list_state.selected()) else {
+            return vec![];
+        };
+
+        return (anchor.min(selected)..=anchor.max(selected)).collect();
+    }
+
+    fn is_marked(&self, row: usize) -> bool {
+        let Some(Row::Dependency(index)) = self.rows.get(row) else {
+            return false;
+        };
+
+        return self.visual_rows().contains(&row)
+            || self.dependency_ids.get(*index).is_some_and(|id| self.marked.contains(id));
+    }
+
+    /// Ends marking a range, keeping its rows marked.
+    fn commit_visual(&mut self) {
+        for row in self.visual_rows() {
+            let ids = self.row_ids(row);
+            self.marked.extend(ids);
+        }
+
+        self.visual_anchor = None;
+    }
+
+    /// Marked dependencies in declaration order, or the selected one (or
+    /// group) when none is marked. Marks hidden by the filter still count.
+    fn targets(&mut self) -> Vec<(String, String)> {
+        self.commit_visual();
+
+        let marked: Vec<(String, String)> = self.dependency_ids.iter().filter(|id| self.marked.contains(*id)).cloned().collect();
+        if !marked.is_empty() {
+            return marked;
+        }
+
+        return self.list_state.selected().map(|row| self.row_ids(row)).unwrap_or_default();
+    }
+
+    /// Moves the selected declaration past its listed neighbour. Only
+    /// possible while the list shows the declaration order.
+    fn move_selected(&mut self, down: bool) -> Option<Intent> {
+        if self.sort != SortMode::Declaration || self.grouped {
+            return None;
+        }
+
+        let row = self.list_state.selected()?;
+        let neighbour = if down { row + 1 } else { row.checked_sub(1)? };
+        let (from, to) = (*self.visible.get(row)?, *self.visible.get(neighbour)?);
+
+        self.list_state.select(Some(neighbour));
+
+        return Some(Intent::MoveDependency { from, to });
+    }
+
+    fn plan_batch(&mut self, operation: BatchOperation) -> Option<Intent> {
+        let artifacts = self.targets();
+        if artifacts.is_empty() {
+            return None;
+        }
+
+        return Some(Intent::PlanBatch { operation, artifacts });
+    }
+
+    /// Types into the prompt, restarting the selection when the filter changed.
+    fn edit_prompt(&mut self, event: &Event) {
+        if self.prompt.handle_event(event) && self.prompt.editing() == Some(&Edit::Filter) {
+            self.list_state.select_first();
+        }
+    }
+
+    fn submit_scope(&mut self) -> Option<Intent> {
+        let scope = self.prompt.value().trim().to_string();
+        if !SCOPES.contains(&scope.as_str()) {
+            self.scope_error = Some(format!("Scope must be one of {}", SCOPES.join(", ")));
+            return None;
+        }
+
+        self.prompt.close();
+        self.scope_error = None;
+
+        return self.plan_batch(BatchOperation::SetScope(scope));
+    }
+
+    /// Version the project actually uses: the mediated one once the tree is
+    /// resolved, otherwise the declared one unless it is still a property.
+    fn audited_version<'a>(dependency: &'a JavaDependency, state: &'a Data) -> Option<&'a str> {
+        if let ResolutionState::Resolved(tree) = &state.dependency_tree
+            && let Some(version) = tree.resolved_version(&dependency.group_id, &dependency.artifact_id)
+        {
+            return Some(version);
+        }
+
+        return Some(dependency.version.as_str()).filter(|version| !version.is_empty() && !version.contains("${"));
+    }
+
+    fn finding_spans(finding: &Finding) -> Vec<Span<'static>> {
+        let style = severity_style(finding.severity);
+
+        let ids: Vec<&str> = finding.advisories.iter().map(|advisory| advisory.display_id()).collect();
+        let fix = match &finding.fixed_in {
+            Some(version) => format!("fixed in {}", version),
+            None => "no fix available".to_string(),
+        };
+
+        return vec![
+            Span::styled(format!(" ⚠ {}", finding.severity.label()), style),
+            Span::styled(format!(" {} ({})", ids.join(", "), fix), style.remove_modifier(Modifier::BOLD)),
+        ];
+    }
+
+    /// License column of a dependency, colored by the policy verdict.
+    fn license_span(dependency: &JavaDependency, state: &Data) -> (Span<'static>, Option<Verdict>) {
+        let key = format!("{}:{}", dependency.group_id, dependency.artifact_id);
+
+        match state.licenses.get(&key) {
+            Some(LicenseLookup::Found { licenses, .. }) => {
+                let verdict = state.license_policy.evaluate(licenses);
+                let text = if licenses.is_empty() { "no license".to_string() } else { licenses.join(" OR ") };
+
+                return (Span::styled(text, verdict_style(verdict)), Some(verdict));
+            }
+            Some(LicenseLookup::Failed(_)) => return (Span::styled("lookup failed", PENDING_LICENSE_STYLE), None),
+            None => return (Span::styled("…", PENDING_LICENSE_STYLE), None),
+        }
+    }
+
+    /// Latest version of a dependency that is behind it.
+    fn latest_span(dependency: &JavaDependency, state: &Data) -> Option<Span<'static>> {
+        let outdatedness = Self::outdatedness(dependency, state).filter(|outdatedness| *outdatedness != Outdatedness::UpToDate)?;
+        let Some(Ok(latest)) = state.latest_versions.get(&format!("{}:{}", dependency.group_id, dependency.artifact_id)) else {
+            return None;
+        };
+
+        return Some(Span::styled(format!("  → {} ({})", latest, outdatedness.label()), OUTDATED_STYLE));
+    }
+
+    fn audit_status(state: &Data) -> String {
+        match &state.audit {
+            AuditState::Disabled => return " No advisory database ".to_string(),
+            AuditState::Loading => return " Loading advisories... ".to_string(),
+            AuditState::Loaded(database) => return format!(" {} advisories loaded ", database.len()),
+            AuditState::Failed(error) => return format!(" Advisories failed to load: {} ", error),
+        }
+    }
+
+    /// How the list is filtered, sorted and grouped, when not the default.
+    fn list_status(&self) -> Option<String> {
+        let mut parts = vec![];
+
+        let pattern = self.filter_pattern();
+        if !pattern.is_empty() {
+            parts.push(format!("/{}", pattern));
+        }
+        if self.sort != SortMode::Declaration {
+            parts.push(format!("by {}", self.sort.label()));
+        }
+        if self.grouped {
+            parts.push("grouped".to_string());
+        }
+
+        return match parts.is_empty() {
+            true => None,
+            false => Some(format!(" {} ", parts.join(" · "))),
+        };
+    }
+
+    fn get_mapping(&self) -> HashMap<KeyCode, DependencyViewAction> {
+        if self.prompt.is_open() {
+            return HashMap::from([
+                (KeyCode::Esc, DependencyViewAction::StopEditing),
+                (KeyCode::Enter, DependencyViewAction::Submit),
+            ]);
+        }
+
+        let mut mapping = HashMap::from([
+            (KeyCode::Char('j'), DependencyViewAction::SelectNext),
+            (KeyCode::Char('k'), DependencyViewAction::SelectPrevious),
+            (KeyCode::Char('d'), DependencyViewAction::DeleteSelected),
+            (KeyCode::Char('w'), DependencyViewAction::Explain),
+            (KeyCode::Char('e'), DependencyViewAction::ExportLicenses),
+            (KeyCode::Char('x'), DependencyViewAction::ExtractVersion),
+            (KeyCode::Char(' '), DependencyViewAction::ToggleMark),
+            (KeyCode::Char('V'), DependencyViewAction::ToggleVisual),
+            (KeyCode::Esc, DependencyViewAction::ClearMarks),
+            (KeyCode::Char('D'), DependencyViewAction::BatchDelete),
+            (KeyCode::Char('u'), DependencyViewAction::BatchUpgrade(UpgradeLevel::Patch)),
+            (KeyCode::Char('U'), DependencyViewAction::BatchUpgrade(UpgradeLevel::Minor)),
+            (KeyCode::Char('S'), DependencyViewAction::BatchScope),
+            (KeyCode::Char('/'), DependencyViewAction::Filter),
+            (KeyCode::Char('o'), DependencyViewAction::CycleSort),
+            (KeyCode::Char('g'), DependencyViewAction::ToggleGrouping),
+            (KeyCode::Char('c'), DependencyViewAction::Edit),
+            (KeyCode::Char('z'), DependencyViewAction::Undo),
+        ]);
+
+        if self.reorderable {
+            mapping.insert(KeyCode::Char('K'), DependencyViewAction::MoveUp);
+            mapping.insert(KeyCode::Char('J'), DependencyViewAction::MoveDown);
+            mapping.insert(KeyCode::Char('O'), DependencyViewAction::SortDeclarations);
+        }
+
+        if self.grouped {
+            mapping.insert(KeyCode::Enter, DependencyViewAction::ToggleGroup);
+        }
+
+        return mapping;
+    }
 }
 
 impl View for DependencyView {
 
     fn render(&mut self, buffer: &mut Buffer, area: Rect, state: &Data) {
-        let block = Block::new().title(Line::raw("Dependencies").centered());
+        self.reorderable = state.gradle_file.is_none();
+
+        let block = Block::new()
+            .title(Line::raw(format!("Dependencies ({})", state.build_file().name())).centered())
+            .title_bottom(Line::styled(Self::audit_status(state), AUDIT_STATUS_STYLE).right_aligned());
 
-        let items: Vec<ListItem> = state
+        let title = match (&self.scope_error, self.prompt.editing()) {
+            (Some(error), _) => Line::styled(error.clone(), ERROR_STYLE),
+            (None, Some(Edit::Scope)) => Line::raw(format!("Scope ({})", SCOPES.join(", "))),
+            (None, Some(Edit::Filter)) => Line::raw("Filter (group:artifact:version)"),
+            (None, None) => Line::default(),
+        };
+        let list_area = self.prompt.render(area, buffer, title);
+
+        self.dependency_ids = state
             .dependencies
             .iter()
+            .map(|dependency| (dependency.group_id.clone(), dependency.artifact_id.clone()))
+            .collect();
+        self.marked.retain(|id| self.dependency_ids.contains(id));
+        self.visible = self.visible_dependencies(state);
+        self.rows = self.build_rows(state);
+
+        // Grouped rows sit under a header naming their group already.
+        let names: HashMap<usize, String> = self
+            .rows
+            .iter()
+            .filter_map(|row| match row {
+                Row::Dependency(index) => {
+                    let dependency = &state.dependencies[*index];
+                    let name = match self.grouped {
+                        true => format!("  {} {}", dependency.artifact_id, dependency.version),
+                        false => format!("{}:{} {}", dependency.group_id, dependency.artifact_id, dependency.version),
+                    };
+                    Some((*index, name))
+                }
+                Row::Group { .. } => None,
+            })
+            .collect();
+        let name_width = names.values().map(|name| name.chars().count()).max().unwrap_or(0);
+        let mut violations = 0;
+
+        let items: Vec<ListItem> = self
+            .rows
+            .iter()
             .enumerate()
-            .map(|(i, dependency)| {
-                let item = String::from(format!("{} {}", dependency.group_id, dependency.version));
+            .map(|(row, kind)| {
+                let index = match kind {
+                    Row::Group { group_id, count } => {
+                        let arrow = if self.collapsed.contains(group_id) { "▸" } else { "▾" };
+                        return ListItem::new(Line::styled(format!("{} {} ({})", arrow, group_id, count), GROUP_STYLE));
+                    }
+                    Row::Dependency(index) => *index,
+                };
+                let dependency = &state.dependencies[index];
+
+                let (license, verdict) = Self::license_span(dependency, state);
+                if let Some(Verdict::Denied | Verdict::NotAllowed) = verdict {
+                    violations += 1;
+                }
 
-                let color = alternate_colors(i);
-                ListItem::new(item).bg(color)
+                let mark = match self.is_marked(row) {
+                    true => Span::styled("● ", MARK_STYLE),
+                    false => Span::raw("  "),
+                };
+                let mut spans = vec![mark, Span::raw(format!("{:width$}  ", names[&index], width = name_width)), license];
+
+                if self.sort == SortMode::Outdatedness
+                    && let Some(latest) = Self::latest_span(dependency, state)
+                {
+                    spans.push(latest);
+                }
+
+                if let AuditState::Loaded(database) = &state.audit
+                    && let Some(version) = Self::audited_version(dependency, state)
+                    && let Some(finding) = database.audit(&dependency.group_id, &dependency.artifact_id, version)
+                {
+                    spans.extend(Self::finding_spans(&finding));
+                }
+
+                let color = alternate_colors(row);
+                ListItem::new(Line::from(spans)).bg(color)
             })
             .collect();
 
+        let block = match violations {
+            0 => block,
+            count => block.title_bottom(
+                Line::styled(format!(" {} license violation(s) ", count), verdict_style(Verdict::Denied)).left_aligned(),
+            ),
+        };
+
+        let mut marked = self.marked.clone();
+        for row in self.visual_rows() {
+            marked.extend(self.row_ids(row));
+        }
+        let block = match marked.len() {
+            0 => block,
+            count => block.title_bottom(Line::styled(format!(" {} marked ", count), MARK_STYLE).left_aligned()),
+        };
+
+        let block = match self.list_status() {
+            Some(status) => block.title_bottom(Line::styled(status, AUDIT_STATUS_STYLE).left_aligned()),
+            None => block,
+        };
+
         let list = List::new(items)
             .block(block)
             .highlight_style(SELECTED_STYLE)
             .highlight_symbol(">")
             .highlight_spacing(HighlightSpacing::Always);
 
-        StatefulWidget::render(list, area, buffer, &mut self.list_state);
+        StatefulWidget::render(list, list_area, buffer, &mut self.list_state);
     }
 
     fn handle_event(&mut self, event: &Event) -> Option<Intent> {
         if let Event::Key(key_event) = event {
-            let keycode = key_event.code;
+            let Some(action) = self.get_mapping().get(&key_event.code).copied() else {
+                self.edit_prompt(event);
+                return None;
+            };
 
-            match keycode {
-                KeyCode::Char('j') => self.list_state.select_next(),
-                KeyCode::Char('k') => self.list_state.select_previous(),
-                KeyCode::Char('d') =>  {
-                    if let Some(index) = self.list_state.selected() {
+            match action {
+                DependencyViewAction::SelectNext => self.list_state.select_next(),
+                DependencyViewAction::SelectPrevious => self.list_state.select_previous(),
+                DependencyViewAction::DeleteSelected =>  {
+                    if let Some(index) = self.selected_dependency() {
                         return Some(Intent::DeleteSelectedDependency { index });
                     };
                 }
-                _ => ()
+                DependencyViewAction::ExportLicenses => return Some(Intent::ExportLicenseReport),
+                DependencyViewAction::ExtractVersion => {
+                    let index = self.selected_dependency()?;
+                    return Some(Intent::ExtractVersionProperty { index });
+                }
+                DependencyViewAction::Explain => {
+                    let (group_id, artifact_id) = self.dependency_ids.get(self.selected_dependency()?)?.clone();
+
+                    return Some(Intent::ExplainDependency { group_id, artifact_id });
+                }
+                DependencyViewAction::ToggleMark => {
+                    let ids = self.row_ids(self.list_state.selected()?);
+                    if ids.iter().all(|id| self.marked.contains(id)) {
+                        ids.iter().for_each(|id| { self.marked.remove(id); });
+                    } else {
+                        self.marked.extend(ids);
+                    }
+                    self.list_state.select_next();
+                }
+                DependencyViewAction::ToggleVisual => match self.visual_anchor {
+                    Some(_) => self.commit_visual(),
+                    None => self.visual_anchor = self.list_state.selected(),
+                },
+                DependencyViewAction::ClearMarks => {
+                    self.marked.clear();
+                    self.visual_anchor = None;
+                    self.filter.clear();
+                }
+                DependencyViewAction::BatchDelete => return self.plan_batch(BatchOperation::Delete),
+                DependencyViewAction::BatchUpgrade(level) => return self.plan_batch(BatchOperation::Upgrade(level)),
+                DependencyViewAction::BatchScope => {
+                    self.prompt.open(Edit::Scope, "");
+                    self.scope_error = None;
+
+                    return Some(Intent::EnterInputMode);
+                }
+                DependencyViewAction::Filter => {
+                    self.prompt.open(Edit::Filter, &self.filter);
+                    self.visual_anchor = None;
+
+                    return Some(Intent::EnterInputMode);
+                }
+                DependencyViewAction::CycleSort => {
+                    self.sort = self.sort.next();
+                    if self.sort == SortMode::Outdatedness {
+                        return Some(Intent::CheckDependencyUpdates);
+                    }
+                }
+                DependencyViewAction::ToggleGrouping => {
+                    self.grouped = !self.grouped;
+                    self.visual_anchor = None;
+                    self.list_state.select_first();
+                }
+                DependencyViewAction::ToggleGroup => {
+                    if let Some(Row::Group { group_id, .. }) = self.rows.get(self.list_state.selected()?) {
+                        let group_id = group_id.clone();
+                        if !self.collapsed.remove(&group_id) {
+                            self.collapsed.insert(group_id);
+                        }
+                    }
+                }
+                DependencyViewAction::Edit => {
+                    let index = self.selected_dependency()?;
+                    return Some(Intent::EditDependency { index });
+                }
+                DependencyViewAction::Undo => return Some(Intent::UndoDependencyEdit),
+                DependencyViewAction::MoveUp => return self.move_selected(false),
+                DependencyViewAction::MoveDown => return self.move_selected(true),
+                DependencyViewAction::SortDeclarations => {
+                    let order = match self.sort {
+                        SortMode::Alphabetical => DeclarationOrder::Alphabetical,
+                        SortMode::Scope => DeclarationOrder::Scope,
+                        SortMode::Declaration | SortMode::Outdatedness => return None,
+                    };
+                    self.sort = SortMode::Declaration;
+
+                    return Some(Intent::SortDependencies(order));
+                }
+                DependencyViewAction::StopEditing => {
+                    if self.prompt.editing() == Some(&Edit::Filter) {
+                        self.filter.clear();
+                    }
+                    self.prompt.close();
+                    self.scope_error = None;
+
+                    return Some(Intent::LeaveInputMode);
+                }
+                DependencyViewAction::Submit => match self.prompt.editing() {
+                    Some(Edit::Filter) => {
+                        self.filter = self.prompt.value().trim().to_string();
+                        self.prompt.close();
+
+                        return Some(Intent::LeaveInputMode);
+                    }
+                    _ => return self.submit_scope(),
+                },
             };
 
             return None;
 
         }
 
+        self.edit_prompt(event);
+
         return None;
     }
+
+    fn keybindings(&self) -> Vec<KeyBinding> {
+        let mut bindings = KeyBinding::from_mapping(&self.get_mapping(), DependencyViewAction::description);
+        bindings.extend(self.prompt.keybindings());
+
+        return bindings;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crossterm::event::KeyEvent;
+
+    fn data(coordinates: &[&str]) -> Data {
+        let dependencies = coordinates
+            .iter()
+            .map(|coordinates| {
+                let parts: Vec<&str> = coordinates.split(':').collect();
+                JavaDependency { group_id: parts[0].to_string(), artifact_id: parts[1].to_string(), version: parts[2].to_string() }
+            })
+            .collect();
+
+        return Data { dependencies, ..Default::default() };
+    }
+
+    fn render(view: &mut DependencyView, state: &Data) {
+        let area = Rect::new(0, 0, 80, 20);
+        view.render(&mut Buffer::empty(area), area, state);
+    }
+
+    /// Index of the dependency `d` deletes with the given row selected.
+    fn deleted_at(view: &mut DependencyView, row: usize) -> Option<usize> {
+        view.list_state.select(Some(row));
+
+        match view.handle_event(&Event::Key(KeyEvent::from(KeyCode::Char('d')))) {
+            Some(Intent::DeleteSelectedDependency { index }) => return Some(index),
+            _ => return None,
+        }
+    }
+
+    #[test]
+    fn filtered_and_sorted_rows_resolve_to_their_dependency() {
+        let state = data(&["org.slf4j:slf4j-api:2.0.9", "com.google.guava:guava:33.0.0-jre", "org.junit:junit-bom:5.10.0"]);
+        let mut view = DependencyView::new();
+
+        view.filter = "org".to_string();
+        view.sort = SortMode::Alphabetical;
+        render(&mut view, &state);
+
+        assert_eq!(view.rows, vec![Row::Dependency(2), Row::Dependency(0)]);
+        assert_eq!(deleted_at(&mut view, 0), Some(2));
+        assert_eq!(deleted_at(&mut view, 1), Some(0));
+    }
+
+    #[test]
+    fn grouped_rows_resolve_to_their_dependency() {
+        let state = data(&["org.example:b:1.0", "com.example:c:1.0", "org.example:a:1.0"]);
+        let mut view = DependencyView::new();
+
+        view.grouped = true;
+        render(&mut view, &state);
+
+        assert_eq!(
+            view.rows,
+            vec![
+                Row::Group { group_id: "org.example".to_string(), count: 2 },
+                Row::Dependency(0),
+                Row::Dependency(2),
+                Row::Group { group_id: "com.example".to_string(), count: 1 },
+                Row::Dependency(1),
+            ]
+        );
+        assert_eq!(deleted_at(&mut view, 0), None);
+        assert_eq!(deleted_at(&mut view, 2), Some(2));
+
+        view.collapsed.insert("org.example".to_string());
+        render(&mut view, &state);
+
+        assert_eq!(view.rows.len(), 3);
+        assert_eq!(deleted_at(&mut view, 2), Some(1));
+    }
 }
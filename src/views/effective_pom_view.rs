@@ -0,0 +1,168 @@
+use ratatui::{
+    buffer::Buffer,
+    prelude::{Color, Modifier, Rect, Style, style::palette::tailwind::SLATE},
+    text::{Line, Span},
+    widgets::{Block, Paragraph, Widget},
+};
+use crate::{
+    views::View,
+    app::Data,
+    effective_pom::{EffectiveElement, EffectivePomState},
+    events::{Intent, KeyBinding},
+};
+
+use crossterm::event::{Event, KeyCode};
+use std::collections::HashMap;
+
+const TAG_STYLE: Style = Style::new().fg(Color::Cyan);
+const TEXT_STYLE: Style = Style::new().fg(SLATE.c100);
+const SOURCE_STYLE: Style = Style::new().fg(SLATE.c500).add_modifier(Modifier::ITALIC);
+const ERROR_STYLE: Style = Style::new().fg(Color::Red);
+
+const INDENT: &str = "  ";
+
+#[derive(Debug, Clone, Copy)]
+enum EffectivePomViewAction {
+    ScrollDown,
+    ScrollUp,
+    PageDown,
+    PageUp,
+    Top,
+    Bottom,
+    Build,
+}
+
+impl EffectivePomViewAction {
+    fn description(&self) -> &'static str {
+        match self {
+            EffectivePomViewAction::ScrollDown => "Scroll down",
+            EffectivePomViewAction::ScrollUp => "Scroll up",
+            EffectivePomViewAction::PageDown => "Scroll down one page",
+            EffectivePomViewAction::PageUp => "Scroll up one page",
+            EffectivePomViewAction::Top => "Go to top",
+            EffectivePomViewAction::Bottom => "Go to bottom",
+            EffectivePomViewAction::Build => "Compute effective POM again",
+        }
+    }
+}
+
+pub struct EffectivePomView {
+    scroll: u16,
+    /// Number of lines and visible height of the last render, bounding the scroll.
+    line_count: u16,
+    page_height: u16,
+}
+
+impl EffectivePomView {
+    pub fn new() -> Self {
+        Self {
+            scroll: 0,
+            line_count: 0,
+            page_height: 0,
+        }
+    }
+
+    fn get_mapping(&self) -> HashMap<KeyCode, EffectivePomViewAction> {
+        return HashMap::from([
+            (KeyCode::Char('j'), EffectivePomViewAction::ScrollDown),
+            (KeyCode::Char('k'), EffectivePomViewAction::ScrollUp),
+            (KeyCode::PageDown, EffectivePomViewAction::PageDown),
+            (KeyCode::PageUp, EffectivePomViewAction::PageUp),
+            (KeyCode::Char('g'), EffectivePomViewAction::Top),
+            (KeyCode::Char('G'), EffectivePomViewAction::Bottom),
+            (KeyCode::Char('r'), EffectivePomViewAction::Build),
+        ]);
+    }
+
+    /// XML lines of `element`, annotated with its source where it differs
+    /// from the source of the enclosing element.
+    fn element_lines(element: &EffectiveElement, depth: usize, enclosing_source: Option<&str>, lines: &mut Vec<Line<'static>>) {
+        let indent = INDENT.repeat(depth);
+
+        let mut spans = vec![Span::raw(indent.clone())];
+        match (element.children.is_empty(), element.text.is_empty()) {
+            (true, true) => spans.push(Span::styled(format!("<{}/>", element.name), TAG_STYLE)),
+            (true, false) => {
+                spans.push(Span::styled(format!("<{}>", element.name), TAG_STYLE));
+                spans.push(Span::styled(element.text.clone(), TEXT_STYLE));
+                spans.push(Span::styled(format!("</{}>", element.name), TAG_STYLE));
+            }
+            (false, _) => spans.push(Span::styled(format!("<{}>", element.name), TAG_STYLE)),
+        }
+
+        if enclosing_source != Some(element.source.as_str()) {
+            spans.push(Span::styled(format!("  <!-- {} -->", element.source), SOURCE_STYLE));
+        }
+
+        lines.push(Line::from(spans));
+
+        if element.children.is_empty() {
+            return;
+        }
+
+        for child in &element.children {
+            Self::element_lines(child, depth + 1, Some(&element.source), lines);
+        }
+
+        lines.push(Line::from(vec![Span::raw(indent), Span::styled(format!("</{}>", element.name), TAG_STYLE)]));
+    }
+
+    fn scroll_by(&mut self, lines: i32) {
+        let max = self.line_count.saturating_sub(self.page_height);
+        self.scroll = (self.scroll as i32 + lines).clamp(0, max as i32) as u16;
+    }
+}
+
+impl View for EffectivePomView {
+
+    fn render(&mut self, buffer: &mut Buffer, area: Rect, state: &Data) {
+        let block = Block::new().title(Line::raw("Effective POM").centered());
+
+        let effective = match &state.effective_pom {
+            EffectivePomState::Built(effective) => effective,
+            other => {
+                let message = match other {
+                    EffectivePomState::Building => Line::raw("Computing effective POM..."),
+                    EffectivePomState::Failed(error) => Line::styled(format!("Effective POM failed: {}", error), ERROR_STYLE),
+                    _ => Line::raw("Press r to compute the effective POM"),
+                };
+
+                Paragraph::new(message).block(block).render(area, buffer);
+                return;
+            }
+        };
+
+        let mut lines = vec![];
+        Self::element_lines(effective, 0, None, &mut lines);
+
+        let inner = block.inner(area);
+        self.line_count = lines.len() as u16;
+        self.page_height = inner.height;
+        self.scroll_by(0);
+
+        Paragraph::new(lines).block(block).scroll((self.scroll, 0)).render(area, buffer);
+    }
+
+    fn handle_event(&mut self, event: &Event) -> Option<Intent> {
+        if let Event::Key(key_event) = event {
+            let action = self.get_mapping().get(&key_event.code).copied()?;
+            let page = self.page_height.max(1) as i32;
+
+            match action {
+                EffectivePomViewAction::ScrollDown => self.scroll_by(1),
+                EffectivePomViewAction::ScrollUp => self.scroll_by(-1),
+                EffectivePomViewAction::PageDown => self.scroll_by(page),
+                EffectivePomViewAction::PageUp => self.scroll_by(-page),
+                EffectivePomViewAction::Top => self.scroll = 0,
+                EffectivePomViewAction::Bottom => self.scroll_by(self.line_count as i32),
+                EffectivePomViewAction::Build => return Some(Intent::BuildEffectivePom),
+            };
+        }
+
+        return None;
+    }
+
+    fn keybindings(&self) -> Vec<KeyBinding> {
+        return KeyBinding::from_mapping(&self.get_mapping(), EffectivePomViewAction::description);
+    }
+}
@@ -0,0 +1,303 @@
+use ratatui::{
+    buffer::Buffer,
+    prelude::{Color, Modifier, Rect, Style, style::palette::tailwind::SLATE},
+    style::Stylize,
+    text::{Line, Span},
+    widgets::{Block, HighlightSpacing, List, ListItem, ListState, StatefulWidget},
+};
+use crate::{
+    views::View,
+    app::Data,
+    dependency::parse_coordinates,
+    pom::PomDependency,
+    ui::alternate_colors,
+    events::{Intent, KeyBinding},
+    widgets::prompt::Prompt,
+};
+
+use crossterm::event::{Event, KeyCode};
+use std::collections::{HashMap, HashSet};
+
+const SELECTED_STYLE: Style = Style::new().bg(SLATE.c800).add_modifier(Modifier::BOLD);
+const IMPORT_STYLE: Style = Style::new().fg(Color::Cyan);
+const GOVERNS_STYLE: Style = Style::new().fg(SLATE.c400);
+const OVERRIDDEN_STYLE: Style = Style::new().fg(Color::Yellow);
+const ERROR_STYLE: Style = Style::new().fg(Color::Red);
+
+#[derive(Debug, Clone, Copy)]
+enum ManagedDependenciesViewAction {
+    SelectNext,
+    SelectPrevious,
+    AddEntry,
+    AddImport,
+    EditVersion,
+    Remove,
+    StopEditing,
+    Submit,
+}
+
+impl ManagedDependenciesViewAction {
+    fn description(&self) -> &'static str {
+        match self {
+            ManagedDependenciesViewAction::SelectNext => "Select next entry",
+            ManagedDependenciesViewAction::SelectPrevious => "Select previous entry",
+            ManagedDependenciesViewAction::AddEntry => "Add managed dependency",
+            ManagedDependenciesViewAction::AddImport => "Import BOM",
+            ManagedDependenciesViewAction::EditVersion => "Change version of selected entry",
+            ManagedDependenciesViewAction::Remove => "Remove selected entry",
+            ManagedDependenciesViewAction::StopEditing => "Cancel editing",
+            ManagedDependenciesViewAction::Submit => "Apply",
+        }
+    }
+}
+
+/// What the text input is currently used for.
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum Edit {
+    NewEntry,
+    NewImport,
+    Version { index: usize },
+}
+
+impl Edit {
+    fn prompt(&self) -> &'static str {
+        match self {
+            Edit::NewEntry => "New managed dependency (groupId:artifactId:version)",
+            Edit::NewImport => "Import BOM (groupId:artifactId:version)",
+            Edit::Version { .. } => "Version",
+        }
+    }
+}
+
+/// Direct dependencies an entry of `<dependencyManagement>` applies to.
+#[derive(Default)]
+struct Governed {
+    /// Declared without a version, so the managed one is used.
+    versions: Vec<String>,
+    /// Declared with their own version, which wins over the managed one.
+    overridden: Vec<String>,
+}
+
+pub struct ManagedDependenciesView {
+    list_state: ListState,
+    prompt: Prompt<Edit>,
+    /// Versions of the listed entries from the last render, used to prefill edits.
+    versions: Vec<String>,
+}
+
+impl ManagedDependenciesView {
+    pub fn new() -> Self {
+        Self {
+            list_state: Default::default(),
+            prompt: Prompt::new(),
+            versions: vec![],
+        }
+    }
+
+    fn get_mapping(&self) -> HashMap<KeyCode, ManagedDependenciesViewAction> {
+        if self.prompt.is_open() {
+            return HashMap::from([
+                (KeyCode::Esc, ManagedDependenciesViewAction::StopEditing),
+                (KeyCode::Enter, ManagedDependenciesViewAction::Submit),
+            ]);
+        }
+
+        return HashMap::from([
+            (KeyCode::Char('j'), ManagedDependenciesViewAction::SelectNext),
+            (KeyCode::Char('k'), ManagedDependenciesViewAction::SelectPrevious),
+            (KeyCode::Char('n'), ManagedDependenciesViewAction::AddEntry),
+            (KeyCode::Char('b'), ManagedDependenciesViewAction::AddImport),
+            (KeyCode::Char('v'), ManagedDependenciesViewAction::EditVersion),
+            (KeyCode::Char('d'), ManagedDependenciesViewAction::Remove),
+        ]);
+    }
+
+    /// Works out which direct dependencies each entry governs. Entries
+    /// declared in the project win over imported BOMs, and earlier imports
+    /// win over later ones.
+    fn governed(managed: &[PomDependency], state: &Data) -> Vec<Governed> {
+        let explicit: HashSet<String> = managed
+            .iter()
+            .filter(|entry| !entry.is_bom_import())
+            .map(PomDependency::id)
+            .collect();
+        let mut claimed: HashSet<String> = HashSet::new();
+
+        return managed
+            .iter()
+            .map(|entry| {
+                let covers = |id: &String| -> bool {
+                    if !entry.is_bom_import() {
+                        return *id == entry.id();
+                    }
+
+                    let in_bom = match state.bom_contents.get(&entry.id()) {
+                        Some(Ok(ids)) => ids.contains(id),
+                        _ => false,
+                    };
+
+                    return in_bom && !explicit.contains(id) && !claimed.contains(id);
+                };
+
+                let mut governed = Governed::default();
+
+                for dependency in &state.dependencies {
+                    let id = format!("{}:{}", dependency.group_id, dependency.artifact_id);
+                    if !covers(&id) {
+                        continue;
+                    }
+
+                    match dependency.version.is_empty() {
+                        true => governed.versions.push(dependency.artifact_id.clone()),
+                        false => governed.overridden.push(dependency.artifact_id.clone()),
+                    }
+                }
+
+                if entry.is_bom_import()
+                    && let Some(Ok(ids)) = state.bom_contents.get(&entry.id())
+                {
+                    claimed.extend(ids.iter().cloned());
+                }
+
+                governed
+            })
+            .collect();
+    }
+
+    fn render_row(entry: &PomDependency, governed: &Governed, state: &Data) -> Line<'static> {
+        let mut spans = vec![Span::raw(format!("{} {}", entry.id(), entry.version))];
+
+        if entry.is_bom_import() {
+            spans.push(Span::styled(" [BOM]", IMPORT_STYLE));
+
+            match state.bom_contents.get(&entry.id()) {
+                Some(Ok(_)) => (),
+                Some(Err(error)) => spans.push(Span::styled(format!(" (cannot load: {})", error), ERROR_STYLE)),
+                None => spans.push(Span::styled(" (loading…)", GOVERNS_STYLE)),
+            }
+        } else if !entry.scope.is_empty() {
+            spans.push(Span::styled(format!(" [{}]", entry.scope), GOVERNS_STYLE));
+        }
+
+        if !governed.versions.is_empty() {
+            spans.push(Span::styled(format!(" governs {}", governed.versions.join(", ")), GOVERNS_STYLE));
+        }
+        if !governed.overridden.is_empty() {
+            spans.push(Span::styled(format!(" overridden by {}", governed.overridden.join(", ")), OVERRIDDEN_STYLE));
+        }
+        if governed.versions.is_empty() && governed.overridden.is_empty() {
+            spans.push(Span::styled(" no direct dependencies", GOVERNS_STYLE));
+        }
+
+        return Line::from(spans);
+    }
+
+    fn start_editing(&mut self, edit: Edit) -> Option<Intent> {
+        let value = match edit {
+            Edit::Version { index } => self.versions.get(index)?.clone(),
+            Edit::NewEntry | Edit::NewImport => String::new(),
+        };
+
+        self.prompt.open(edit, &value);
+
+        return Some(Intent::EnterInputMode);
+    }
+
+    fn submit(&mut self) -> Option<Intent> {
+        let value = self.prompt.value().trim().to_string();
+
+        let intent = match *self.prompt.editing()? {
+            Edit::Version { index } if !value.is_empty() => Intent::SetManagedVersion { index, version: value },
+            edit @ (Edit::NewEntry | Edit::NewImport) => {
+                let (dependency, _) = parse_coordinates(&value)?;
+                if dependency.version.is_empty() {
+                    return None;
+                }
+
+                Intent::AddManagedDependency {
+                    group_id: dependency.group_id,
+                    artifact_id: dependency.artifact_id,
+                    version: dependency.version,
+                    import: edit == Edit::NewImport,
+                }
+            }
+            _ => return None,
+        };
+
+        self.prompt.close();
+
+        return Some(intent);
+    }
+}
+
+impl View for ManagedDependenciesView {
+
+    fn render(&mut self, buffer: &mut Buffer, area: Rect, state: &Data) {
+        let block = Block::new().title(Line::raw("Dependency Management").centered());
+
+        let managed = state.maven_file.get_managed_dependencies();
+        let governed = Self::governed(&managed, state);
+        self.versions = managed.iter().map(|entry| entry.version.clone()).collect();
+
+        let title = self.prompt.editing().map(Edit::prompt).unwrap_or_default();
+        let list_area = self.prompt.render(area, buffer, title);
+
+        let items: Vec<ListItem> = managed
+            .iter()
+            .zip(governed.iter())
+            .enumerate()
+            .map(|(i, (entry, governed))| {
+                let color = alternate_colors(i);
+                ListItem::new(Self::render_row(entry, governed, state)).bg(color)
+            })
+            .collect();
+
+        let list = List::new(items)
+            .block(block)
+            .highlight_style(SELECTED_STYLE)
+            .highlight_symbol(">")
+            .highlight_spacing(HighlightSpacing::Always);
+
+        StatefulWidget::render(list, list_area, buffer, &mut self.list_state);
+    }
+
+    fn handle_event(&mut self, event: &Event) -> Option<Intent> {
+        if let Event::Key(key_event) = event {
+            let Some(action) = self.get_mapping().get(&key_event.code).copied() else {
+                self.prompt.handle_event(event);
+                return None;
+            };
+
+            match action {
+                ManagedDependenciesViewAction::SelectNext => self.list_state.select_next(),
+                ManagedDependenciesViewAction::SelectPrevious => self.list_state.select_previous(),
+                ManagedDependenciesViewAction::AddEntry => return self.start_editing(Edit::NewEntry),
+                ManagedDependenciesViewAction::AddImport => return self.start_editing(Edit::NewImport),
+                ManagedDependenciesViewAction::EditVersion => {
+                    let index = self.list_state.selected()?;
+                    return self.start_editing(Edit::Version { index });
+                }
+                ManagedDependenciesViewAction::Remove => {
+                    let index = self.list_state.selected()?;
+                    return Some(Intent::RemoveManagedDependency { index });
+                }
+                ManagedDependenciesViewAction::StopEditing => {
+                    self.prompt.close();
+                    return Some(Intent::LeaveInputMode);
+                }
+                ManagedDependenciesViewAction::Submit => return self.submit(),
+            };
+        }
+
+        self.prompt.handle_event(event);
+
+        return None;
+    }
+
+    fn keybindings(&self) -> Vec<KeyBinding> {
+        let mut bindings = KeyBinding::from_mapping(&self.get_mapping(), ManagedDependenciesViewAction::description);
+        bindings.extend(self.prompt.keybindings());
+
+        return bindings;
+    }
+}
@@ -1,16 +1,84 @@
+pub mod conflicts_view;
 pub mod dependency_search_view;
+pub mod dependency_tree_view;
 pub mod dependency_view;
+pub mod effective_pom_view;
+pub mod managed_dependencies_view;
+pub mod plugins_view;
+pub mod profiles_view;
+pub mod project_info_view;
+pub mod properties_view;
 
-use ratatui::{buffer::Buffer, layout::Rect, prelude::Widget};
+use ratatui::{buffer::Buffer, layout::Rect};
 use crossterm::event::Event;
 
-use crate::app::{Data, UIState};
-use crate::events::Intent;
+use crate::app::Data;
+use crate::events::{Intent, KeyBinding};
 
+#[allow(clippy::enum_variant_names)]
 #[derive(PartialEq, Clone, Debug)]
 pub enum ViewId {
     DependencyView,
+    DependencyTreeView,
+    ConflictsView,
+    ManagedDependenciesView,
+    PluginsView,
+    ProfilesView,
+    PropertiesView,
+    ProjectInfoView,
     DependencySearchView,
+    EffectivePomView,
+}
+
+/// Screen halves views are shown in. Each pane shows one of its views at a time.
+#[derive(PartialEq, Eq, Hash, Clone, Copy, Debug)]
+pub enum Pane {
+    Left,
+    Right,
+}
+
+impl ViewId {
+    pub fn title(&self) -> &'static str {
+        match self {
+            ViewId::DependencyView => "Dependencies",
+            ViewId::DependencyTreeView => "Dependency Tree",
+            ViewId::ConflictsView => "Conflicts",
+            ViewId::ManagedDependenciesView => "Managed",
+            ViewId::PluginsView => "Plugins",
+            ViewId::ProfilesView => "Profiles",
+            ViewId::PropertiesView => "Properties",
+            ViewId::ProjectInfoView => "Project",
+            ViewId::DependencySearchView => "Search Dependencies",
+            ViewId::EffectivePomView => "Effective POM",
+        }
+    }
+
+    pub fn pane(&self) -> Pane {
+        match self {
+            ViewId::DependencyView
+            | ViewId::DependencyTreeView
+            | ViewId::ConflictsView
+            | ViewId::ManagedDependenciesView
+            | ViewId::PluginsView
+            | ViewId::ProfilesView
+            | ViewId::PropertiesView
+            | ViewId::ProjectInfoView => Pane::Left,
+            ViewId::DependencySearchView | ViewId::EffectivePomView => Pane::Right,
+        }
+    }
+
+    /// Views of parts of a POM that Gradle build scripts have no counterpart for.
+    pub fn is_maven_only(&self) -> bool {
+        return matches!(
+            self,
+            ViewId::ManagedDependenciesView
+                | ViewId::PluginsView
+                | ViewId::ProfilesView
+                | ViewId::PropertiesView
+                | ViewId::ProjectInfoView
+                | ViewId::EffectivePomView
+        );
+    }
 }
 
 pub trait View {
@@ -18,4 +86,7 @@ pub trait View {
     fn render(&mut self, buffer: &mut Buffer, area: Rect, state: &Data);
 
     fn handle_event(&mut self, event: &Event) -> Option<Intent>;
+
+    /// Keybindings currently active in this view, used to build the help popup.
+    fn keybindings(&self) -> Vec<KeyBinding>;
 }
@@ -0,0 +1,301 @@
+use ratatui::{
+    buffer::Buffer,
+    prelude::{Color, Modifier, Rect, Style, style::palette::tailwind::SLATE},
+    style::Stylize,
+    text::{Line, Span},
+    widgets::{Block, HighlightSpacing, List, ListItem, ListState, StatefulWidget},
+};
+use crate::{
+    views::View,
+    app::Data,
+    dependency::{DEFAULT_PLUGIN_GROUP_ID, JavaDependency, PluginSection, parse_coordinates},
+    maven_version::MavenVersion,
+    ui::alternate_colors,
+    events::{Intent, KeyBinding},
+    widgets::prompt::Prompt,
+};
+
+use crossterm::event::{Event, KeyCode};
+use std::collections::HashMap;
+
+const SELECTED_STYLE: Style = Style::new().bg(SLATE.c800).add_modifier(Modifier::BOLD);
+const SECTION_STYLE: Style = Style::new().add_modifier(Modifier::UNDERLINED);
+const NOTE_STYLE: Style = Style::new().fg(SLATE.c400);
+const OUTDATED_STYLE: Style = Style::new().fg(Color::Yellow);
+const UP_TO_DATE_STYLE: Style = Style::new().fg(Color::Green);
+const ERROR_STYLE: Style = Style::new().fg(Color::Red);
+
+#[derive(Debug, Clone, Copy)]
+enum PluginsViewAction {
+    SelectNext,
+    SelectPrevious,
+    Add,
+    EditVersion,
+    Upgrade,
+    Remove,
+    CheckUpdates,
+    StopEditing,
+    Submit,
+}
+
+impl PluginsViewAction {
+    fn description(&self) -> &'static str {
+        match self {
+            PluginsViewAction::SelectNext => "Select next plugin",
+            PluginsViewAction::SelectPrevious => "Select previous plugin",
+            PluginsViewAction::Add => "Add plugin to selected section",
+            PluginsViewAction::EditVersion => "Change version of selected plugin",
+            PluginsViewAction::Upgrade => "Upgrade selected plugin to latest version",
+            PluginsViewAction::Remove => "Remove selected plugin",
+            PluginsViewAction::CheckUpdates => "Check for newer versions",
+            PluginsViewAction::StopEditing => "Cancel editing",
+            PluginsViewAction::Submit => "Apply",
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum PluginRow {
+    Section(PluginSection),
+    Plugin { section: PluginSection, index: usize },
+}
+
+impl PluginRow {
+    fn section(&self) -> PluginSection {
+        match self {
+            PluginRow::Section(section) | PluginRow::Plugin { section, .. } => *section,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum Edit {
+    NewPlugin { section: PluginSection },
+    Version { section: PluginSection, index: usize },
+}
+
+impl Edit {
+    fn prompt(&self) -> &'static str {
+        match self {
+            Edit::NewPlugin { .. } => "New plugin ([groupId:]artifactId:version)",
+            Edit::Version { .. } => "Version",
+        }
+    }
+}
+
+pub struct PluginsView {
+    list_state: ListState,
+    prompt: Prompt<Edit>,
+    /// Rows of the last render, with the plugin shown in each of them.
+    rows: Vec<(PluginRow, Option<JavaDependency>)>,
+    /// Newer versions found in the registry, keyed by row index.
+    upgrades: HashMap<usize, String>,
+}
+
+impl PluginsView {
+    pub fn new() -> Self {
+        Self {
+            list_state: Default::default(),
+            prompt: Prompt::new(),
+            rows: vec![],
+            upgrades: HashMap::new(),
+        }
+    }
+
+    fn get_mapping(&self) -> HashMap<KeyCode, PluginsViewAction> {
+        if self.prompt.is_open() {
+            return HashMap::from([
+                (KeyCode::Esc, PluginsViewAction::StopEditing),
+                (KeyCode::Enter, PluginsViewAction::Submit),
+            ]);
+        }
+
+        return HashMap::from([
+            (KeyCode::Char('j'), PluginsViewAction::SelectNext),
+            (KeyCode::Char('k'), PluginsViewAction::SelectPrevious),
+            (KeyCode::Char('n'), PluginsViewAction::Add),
+            (KeyCode::Char('v'), PluginsViewAction::EditVersion),
+            (KeyCode::Char('u'), PluginsViewAction::Upgrade),
+            (KeyCode::Char('d'), PluginsViewAction::Remove),
+            (KeyCode::Char('c'), PluginsViewAction::CheckUpdates),
+        ]);
+    }
+
+    fn collect_rows(&mut self, state: &Data) {
+        self.rows.clear();
+
+        for section in [PluginSection::Build, PluginSection::Management] {
+            self.rows.push((PluginRow::Section(section), None));
+
+            for (index, plugin) in state.maven_file.get_plugins(section).into_iter().enumerate() {
+                self.rows.push((PluginRow::Plugin { section, index }, Some(plugin)));
+            }
+        }
+    }
+
+    /// Version information shown after the plugin, remembering upgrades for `u`.
+    fn version_spans(&mut self, row: usize, plugin: &JavaDependency, state: &Data) -> Vec<Span<'static>> {
+        if plugin.version.is_empty() {
+            return vec![Span::styled(" (version managed)", NOTE_STYLE)];
+        }
+
+        let key = format!("{}:{}", plugin.group_id, plugin.artifact_id);
+        let latest = match state.latest_versions.get(&key) {
+            Some(Ok(latest)) => latest,
+            Some(Err(error)) => return vec![Span::styled(format!(" (lookup failed: {})", error), ERROR_STYLE)],
+            None => return vec![],
+        };
+
+        if plugin.version.contains("${") {
+            return vec![Span::styled(format!(" (latest {})", latest), NOTE_STYLE)];
+        }
+
+        if MavenVersion::parse(latest) > MavenVersion::parse(&plugin.version) {
+            self.upgrades.insert(row, latest.clone());
+            return vec![Span::styled(format!(" → {}", latest), OUTDATED_STYLE)];
+        }
+
+        return vec![Span::styled(" ✓", UP_TO_DATE_STYLE)];
+    }
+
+    fn selected_row(&self) -> Option<&(PluginRow, Option<JavaDependency>)> {
+        return self.rows.get(self.list_state.selected()?);
+    }
+
+    fn start_editing(&mut self, edit: Edit, value: &str) -> Option<Intent> {
+        self.prompt.open(edit, value);
+
+        return Some(Intent::EnterInputMode);
+    }
+
+    fn submit(&mut self) -> Option<Intent> {
+        let value = self.prompt.value().trim().to_string();
+
+        let intent = match *self.prompt.editing()? {
+            Edit::Version { section, index } if !value.is_empty() => {
+                Intent::SetPluginVersion { section, index, version: value }
+            }
+            Edit::NewPlugin { section } => {
+                // Plugins of the default group are usually given without it.
+                let notation = match value.matches(':').count() {
+                    1 => format!("{}:{}", DEFAULT_PLUGIN_GROUP_ID, value),
+                    _ => value,
+                };
+                let (plugin, _) = parse_coordinates(&notation)?;
+                if plugin.version.is_empty() {
+                    return None;
+                }
+
+                Intent::AddPlugin {
+                    section,
+                    group_id: plugin.group_id,
+                    artifact_id: plugin.artifact_id,
+                    version: plugin.version,
+                }
+            }
+            _ => return None,
+        };
+
+        self.prompt.close();
+
+        return Some(intent);
+    }
+}
+
+impl View for PluginsView {
+
+    fn render(&mut self, buffer: &mut Buffer, area: Rect, state: &Data) {
+        let block = Block::new().title(Line::raw("Build Plugins").centered());
+
+        self.collect_rows(state);
+        self.upgrades.clear();
+
+        let title = self.prompt.editing().map(Edit::prompt).unwrap_or_default();
+        let list_area = self.prompt.render(area, buffer, title);
+
+        let rows = self.rows.clone();
+        let items: Vec<ListItem> = rows
+            .iter()
+            .enumerate()
+            .map(|(i, (row, plugin))| {
+                let line = match (row, plugin) {
+                    (PluginRow::Section(PluginSection::Build), _) => Line::styled("Plugins", SECTION_STYLE),
+                    (PluginRow::Section(PluginSection::Management), _) => Line::styled("Plugin Management", SECTION_STYLE),
+                    (PluginRow::Plugin { .. }, Some(plugin)) => {
+                        let mut spans = vec![Span::raw(format!("  {}:{} {}", plugin.group_id, plugin.artifact_id, plugin.version))];
+                        spans.extend(self.version_spans(i, plugin, state));
+                        Line::from(spans)
+                    }
+                    (PluginRow::Plugin { .. }, None) => Line::raw(""),
+                };
+
+                let color = alternate_colors(i);
+                ListItem::new(line).bg(color)
+            })
+            .collect();
+
+        let list = List::new(items)
+            .block(block)
+            .highlight_style(SELECTED_STYLE)
+            .highlight_symbol(">")
+            .highlight_spacing(HighlightSpacing::Always);
+
+        StatefulWidget::render(list, list_area, buffer, &mut self.list_state);
+    }
+
+    fn handle_event(&mut self, event: &Event) -> Option<Intent> {
+        if let Event::Key(key_event) = event {
+            let Some(action) = self.get_mapping().get(&key_event.code).copied() else {
+                self.prompt.handle_event(event);
+                return None;
+            };
+
+            match action {
+                PluginsViewAction::SelectNext => self.list_state.select_next(),
+                PluginsViewAction::SelectPrevious => self.list_state.select_previous(),
+                PluginsViewAction::Add => {
+                    let section = self.selected_row().map(|(row, _)| row.section()).unwrap_or(PluginSection::Build);
+                    return self.start_editing(Edit::NewPlugin { section }, "");
+                }
+                PluginsViewAction::EditVersion => {
+                    let (PluginRow::Plugin { section, index }, Some(plugin)) = self.selected_row()?.clone() else {
+                        return None;
+                    };
+                    return self.start_editing(Edit::Version { section, index }, &plugin.version);
+                }
+                PluginsViewAction::Upgrade => {
+                    let selected = self.list_state.selected()?;
+                    let (PluginRow::Plugin { section, index }, _) = self.rows.get(selected)? else {
+                        return None;
+                    };
+                    let version = self.upgrades.get(&selected)?.clone();
+
+                    return Some(Intent::SetPluginVersion { section: *section, index: *index, version });
+                }
+                PluginsViewAction::Remove => {
+                    let (PluginRow::Plugin { section, index }, _) = self.selected_row()? else {
+                        return None;
+                    };
+                    return Some(Intent::RemovePlugin { section: *section, index: *index });
+                }
+                PluginsViewAction::CheckUpdates => return Some(Intent::CheckPluginUpdates),
+                PluginsViewAction::StopEditing => {
+                    self.prompt.close();
+                    return Some(Intent::LeaveInputMode);
+                }
+                PluginsViewAction::Submit => return self.submit(),
+            };
+        }
+
+        self.prompt.handle_event(event);
+
+        return None;
+    }
+
+    fn keybindings(&self) -> Vec<KeyBinding> {
+        let mut bindings = KeyBinding::from_mapping(&self.get_mapping(), PluginsViewAction::description);
+        bindings.extend(self.prompt.keybindings());
+
+        return bindings;
+    }
+}
@@ -0,0 +1,300 @@
+use ratatui::{
+    buffer::Buffer,
+    prelude::{Color, Modifier, Rect, Style, style::palette::tailwind::SLATE},
+    style::Stylize,
+    text::{Line, Span},
+    widgets::{Block, HighlightSpacing, List, ListItem, ListState, StatefulWidget},
+};
+use crate::{
+    views::View,
+    app::Data,
+    dependency::parse_coordinates,
+    pom::PomDependency,
+    profile::{self, Activation, Profile, ProfileStatus},
+    ui::alternate_colors,
+    events::{Intent, KeyBinding},
+    widgets::prompt::Prompt,
+};
+
+use crossterm::event::{Event, KeyCode};
+use std::collections::HashMap;
+
+const SELECTED_STYLE: Style = Style::new().bg(SLATE.c800).add_modifier(Modifier::BOLD);
+const PROFILE_STYLE: Style = Style::new().add_modifier(Modifier::BOLD);
+const ACTIVE_STYLE: Style = Style::new().fg(Color::Green);
+const INACTIVE_STYLE: Style = Style::new().fg(SLATE.c400);
+const NOTE_STYLE: Style = Style::new().fg(SLATE.c400);
+
+#[derive(Debug, Clone, Copy)]
+enum ProfilesViewAction {
+    SelectNext,
+    SelectPrevious,
+    Add,
+    EditVersion,
+    Remove,
+    StopEditing,
+    Submit,
+}
+
+impl ProfilesViewAction {
+    fn description(&self) -> &'static str {
+        match self {
+            ProfilesViewAction::SelectNext => "Select next row",
+            ProfilesViewAction::SelectPrevious => "Select previous row",
+            ProfilesViewAction::Add => "Add dependency to selected profile",
+            ProfilesViewAction::EditVersion => "Change version of selected dependency",
+            ProfilesViewAction::Remove => "Remove selected dependency",
+            ProfilesViewAction::StopEditing => "Cancel editing",
+            ProfilesViewAction::Submit => "Apply",
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum ProfileRow {
+    Profile { profile: usize },
+    Dependency { profile: usize, index: usize },
+}
+
+impl ProfileRow {
+    fn profile(&self) -> usize {
+        match self {
+            ProfileRow::Profile { profile } | ProfileRow::Dependency { profile, .. } => *profile,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum Edit {
+    NewDependency { profile: usize },
+    Version { profile: usize, index: usize },
+}
+
+impl Edit {
+    fn prompt(&self) -> &'static str {
+        match self {
+            Edit::NewDependency { .. } => "New dependency (groupId:artifactId[:version])",
+            Edit::Version { .. } => "Version",
+        }
+    }
+}
+
+pub struct ProfilesView {
+    list_state: ListState,
+    prompt: Prompt<Edit>,
+    /// Rows of the last render, with the version of each dependency row.
+    rows: Vec<(ProfileRow, String)>,
+}
+
+impl ProfilesView {
+    pub fn new() -> Self {
+        Self {
+            list_state: Default::default(),
+            prompt: Prompt::new(),
+            rows: vec![],
+        }
+    }
+
+    fn get_mapping(&self) -> HashMap<KeyCode, ProfilesViewAction> {
+        if self.prompt.is_open() {
+            return HashMap::from([
+                (KeyCode::Esc, ProfilesViewAction::StopEditing),
+                (KeyCode::Enter, ProfilesViewAction::Submit),
+            ]);
+        }
+
+        return HashMap::from([
+            (KeyCode::Char('j'), ProfilesViewAction::SelectNext),
+            (KeyCode::Char('k'), ProfilesViewAction::SelectPrevious),
+            (KeyCode::Char('n'), ProfilesViewAction::Add),
+            (KeyCode::Char('v'), ProfilesViewAction::EditVersion),
+            (KeyCode::Char('d'), ProfilesViewAction::Remove),
+        ]);
+    }
+
+    /// Short description of the activation conditions, as declared.
+    fn conditions(activation: &Activation) -> Vec<String> {
+        let mut conditions = vec![];
+
+        if activation.active_by_default {
+            conditions.push("activeByDefault".to_string());
+        }
+        if !activation.jdk.is_empty() {
+            conditions.push(format!("jdk {}", activation.jdk));
+        }
+        if let Some(os) = &activation.os {
+            let fields = [("name", &os.name), ("family", &os.family), ("arch", &os.arch), ("version", &os.version)];
+            let declared: Vec<String> = fields
+                .iter()
+                .filter(|(_, value)| !value.is_empty())
+                .map(|(field, value)| format!("{}={}", field, value))
+                .collect();
+            conditions.push(format!("os {}", declared.join(" ")));
+        }
+        if let Some(property) = &activation.property {
+            match property.value.is_empty() {
+                true => conditions.push(format!("property {}", property.name)),
+                false => conditions.push(format!("property {}={}", property.name, property.value)),
+            }
+        }
+        if let Some(file) = &activation.file {
+            match file.exists.is_empty() {
+                true => conditions.push(format!("file missing {}", file.missing)),
+                false => conditions.push(format!("file exists {}", file.exists)),
+            }
+        }
+
+        return conditions;
+    }
+
+    fn profile_line(profile: &Profile, status: ProfileStatus) -> Line<'static> {
+        let status_style = if status.is_active() { ACTIVE_STYLE } else { INACTIVE_STYLE };
+        let mut spans = vec![
+            Span::styled(profile.id.clone(), PROFILE_STYLE),
+            Span::styled(format!(" [{}]", status.label()), status_style),
+        ];
+
+        let conditions = Self::conditions(&profile.activation);
+        if !conditions.is_empty() {
+            spans.push(Span::styled(format!(" {}", conditions.join(", ")), NOTE_STYLE));
+        }
+
+        if profile.dependencies.is_empty() {
+            spans.push(Span::styled(" no dependencies", NOTE_STYLE));
+        }
+
+        return Line::from(spans);
+    }
+
+    fn dependency_line(dependency: &PomDependency) -> Line<'static> {
+        let mut spans = vec![Span::raw(format!("  {} {}", dependency.id(), dependency.version))];
+
+        if !dependency.scope.is_empty() {
+            spans.push(Span::styled(format!(" [{}]", dependency.scope), NOTE_STYLE));
+        }
+
+        return Line::from(spans);
+    }
+
+    fn selected_row(&self) -> Option<&(ProfileRow, String)> {
+        return self.rows.get(self.list_state.selected()?);
+    }
+
+    fn start_editing(&mut self, edit: Edit, value: &str) -> Option<Intent> {
+        self.prompt.open(edit, value);
+
+        return Some(Intent::EnterInputMode);
+    }
+
+    fn submit(&mut self) -> Option<Intent> {
+        let value = self.prompt.value().trim().to_string();
+
+        let intent = match *self.prompt.editing()? {
+            Edit::Version { profile, index } if !value.is_empty() => {
+                Intent::SetProfileDependencyVersion { profile, index, version: value }
+            }
+            Edit::NewDependency { profile } => {
+                let (dependency, _) = parse_coordinates(&value)?;
+
+                Intent::AddProfileDependency {
+                    profile,
+                    group_id: dependency.group_id,
+                    artifact_id: dependency.artifact_id,
+                    version: dependency.version,
+                }
+            }
+            _ => return None,
+        };
+
+        self.prompt.close();
+
+        return Some(intent);
+    }
+}
+
+impl View for ProfilesView {
+
+    fn render(&mut self, buffer: &mut Buffer, area: Rect, state: &Data) {
+        let block = Block::new().title(Line::raw("Profiles").centered());
+
+        let profiles = state.maven_file.get_profiles();
+        let statuses = profile::evaluate(&profiles, &state.activation_context);
+
+        let title = self.prompt.editing().map(Edit::prompt).unwrap_or_default();
+        let list_area = self.prompt.render(area, buffer, title);
+
+        self.rows.clear();
+        let mut lines: Vec<Line> = vec![];
+
+        for (index, (profile, status)) in profiles.iter().zip(statuses).enumerate() {
+            self.rows.push((ProfileRow::Profile { profile: index }, String::new()));
+            lines.push(Self::profile_line(profile, status));
+
+            for (dependency_index, dependency) in profile.dependencies.iter().enumerate() {
+                let row = ProfileRow::Dependency { profile: index, index: dependency_index };
+                self.rows.push((row, dependency.version.clone()));
+                lines.push(Self::dependency_line(dependency));
+            }
+        }
+
+        let items: Vec<ListItem> = lines
+            .into_iter()
+            .enumerate()
+            .map(|(i, line)| ListItem::new(line).bg(alternate_colors(i)))
+            .collect();
+
+        let list = List::new(items)
+            .block(block)
+            .highlight_style(SELECTED_STYLE)
+            .highlight_symbol(">")
+            .highlight_spacing(HighlightSpacing::Always);
+
+        StatefulWidget::render(list, list_area, buffer, &mut self.list_state);
+    }
+
+    fn handle_event(&mut self, event: &Event) -> Option<Intent> {
+        if let Event::Key(key_event) = event {
+            let Some(action) = self.get_mapping().get(&key_event.code).copied() else {
+                self.prompt.handle_event(event);
+                return None;
+            };
+
+            match action {
+                ProfilesViewAction::SelectNext => self.list_state.select_next(),
+                ProfilesViewAction::SelectPrevious => self.list_state.select_previous(),
+                ProfilesViewAction::Add => {
+                    let profile = self.selected_row()?.0.profile();
+                    return self.start_editing(Edit::NewDependency { profile }, "");
+                }
+                ProfilesViewAction::EditVersion => {
+                    let (ProfileRow::Dependency { profile, index }, version) = self.selected_row()?.clone() else {
+                        return None;
+                    };
+                    return self.start_editing(Edit::Version { profile, index }, &version);
+                }
+                ProfilesViewAction::Remove => {
+                    let (ProfileRow::Dependency { profile, index }, _) = self.selected_row()? else {
+                        return None;
+                    };
+                    return Some(Intent::RemoveProfileDependency { profile: *profile, index: *index });
+                }
+                ProfilesViewAction::StopEditing => {
+                    self.prompt.close();
+                    return Some(Intent::LeaveInputMode);
+                }
+                ProfilesViewAction::Submit => return self.submit(),
+            };
+        }
+
+        self.prompt.handle_event(event);
+
+        return None;
+    }
+
+    fn keybindings(&self) -> Vec<KeyBinding> {
+        let mut bindings = KeyBinding::from_mapping(&self.get_mapping(), ProfilesViewAction::description);
+        bindings.extend(self.prompt.keybindings());
+
+        return bindings;
+    }
+}
@@ -0,0 +1,218 @@
+use ratatui::{
+    buffer::Buffer,
+    prelude::{Color, Modifier, Rect, Style, style::palette::tailwind::SLATE},
+    style::Stylize,
+    text::{Line, Span},
+    widgets::{Block, HighlightSpacing, List, ListItem, ListState, StatefulWidget},
+};
+use crate::{
+    views::View,
+    app::Data,
+    project_info::{Bump, ProjectField, ProjectList},
+    ui::alternate_colors,
+    events::{Intent, KeyBinding},
+    widgets::prompt::Prompt,
+};
+
+use crossterm::event::{Event, KeyCode};
+use std::collections::HashMap;
+
+const SELECTED_STYLE: Style = Style::new().bg(SLATE.c800).add_modifier(Modifier::BOLD);
+const LABEL_STYLE: Style = Style::new().fg(SLATE.c400);
+const EMPTY_STYLE: Style = Style::new().fg(SLATE.c500);
+const ERROR_STYLE: Style = Style::new().fg(Color::Red);
+
+#[derive(Debug, Clone, Copy)]
+enum ProjectInfoViewAction {
+    SelectNext,
+    SelectPrevious,
+    Edit,
+    Bump(Bump),
+    AddLicense,
+    AddDeveloper,
+    RemoveEntry,
+    StopEditing,
+    Submit,
+}
+
+impl ProjectInfoViewAction {
+    fn description(&self) -> &'static str {
+        match self {
+            ProjectInfoViewAction::SelectNext => "Select next field",
+            ProjectInfoViewAction::SelectPrevious => "Select previous field",
+            ProjectInfoViewAction::Edit => "Edit selected field",
+            ProjectInfoViewAction::Bump(bump) => bump.description(),
+            ProjectInfoViewAction::AddLicense => "Add license",
+            ProjectInfoViewAction::AddDeveloper => "Add developer",
+            ProjectInfoViewAction::RemoveEntry => "Remove selected license or developer",
+            ProjectInfoViewAction::StopEditing => "Cancel editing",
+            ProjectInfoViewAction::Submit => "Apply",
+        }
+    }
+}
+
+pub struct ProjectInfoView {
+    list_state: ListState,
+    prompt: Prompt<ProjectField>,
+    /// Why the submitted value was rejected, shown until the next submit.
+    error: Option<String>,
+    /// Fields and values of the last render.
+    fields: Vec<(ProjectField, String)>,
+    has_parent: bool,
+}
+
+impl ProjectInfoView {
+    pub fn new() -> Self {
+        Self {
+            list_state: Default::default(),
+            prompt: Prompt::new(),
+            error: None,
+            fields: vec![],
+            has_parent: false,
+        }
+    }
+
+    fn get_mapping(&self) -> HashMap<KeyCode, ProjectInfoViewAction> {
+        if self.prompt.is_open() {
+            return HashMap::from([
+                (KeyCode::Esc, ProjectInfoViewAction::StopEditing),
+                (KeyCode::Enter, ProjectInfoViewAction::Submit),
+            ]);
+        }
+
+        return HashMap::from([
+            (KeyCode::Char('j'), ProjectInfoViewAction::SelectNext),
+            (KeyCode::Char('k'), ProjectInfoViewAction::SelectPrevious),
+            (KeyCode::Char('v'), ProjectInfoViewAction::Edit),
+            (KeyCode::Enter, ProjectInfoViewAction::Edit),
+            (KeyCode::Char('M'), ProjectInfoViewAction::Bump(Bump::Major)),
+            (KeyCode::Char('m'), ProjectInfoViewAction::Bump(Bump::Minor)),
+            (KeyCode::Char('p'), ProjectInfoViewAction::Bump(Bump::Patch)),
+            (KeyCode::Char('r'), ProjectInfoViewAction::Bump(Bump::Release)),
+            (KeyCode::Char('s'), ProjectInfoViewAction::Bump(Bump::Snapshot)),
+            (KeyCode::Char('L'), ProjectInfoViewAction::AddLicense),
+            (KeyCode::Char('D'), ProjectInfoViewAction::AddDeveloper),
+            (KeyCode::Char('d'), ProjectInfoViewAction::RemoveEntry),
+        ]);
+    }
+
+    fn value_spans(&self, field: ProjectField, value: &str) -> Vec<Span<'static>> {
+        let mut spans = match (value.is_empty(), field) {
+            (true, ProjectField::GroupId | ProjectField::Version) if self.has_parent => {
+                vec![Span::styled("inherited from parent", EMPTY_STYLE)]
+            }
+            (true, _) => vec![Span::styled("—", EMPTY_STYLE)],
+            (false, _) => vec![Span::raw(value.to_string())],
+        };
+
+        if let Err(error) = field.validate(value, self.has_parent) {
+            spans.push(Span::styled(format!("  ✗ {}", error), ERROR_STYLE));
+        }
+
+        return spans;
+    }
+
+    fn selected_field(&self) -> Option<&(ProjectField, String)> {
+        return self.fields.get(self.list_state.selected()?);
+    }
+
+    fn submit(&mut self) -> Option<Intent> {
+        let field = *self.prompt.editing()?;
+        let value = self.prompt.value().trim().to_string();
+
+        if let Err(error) = field.validate(&value, self.has_parent) {
+            self.error = Some(format!("{} {}", field.label(), error));
+            return None;
+        }
+
+        self.prompt.close();
+        self.error = None;
+
+        return Some(Intent::SetProjectField { field, value });
+    }
+}
+
+impl View for ProjectInfoView {
+
+    fn render(&mut self, buffer: &mut Buffer, area: Rect, state: &Data) {
+        let block = Block::new().title(Line::raw("Project").centered());
+
+        self.fields = state.maven_file.project_fields();
+        self.has_parent = state.maven_file.to_pom().parent.is_some();
+
+        let title = match (&self.error, self.prompt.editing()) {
+            (Some(error), _) => Line::styled(error.clone(), ERROR_STYLE),
+            (None, Some(field)) => Line::raw(field.label()),
+            (None, None) => Line::default(),
+        };
+        let list_area = self.prompt.render(area, buffer, title);
+
+        let label_width = self.fields.iter().map(|(field, _)| field.label().len()).max().unwrap_or(0);
+
+        let items: Vec<ListItem> = self
+            .fields
+            .iter()
+            .enumerate()
+            .map(|(i, (field, value))| {
+                let mut spans = vec![Span::styled(format!("{:width$}  ", field.label(), width = label_width), LABEL_STYLE)];
+                spans.extend(self.value_spans(*field, value));
+
+                let color = alternate_colors(i);
+                ListItem::new(Line::from(spans)).bg(color)
+            })
+            .collect();
+
+        let list = List::new(items)
+            .block(block)
+            .highlight_style(SELECTED_STYLE)
+            .highlight_symbol(">")
+            .highlight_spacing(HighlightSpacing::Always);
+
+        StatefulWidget::render(list, list_area, buffer, &mut self.list_state);
+    }
+
+    fn handle_event(&mut self, event: &Event) -> Option<Intent> {
+        if let Event::Key(key_event) = event {
+            let Some(action) = self.get_mapping().get(&key_event.code).copied() else {
+                self.prompt.handle_event(event);
+                return None;
+            };
+
+            match action {
+                ProjectInfoViewAction::SelectNext => self.list_state.select_next(),
+                ProjectInfoViewAction::SelectPrevious => self.list_state.select_previous(),
+                ProjectInfoViewAction::Edit => {
+                    let (field, value) = self.selected_field()?.clone();
+                    self.prompt.open(field, &value);
+                    self.error = None;
+
+                    return Some(Intent::EnterInputMode);
+                }
+                ProjectInfoViewAction::Bump(bump) => return Some(Intent::BumpProjectVersion(bump)),
+                ProjectInfoViewAction::AddLicense => return Some(Intent::AddProjectEntry(ProjectList::Licenses)),
+                ProjectInfoViewAction::AddDeveloper => return Some(Intent::AddProjectEntry(ProjectList::Developers)),
+                ProjectInfoViewAction::RemoveEntry => {
+                    let (list, index) = self.selected_field()?.0.list()?;
+                    return Some(Intent::RemoveProjectEntry { list, index });
+                }
+                ProjectInfoViewAction::StopEditing => {
+                    self.prompt.close();
+                    self.error = None;
+                    return Some(Intent::LeaveInputMode);
+                }
+                ProjectInfoViewAction::Submit => return self.submit(),
+            };
+        }
+
+        self.prompt.handle_event(event);
+
+        return None;
+    }
+
+    fn keybindings(&self) -> Vec<KeyBinding> {
+        let mut bindings = KeyBinding::from_mapping(&self.get_mapping(), ProjectInfoViewAction::description);
+        bindings.extend(self.prompt.keybindings());
+
+        return bindings;
+    }
+}
@@ -0,0 +1,228 @@
+use ratatui::{
+    buffer::Buffer,
+    prelude::{Color, Modifier, Rect, Style, style::palette::tailwind::SLATE},
+    style::Stylize,
+    text::{Line, Span},
+    widgets::{Block, HighlightSpacing, List, ListItem, ListState, StatefulWidget},
+};
+use crate::{
+    views::View,
+    app::Data,
+    ui::alternate_colors,
+    events::{Intent, KeyBinding},
+    widgets::prompt::Prompt,
+};
+
+use crossterm::event::{Event, KeyCode};
+use std::collections::HashMap;
+
+const SELECTED_STYLE: Style = Style::new().bg(SLATE.c800).add_modifier(Modifier::BOLD);
+const NOTE_STYLE: Style = Style::new().fg(SLATE.c400);
+const OVERRIDE_STYLE: Style = Style::new().fg(Color::Yellow);
+const ERROR_STYLE: Style = Style::new().fg(Color::Red);
+
+#[derive(Debug, Clone, Copy)]
+enum PropertiesViewAction {
+    SelectNext,
+    SelectPrevious,
+    Add,
+    EditValue,
+    Remove,
+    StopEditing,
+    Submit,
+}
+
+impl PropertiesViewAction {
+    fn description(&self) -> &'static str {
+        match self {
+            PropertiesViewAction::SelectNext => "Select next property",
+            PropertiesViewAction::SelectPrevious => "Select previous property",
+            PropertiesViewAction::Add => "Add property",
+            PropertiesViewAction::EditValue => "Change value of selected property",
+            PropertiesViewAction::Remove => "Remove selected property",
+            PropertiesViewAction::StopEditing => "Cancel editing",
+            PropertiesViewAction::Submit => "Apply",
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+enum Edit {
+    NewProperty,
+    Value { name: String },
+}
+
+impl Edit {
+    fn prompt(&self) -> String {
+        match self {
+            Edit::NewProperty => "New property (name=value)".to_string(),
+            Edit::Value { name } => format!("Value of {}", name),
+        }
+    }
+}
+
+pub struct PropertiesView {
+    list_state: ListState,
+    prompt: Prompt<Edit>,
+    /// Names and values of the listed properties, from the last render.
+    properties: Vec<(String, String)>,
+}
+
+impl PropertiesView {
+    pub fn new() -> Self {
+        Self {
+            list_state: Default::default(),
+            prompt: Prompt::new(),
+            properties: vec![],
+        }
+    }
+
+    fn get_mapping(&self) -> HashMap<KeyCode, PropertiesViewAction> {
+        if self.prompt.is_open() {
+            return HashMap::from([
+                (KeyCode::Esc, PropertiesViewAction::StopEditing),
+                (KeyCode::Enter, PropertiesViewAction::Submit),
+            ]);
+        }
+
+        return HashMap::from([
+            (KeyCode::Char('j'), PropertiesViewAction::SelectNext),
+            (KeyCode::Char('k'), PropertiesViewAction::SelectPrevious),
+            (KeyCode::Char('n'), PropertiesViewAction::Add),
+            (KeyCode::Char('v'), PropertiesViewAction::EditValue),
+            (KeyCode::Enter, PropertiesViewAction::EditValue),
+            (KeyCode::Char('d'), PropertiesViewAction::Remove),
+        ]);
+    }
+
+    /// How the value relates to the one inherited from the parent, if any.
+    fn parent_span(name: &str, value: &str, state: &Data) -> Option<Span<'static>> {
+        let Some(Ok(parent)) = &state.parent_properties else {
+            return None;
+        };
+
+        let inherited = parent.get(name)?;
+        if inherited == value {
+            return Some(Span::styled(" same as parent", NOTE_STYLE));
+        }
+
+        return Some(Span::styled(format!(" overrides parent value {}", inherited), OVERRIDE_STYLE));
+    }
+
+    fn parent_status(state: &Data) -> Option<Line<'static>> {
+        match &state.parent_properties {
+            Some(Err(error)) => Some(Line::styled(format!(" Parent not loaded: {} ", error), ERROR_STYLE)),
+            None if state.maven_file.to_pom().parent.is_some() => Some(Line::styled(" Loading parent... ", NOTE_STYLE)),
+            _ => None,
+        }
+    }
+
+    fn start_editing(&mut self, edit: Edit, value: &str) -> Option<Intent> {
+        self.prompt.open(edit, value);
+
+        return Some(Intent::EnterInputMode);
+    }
+
+    fn submit(&mut self) -> Option<Intent> {
+        let value = self.prompt.value().trim().to_string();
+
+        let intent = match self.prompt.editing()?.clone() {
+            Edit::Value { name } => Intent::SetProperty { name, value },
+            Edit::NewProperty => {
+                let (name, value) = value.split_once('=')?;
+                let name = name.trim();
+                if name.is_empty() || name.contains(char::is_whitespace) {
+                    return None;
+                }
+
+                Intent::SetProperty { name: name.to_string(), value: value.trim().to_string() }
+            }
+        };
+
+        self.prompt.close();
+
+        return Some(intent);
+    }
+}
+
+impl View for PropertiesView {
+
+    fn render(&mut self, buffer: &mut Buffer, area: Rect, state: &Data) {
+        let mut block = Block::new().title(Line::raw("Properties").centered());
+        if let Some(status) = Self::parent_status(state) {
+            block = block.title_bottom(status.right_aligned());
+        }
+
+        self.properties = state.maven_file.get_properties();
+        let references = state.maven_file.property_references();
+
+        let title = self.prompt.editing().map(Edit::prompt).unwrap_or_default();
+        let list_area = self.prompt.render(area, buffer, title);
+
+        let items: Vec<ListItem> = self
+            .properties
+            .iter()
+            .enumerate()
+            .map(|(i, (name, value))| {
+                let mut spans = vec![Span::raw(format!("{} = {}", name, value))];
+
+                spans.extend(Self::parent_span(name, value, state));
+
+                match references.get(name) {
+                    Some(locations) => spans.push(Span::styled(format!(" used by {}", locations.join(", ")), NOTE_STYLE)),
+                    None => spans.push(Span::styled(" unused", NOTE_STYLE)),
+                }
+
+                let color = alternate_colors(i);
+                ListItem::new(Line::from(spans)).bg(color)
+            })
+            .collect();
+
+        let list = List::new(items)
+            .block(block)
+            .highlight_style(SELECTED_STYLE)
+            .highlight_symbol(">")
+            .highlight_spacing(HighlightSpacing::Always);
+
+        StatefulWidget::render(list, list_area, buffer, &mut self.list_state);
+    }
+
+    fn handle_event(&mut self, event: &Event) -> Option<Intent> {
+        if let Event::Key(key_event) = event {
+            let Some(action) = self.get_mapping().get(&key_event.code).copied() else {
+                self.prompt.handle_event(event);
+                return None;
+            };
+
+            match action {
+                PropertiesViewAction::SelectNext => self.list_state.select_next(),
+                PropertiesViewAction::SelectPrevious => self.list_state.select_previous(),
+                PropertiesViewAction::Add => return self.start_editing(Edit::NewProperty, ""),
+                PropertiesViewAction::EditValue => {
+                    let (name, value) = self.properties.get(self.list_state.selected()?)?.clone();
+                    return self.start_editing(Edit::Value { name }, &value);
+                }
+                PropertiesViewAction::Remove => {
+                    let (name, _) = self.properties.get(self.list_state.selected()?)?;
+                    return Some(Intent::RemoveProperty { name: name.clone() });
+                }
+                PropertiesViewAction::StopEditing => {
+                    self.prompt.close();
+                    return Some(Intent::LeaveInputMode);
+                }
+                PropertiesViewAction::Submit => return self.submit(),
+            };
+        }
+
+        self.prompt.handle_event(event);
+
+        return None;
+    }
+
+    fn keybindings(&self) -> Vec<KeyBinding> {
+        let mut bindings = KeyBinding::from_mapping(&self.get_mapping(), PropertiesViewAction::description);
+        bindings.extend(self.prompt.keybindings());
+
+        return bindings;
+    }
+}
@@ -0,0 +1,2 @@
+pub mod prompt;
+pub mod text_input;
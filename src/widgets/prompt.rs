@@ -0,0 +1,89 @@
+use ratatui::{
+    buffer::Buffer,
+    layout::{Constraint, Direction, Layout, Rect},
+    text::Line,
+    widgets::{Block, Borders, Widget},
+};
+
+use crossterm::event::Event;
+
+use crate::{events::KeyBinding, widgets::text_input::TextInput};
+
+/// Text input opened at the bottom of a view to enter a value, remembering
+/// what the value is for.
+#[derive(Debug)]
+pub struct Prompt<E> {
+    input: TextInput,
+    editing: Option<E>,
+}
+
+impl<E> Prompt<E> {
+    pub fn new() -> Self {
+        return Self {
+            input: TextInput::new(),
+            editing: None,
+        };
+    }
+
+    /// What the value is entered for, `None` while the prompt is closed.
+    pub fn editing(&self) -> Option<&E> {
+        return self.editing.as_ref();
+    }
+
+    pub fn is_open(&self) -> bool {
+        return self.editing.is_some();
+    }
+
+    /// The value as entered so far.
+    pub fn value(&self) -> String {
+        return self.input.value();
+    }
+
+    /// Opens the prompt for `edit`, prefilled with `value`.
+    pub fn open(&mut self, edit: E, value: &str) {
+        self.input.set_value(value);
+        self.editing = Some(edit);
+    }
+
+    pub fn close(&mut self) {
+        self.editing = None;
+    }
+
+    /// Passes key presses the view does not handle itself, and pastes, to
+    /// the input while it is open. Returns `true` when the value changed.
+    pub fn handle_event(&mut self, event: &Event) -> bool {
+        if self.editing.is_none() {
+            return false;
+        }
+
+        return self.input.handle_event(event);
+    }
+
+    pub fn keybindings(&self) -> Vec<KeyBinding> {
+        if self.editing.is_none() {
+            return vec![];
+        }
+
+        return self.input.keybindings();
+    }
+
+    /// Renders the open prompt below `area` with the given title. Returns
+    /// the part of `area` left for the view.
+    pub fn render<'a>(&self, area: Rect, buffer: &mut Buffer, title: impl Into<Line<'a>>) -> Rect {
+        if self.editing.is_none() {
+            return area;
+        }
+
+        let layout = Layout::default()
+            .direction(Direction::Vertical)
+            .constraints([Constraint::Min(0), Constraint::Length(3)])
+            .split(area);
+
+        let block = Block::new().borders(Borders::ALL).title(title);
+        let input_area = block.inner(layout[1]);
+        block.render(layout[1], buffer);
+        self.input.render(input_area, buffer, true);
+
+        return layout[0];
+    }
+}
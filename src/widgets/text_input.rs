@@ -0,0 +1,396 @@
+use ratatui::{
+    buffer::Buffer,
+    layout::Rect,
+    style::{Modifier, Style},
+    text::{Line, Span},
+    widgets::Widget,
+};
+
+use crossterm::event::{Event, KeyCode, KeyModifiers};
+use std::collections::HashMap;
+use unicode_segmentation::UnicodeSegmentation;
+
+use crate::events::KeyBinding;
+
+const CURSOR_STYLE: Style = Style::new().add_modifier(Modifier::REVERSED);
+
+#[derive(Debug, Clone, Copy)]
+enum TextInputAction {
+    MoveLeft,
+    MoveRight,
+    MoveWordLeft,
+    MoveWordRight,
+    MoveHome,
+    MoveEnd,
+    DeleteBackward,
+    DeleteForward,
+    DeleteWordBackward,
+    DeleteWordForward,
+    DeleteToStart,
+    DeleteToEnd,
+    HistoryPrevious,
+    HistoryNext,
+}
+
+impl TextInputAction {
+    fn description(&self) -> &'static str {
+        match self {
+            TextInputAction::MoveLeft => "Move cursor left",
+            TextInputAction::MoveRight => "Move cursor right",
+            TextInputAction::MoveWordLeft => "Move to previous word",
+            TextInputAction::MoveWordRight => "Move to next word",
+            TextInputAction::MoveHome => "Move to start of line",
+            TextInputAction::MoveEnd => "Move to end of line",
+            TextInputAction::DeleteBackward => "Delete character before cursor",
+            TextInputAction::DeleteForward => "Delete character under cursor",
+            TextInputAction::DeleteWordBackward => "Delete previous word",
+            TextInputAction::DeleteWordForward => "Delete next word",
+            TextInputAction::DeleteToStart => "Delete to start of line",
+            TextInputAction::DeleteToEnd => "Delete to end of line",
+            TextInputAction::HistoryPrevious => "Previous entry from history",
+            TextInputAction::HistoryNext => "Next entry from history",
+        }
+    }
+}
+
+/// Single line text field with cursor movement, word-wise editing,
+/// bracketed paste and a history of submitted values.
+#[derive(Debug, Clone, Default)]
+pub struct TextInput {
+    chars: Vec<char>,
+    cursor: usize,
+    history: Vec<String>,
+    history_index: Option<usize>,
+    draft: Vec<char>,
+}
+
+impl TextInput {
+    pub fn new() -> Self {
+        return Default::default();
+    }
+
+    pub fn value(&self) -> String {
+        return self.chars.iter().collect();
+    }
+
+    /// Replaces the value and moves the cursor to its end.
+    pub fn set_value(&mut self, value: &str) {
+        self.chars = value.chars().collect();
+        self.cursor = self.chars.len();
+        self.history_index = None;
+    }
+
+    /// Stores the current value as the most recent history entry.
+    pub fn push_history(&mut self) {
+        let value = self.value();
+        self.history_index = None;
+
+        if value.trim().is_empty() || self.history.last() == Some(&value) {
+            return;
+        }
+
+        self.history.push(value);
+    }
+
+    fn get_mapping(&self) -> HashMap<(KeyModifiers, KeyCode), TextInputAction> {
+        return HashMap::from([
+            ((KeyModifiers::NONE, KeyCode::Left), TextInputAction::MoveLeft),
+            ((KeyModifiers::NONE, KeyCode::Right), TextInputAction::MoveRight),
+            ((KeyModifiers::CONTROL, KeyCode::Left), TextInputAction::MoveWordLeft),
+            ((KeyModifiers::CONTROL, KeyCode::Right), TextInputAction::MoveWordRight),
+            ((KeyModifiers::ALT, KeyCode::Char('b')), TextInputAction::MoveWordLeft),
+            ((KeyModifiers::ALT, KeyCode::Char('f')), TextInputAction::MoveWordRight),
+            ((KeyModifiers::NONE, KeyCode::Home), TextInputAction::MoveHome),
+            ((KeyModifiers::NONE, KeyCode::End), TextInputAction::MoveEnd),
+            ((KeyModifiers::CONTROL, KeyCode::Char('a')), TextInputAction::MoveHome),
+            ((KeyModifiers::CONTROL, KeyCode::Char('e')), TextInputAction::MoveEnd),
+            ((KeyModifiers::NONE, KeyCode::Backspace), TextInputAction::DeleteBackward),
+            ((KeyModifiers::NONE, KeyCode::Delete), TextInputAction::DeleteForward),
+            ((KeyModifiers::CONTROL, KeyCode::Char('w')), TextInputAction::DeleteWordBackward),
+            ((KeyModifiers::ALT, KeyCode::Backspace), TextInputAction::DeleteWordBackward),
+            ((KeyModifiers::ALT, KeyCode::Char('d')), TextInputAction::DeleteWordForward),
+            ((KeyModifiers::CONTROL, KeyCode::Char('u')), TextInputAction::DeleteToStart),
+            ((KeyModifiers::CONTROL, KeyCode::Char('k')), TextInputAction::DeleteToEnd),
+            ((KeyModifiers::NONE, KeyCode::Up), TextInputAction::HistoryPrevious),
+            ((KeyModifiers::NONE, KeyCode::Down), TextInputAction::HistoryNext),
+        ]);
+    }
+
+    pub fn keybindings(&self) -> Vec<KeyBinding> {
+        return KeyBinding::from_modified_mapping(&self.get_mapping(), TextInputAction::description);
+    }
+
+    /// Applies a key press or paste to the field. Returns `true` when the value changed.
+    pub fn handle_event(&mut self, event: &Event) -> bool {
+        let before = self.chars.clone();
+
+        match event {
+            Event::Key(key_event) => {
+                let modifiers = key_event.modifiers;
+
+                if let Some(action) = self.get_mapping().get(&(modifiers, key_event.code)).copied() {
+                    self.apply(action);
+                } else if let KeyCode::Char(char) = key_event.code
+                    && (modifiers - KeyModifiers::SHIFT).is_empty()
+                {
+                    self.insert(&[char]);
+                }
+            }
+            Event::Paste(text) => {
+                let pasted: Vec<char> = text.chars().filter(|char| !char.is_control()).collect();
+                self.insert(&pasted);
+            }
+            _ => (),
+        }
+
+        return before != self.chars;
+    }
+
+    fn apply(&mut self, action: TextInputAction) {
+        match action {
+            TextInputAction::MoveLeft => self.cursor = self.previous_grapheme_start(),
+            TextInputAction::MoveRight => self.cursor = self.next_grapheme_end(),
+            TextInputAction::MoveWordLeft => self.cursor = self.previous_word_start(),
+            TextInputAction::MoveWordRight => self.cursor = self.next_word_end(),
+            TextInputAction::MoveHome => self.cursor = 0,
+            TextInputAction::MoveEnd => self.cursor = self.chars.len(),
+            TextInputAction::DeleteBackward => self.delete_range(self.previous_grapheme_start(), self.cursor),
+            TextInputAction::DeleteForward => self.delete_range(self.cursor, self.next_grapheme_end()),
+            TextInputAction::DeleteWordBackward => self.delete_range(self.previous_word_start(), self.cursor),
+            TextInputAction::DeleteWordForward => self.delete_range(self.cursor, self.next_word_end()),
+            TextInputAction::DeleteToStart => self.delete_range(0, self.cursor),
+            TextInputAction::DeleteToEnd => self.delete_range(self.cursor, self.chars.len()),
+            TextInputAction::HistoryPrevious => self.history_previous(),
+            TextInputAction::HistoryNext => self.history_next(),
+        }
+    }
+
+    fn insert(&mut self, chars: &[char]) {
+        self.history_index = None;
+        self.chars.splice(self.cursor..self.cursor, chars.iter().copied());
+        self.cursor += chars.len();
+    }
+
+    fn delete_range(&mut self, start: usize, end: usize) {
+        self.history_index = None;
+        self.chars.drain(start..end);
+        self.cursor = start;
+    }
+
+    /// Char ranges of the user-perceived characters of the value, so that an
+    /// accent and the letter it combines with are moved over and deleted together.
+    fn graphemes(&self) -> Vec<(usize, usize)> {
+        let value = self.value();
+        let mut position = 0;
+
+        return value
+            .graphemes(true)
+            .map(|grapheme| {
+                let start = position;
+                position += grapheme.chars().count();
+                (start, position)
+            })
+            .collect();
+    }
+
+    fn is_word(&self, (start, _): (usize, usize)) -> bool {
+        return self.chars[start].is_alphanumeric();
+    }
+
+    fn previous_grapheme_start(&self) -> usize {
+        return self
+            .graphemes()
+            .into_iter()
+            .rev()
+            .find(|(start, _)| *start < self.cursor)
+            .map_or(0, |(start, _)| start);
+    }
+
+    fn next_grapheme_end(&self) -> usize {
+        return self
+            .graphemes()
+            .into_iter()
+            .find(|(_, end)| *end > self.cursor)
+            .map_or(self.chars.len(), |(_, end)| end);
+    }
+
+    fn previous_word_start(&self) -> usize {
+        let graphemes: Vec<(usize, usize)> = self.graphemes().into_iter().filter(|(_, end)| *end <= self.cursor).collect();
+        let mut remaining = graphemes.iter().rev().copied().peekable();
+
+        while remaining.next_if(|grapheme| !self.is_word(*grapheme)).is_some() {}
+        while remaining.next_if(|grapheme| self.is_word(*grapheme)).is_some() {}
+
+        return remaining.peek().map_or(0, |(_, end)| *end);
+    }
+
+    fn next_word_end(&self) -> usize {
+        let graphemes: Vec<(usize, usize)> = self.graphemes().into_iter().filter(|(start, _)| *start >= self.cursor).collect();
+        let mut remaining = graphemes.iter().copied().peekable();
+
+        while remaining.next_if(|grapheme| !self.is_word(*grapheme)).is_some() {}
+        while remaining.next_if(|grapheme| self.is_word(*grapheme)).is_some() {}
+
+        return remaining.peek().map_or(self.chars.len(), |(start, _)| *start);
+    }
+
+    fn history_previous(&mut self) {
+        let index = match self.history_index {
+            None if self.history.is_empty() => return,
+            None => {
+                self.draft = self.chars.clone();
+                self.history.len() - 1
+            }
+            Some(index) => index.saturating_sub(1),
+        };
+
+        self.history_index = Some(index);
+        self.chars = self.history[index].chars().collect();
+        self.cursor = self.chars.len();
+    }
+
+    fn history_next(&mut self) {
+        let Some(index) = self.history_index else {
+            return;
+        };
+
+        if index + 1 < self.history.len() {
+            self.history_index = Some(index + 1);
+            self.chars = self.history[index + 1].chars().collect();
+        } else {
+            self.history_index = None;
+            self.chars = std::mem::take(&mut self.draft);
+        }
+
+        self.cursor = self.chars.len();
+    }
+
+    /// Renders the value on a single line, scrolled so that the cursor stays visible.
+    pub fn render(&self, area: Rect, buffer: &mut Buffer, show_cursor: bool) {
+        let width = area.width as usize;
+        if width == 0 {
+            return;
+        }
+
+        let offset = (self.cursor + 1).saturating_sub(width);
+        let visible: String = self.chars.iter().skip(offset).take(width).collect();
+
+        if !show_cursor {
+            Line::raw(visible).render(area, buffer);
+            return;
+        }
+
+        let cursor_position = self.cursor - offset;
+        let before: String = visible.chars().take(cursor_position).collect();
+        let under: String = visible.chars().nth(cursor_position).unwrap_or(' ').to_string();
+        let after: String = visible.chars().skip(cursor_position + 1).collect();
+
+        Line::from(vec![
+            Span::raw(before),
+            Span::styled(under, CURSOR_STYLE),
+            Span::raw(after),
+        ])
+        .render(area, buffer);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crossterm::event::KeyEvent;
+
+    fn input(value: &str) -> TextInput {
+        let mut input = TextInput::new();
+        enter(&mut input, value);
+        return input;
+    }
+
+    /// Replaces the value, leaving the cursor at its end.
+    fn enter(input: &mut TextInput, value: &str) {
+        input.chars = value.chars().collect();
+        input.cursor = input.chars.len();
+    }
+
+    fn press(input: &mut TextInput, modifiers: KeyModifiers, code: KeyCode) {
+        input.handle_event(&Event::Key(KeyEvent::new(code, modifiers)));
+    }
+
+    #[test]
+    fn deletes_words_before_the_cursor() {
+        let mut input = input("org.example:my-lib");
+
+        press(&mut input, KeyModifiers::CONTROL, KeyCode::Char('w'));
+        assert_eq!(input.value(), "org.example:my-");
+
+        press(&mut input, KeyModifiers::ALT, KeyCode::Backspace);
+        assert_eq!(input.value(), "org.example:");
+
+        press(&mut input, KeyModifiers::CONTROL, KeyCode::Left);
+        press(&mut input, KeyModifiers::ALT, KeyCode::Backspace);
+        assert_eq!(input.value(), "example:");
+        assert_eq!(input.cursor, 0);
+
+        press(&mut input, KeyModifiers::CONTROL, KeyCode::Char('w'));
+        assert_eq!(input.value(), "example:");
+    }
+
+    #[test]
+    fn moves_over_words_and_whole_graphemes() {
+        let mut input = input("cafe\u{301} au lait");
+
+        press(&mut input, KeyModifiers::CONTROL, KeyCode::Left);
+        assert_eq!(input.cursor, 9);
+        press(&mut input, KeyModifiers::CONTROL, KeyCode::Left);
+        press(&mut input, KeyModifiers::CONTROL, KeyCode::Left);
+        assert_eq!(input.cursor, 0);
+        press(&mut input, KeyModifiers::ALT, KeyCode::Char('f'));
+        assert_eq!(input.cursor, 5);
+
+        // The accent is combined with the `e` before it.
+        press(&mut input, KeyModifiers::NONE, KeyCode::Left);
+        assert_eq!(input.cursor, 3);
+        press(&mut input, KeyModifiers::NONE, KeyCode::Right);
+        assert_eq!(input.cursor, 5);
+        press(&mut input, KeyModifiers::NONE, KeyCode::Backspace);
+        assert_eq!(input.value(), "caf au lait");
+
+        press(&mut input, KeyModifiers::NONE, KeyCode::End);
+        press(&mut input, KeyModifiers::NONE, KeyCode::Right);
+        assert_eq!(input.cursor, 11);
+    }
+
+    #[test]
+    fn history_keeps_the_draft() {
+        let mut input = input("guava");
+        input.push_history();
+        enter(&mut input, "junit");
+        input.push_history();
+        enter(&mut input, "slf");
+
+        press(&mut input, KeyModifiers::NONE, KeyCode::Up);
+        assert_eq!(input.value(), "junit");
+        press(&mut input, KeyModifiers::NONE, KeyCode::Up);
+        press(&mut input, KeyModifiers::NONE, KeyCode::Up);
+        assert_eq!(input.value(), "guava");
+
+        press(&mut input, KeyModifiers::NONE, KeyCode::Down);
+        press(&mut input, KeyModifiers::NONE, KeyCode::Down);
+        assert_eq!(input.value(), "slf");
+        assert_eq!(input.cursor, 3);
+
+        press(&mut input, KeyModifiers::NONE, KeyCode::Down);
+        assert_eq!(input.value(), "slf");
+    }
+
+    #[test]
+    fn paste_drops_control_characters() {
+        let mut input = input("a:");
+        press(&mut input, KeyModifiers::NONE, KeyCode::Home);
+
+        assert!(input.handle_event(&Event::Paste("org.\texample\r\n".to_string())));
+        assert_eq!(input.value(), "org.examplea:");
+        assert_eq!(input.cursor, 11);
+
+        assert!(!input.handle_event(&Event::Paste("\n".to_string())));
+    }
+}